@@ -0,0 +1,150 @@
+//! Refresh filtering throughput: one blocklist checked against a batch of entries.
+//!
+//! Run with `cargo bench --bench blocklist`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use regex::Regex;
+
+use beatcheck::blocklist::{BlockTarget, Blocklist};
+
+const WORDS: &[&str] = &[
+    "apple",
+    "launch",
+    "privacy",
+    "update",
+    "court",
+    "ruling",
+    "chip",
+    "battery",
+    "streaming",
+    "policy",
+    "security",
+    "startup",
+    "funding",
+    "review",
+    "camera",
+    "network",
+    "browser",
+    "release",
+    "feature",
+    "market",
+    "developer",
+    "platform",
+    "subscription",
+    "hardware",
+];
+
+struct Entry {
+    title: String,
+    content: String,
+    url: String,
+}
+
+/// Deterministic pseudo-random word sequence so runs are comparable
+fn words(seed: usize, count: usize) -> String {
+    (0..count)
+        .map(|i| WORDS[(seed * 31 + i * 17 + i * i) % WORDS.len()])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn entries(count: usize) -> Vec<Entry> {
+    (0..count)
+        .map(|i| Entry {
+            title: words(i, 10),
+            content: words(i + 7, 300),
+            url: format!("https://example.com/{}/story-{}", i % 13, i),
+        })
+        .collect()
+}
+
+/// Keywords that never occur in the generated text, so every rule is checked
+fn blocklist_text(keywords: usize) -> String {
+    (0..keywords)
+        .map(|i| format!("blockedterm{}", i))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Write `text` to a blocklist.txt the way a user would and load it
+fn load_blocklist(text: &str) -> Blocklist {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("blocklist.txt");
+    std::fs::write(&path, text).unwrap();
+    Blocklist::load_from(&path)
+}
+
+/// The pre-compilation approach: build a regex per keyword per text
+fn naive_contains(keywords: &[String], text: &str) -> bool {
+    let text_lower = text.to_lowercase();
+    keywords.iter().any(|keyword| {
+        let pattern = format!(r"\b{}\b", regex::escape(keyword));
+        Regex::new(&pattern)
+            .map(|re| re.is_match(&text_lower))
+            .unwrap_or(false)
+    })
+}
+
+fn bench_refresh_filtering(c: &mut Criterion) {
+    let entries = entries(200);
+    // The per-keyword regex approach is orders of magnitude slower, so give it fewer entries
+    let naive_entries = &entries[..10];
+    let mut group = c.benchmark_group("refresh_filtering");
+    group.sample_size(10);
+
+    for keywords in [50, 300] {
+        let text = blocklist_text(keywords);
+        let blocklist = load_blocklist(&text);
+
+        group.throughput(Throughput::Elements(entries.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("compiled", keywords),
+            &blocklist,
+            |b, bl| {
+                b.iter(|| {
+                    entries
+                        .iter()
+                        .filter(|e| {
+                            bl.find_match(&BlockTarget {
+                                title: &e.title,
+                                content: Some(&e.content),
+                                url: &e.url,
+                                ..Default::default()
+                            })
+                            .is_some()
+                        })
+                        .count()
+                })
+            },
+        );
+
+        let keyword_list: Vec<String> = text.lines().map(str::to_string).collect();
+        group.throughput(Throughput::Elements(naive_entries.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("regex_per_keyword", keywords),
+            &keyword_list,
+            |b, kw| {
+                b.iter(|| {
+                    naive_entries
+                        .iter()
+                        .filter(|e| naive_contains(kw, &e.title) || naive_contains(kw, &e.content))
+                        .count()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_load(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("blocklist.txt");
+    std::fs::write(&path, blocklist_text(300)).unwrap();
+    c.bench_function("blocklist_compile_300", |b| {
+        b.iter(|| Blocklist::load_from(black_box(&path)))
+    });
+}
+
+criterion_group!(benches, bench_refresh_filtering, bench_load);
+criterion_main!(benches);
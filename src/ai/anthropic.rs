@@ -0,0 +1,546 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::error::{AppError, ClaudeApiError, Result};
+
+use super::backend::{truncate_for_error, Completion, SummaryBackend, Usage};
+use super::retry::RetryPolicy;
+use super::sse::SseParser;
+
+#[derive(Debug, Serialize)]
+struct MessageRequest<'a> {
+    model: &'a str,
+    max_tokens: u32,
+    messages: Vec<Message<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct Message<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageResponse {
+    content: Vec<ContentBlock>,
+    usage: Option<ResponseUsage>,
+}
+
+/// Token counts; streaming events carry only some of them
+#[derive(Debug, Default, Deserialize)]
+struct ResponseUsage {
+    #[serde(default)]
+    input_tokens: u32,
+    #[serde(default)]
+    output_tokens: u32,
+}
+
+/// The parts of a streaming event we use
+#[derive(Debug, Deserialize)]
+struct StreamEvent {
+    #[serde(rename = "type")]
+    event_type: String,
+    delta: Option<StreamDelta>,
+    error: Option<ErrorDetail>,
+    /// message_start carries the input token count here
+    message: Option<StreamMessage>,
+    /// message_delta carries the final output token count here
+    usage: Option<ResponseUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamMessage {
+    usage: Option<ResponseUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamDelta {
+    text: Option<String>,
+}
+
+/// Body of an error response, also sent as a streaming `error` event
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    #[serde(rename = "type")]
+    error_type: String,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentBlock {
+    #[serde(rename = "type")]
+    #[allow(dead_code)]
+    content_type: String,
+    text: Option<String>,
+}
+
+/// Anthropic Messages API
+pub struct AnthropicBackend {
+    client: Client,
+    base_url: String,
+    api_key: String,
+    model: String,
+    retry: RetryPolicy,
+}
+
+impl AnthropicBackend {
+    pub const DEFAULT_URL: &'static str = "https://api.anthropic.com";
+    pub const DEFAULT_MODEL: &'static str = "claude-haiku-4-5-20251001";
+
+    pub fn new(api_key: String, base_url: Option<String>, model: Option<String>) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .expect("Failed to create HTTP client");
+        Self {
+            client,
+            base_url: base_url.unwrap_or_else(|| Self::DEFAULT_URL.to_string()),
+            api_key,
+            model: model.unwrap_or_else(|| Self::DEFAULT_MODEL.to_string()),
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+impl AnthropicBackend {
+    /// Send the request, retrying rate limits, overloads, server errors and network
+    /// failures with backoff
+    async fn send(&self, prompt: &str, max_tokens: u32, stream: bool) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let (error, retry_after) = match self.send_once(prompt, max_tokens, stream).await {
+                Err(AppError::ClaudeApi(e)) if e.is_transient() => {
+                    let retry_after = e.retry_after;
+                    (AppError::ClaudeApi(e), retry_after)
+                }
+                Err(AppError::Http(e)) if e.is_timeout() || e.is_connect() => {
+                    (AppError::Http(e), None)
+                }
+                result => return result,
+            };
+
+            match self.retry.delay(attempt, retry_after) {
+                Some(delay) if attempt + 1 < self.retry.max_attempts => {
+                    tracing::warn!(
+                        "Claude request failed ({}), retrying in {:.1}s",
+                        error,
+                        delay.as_secs_f64()
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+
+    async fn send_once(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        stream: bool,
+    ) -> Result<reqwest::Response> {
+        let request = MessageRequest {
+            model: &self.model,
+            max_tokens,
+            messages: vec![Message {
+                role: "user",
+                content: prompt,
+            }],
+            system: None,
+            stream,
+        };
+
+        let url = format!("{}/v1/messages", self.base_url.trim_end_matches('/'));
+        let response = self
+            .client
+            .post(&url)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&request)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let retry_after = response
+                .headers()
+                .get("retry-after")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let error_text = response.text().await?;
+            let error = match serde_json::from_str::<ErrorResponse>(&error_text) {
+                Ok(body) => ClaudeApiError::new(
+                    status,
+                    Some(&body.error.error_type),
+                    body.error.message,
+                    retry_after,
+                ),
+                Err(_) => ClaudeApiError::new(
+                    status,
+                    None,
+                    truncate_for_error(&error_text, 500).to_string(),
+                    retry_after,
+                ),
+            };
+            return Err(AppError::ClaudeApi(error));
+        }
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl SummaryBackend for AnthropicBackend {
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<Completion> {
+        let response = self.send(prompt, max_tokens, false).await?;
+        let message_response: MessageResponse = response.json().await?;
+
+        let text = message_response
+            .content
+            .into_iter()
+            .filter_map(|block| block.text)
+            .collect::<Vec<_>>()
+            .join("\n");
+        Ok(Completion {
+            text,
+            usage: message_response.usage.map(|u| Usage {
+                input_tokens: u.input_tokens,
+                output_tokens: u.output_tokens,
+            }),
+        })
+    }
+
+    async fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mpsc::UnboundedSender<String>,
+    ) -> Result<Completion> {
+        let mut response = self.send(prompt, max_tokens, true).await?;
+
+        let mut parser = SseParser::new();
+        let mut text = String::new();
+        let mut usage: Option<Usage> = None;
+        while let Some(chunk) = response.chunk().await? {
+            for event in parser.feed(&chunk) {
+                let Ok(event) = serde_json::from_str::<StreamEvent>(&event.data) else {
+                    continue;
+                };
+                match event.event_type.as_str() {
+                    "message_start" => {
+                        if let Some(u) = event.message.and_then(|m| m.usage) {
+                            usage.get_or_insert_with(Usage::default).input_tokens = u.input_tokens;
+                        }
+                    }
+                    "message_delta" => {
+                        if let Some(u) = event.usage {
+                            usage.get_or_insert_with(Usage::default).output_tokens =
+                                u.output_tokens;
+                        }
+                    }
+                    "content_block_delta" => {
+                        if let Some(delta) = event.delta.and_then(|d| d.text) {
+                            text.push_str(&delta);
+                            let _ = on_text.send(delta);
+                        }
+                    }
+                    "error" => {
+                        let error = match event.error {
+                            Some(e) => ClaudeApiError::new(0, Some(&e.error_type), e.message, None),
+                            None => ClaudeApiError::new(0, None, "stream error".to_string(), None),
+                        };
+                        return Err(AppError::ClaudeApi(error));
+                    }
+                    "message_stop" => return Ok(Completion { text, usage }),
+                    _ => {}
+                }
+            }
+        }
+        Ok(Completion { text, usage })
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ClaudeErrorKind;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Backend against a mock server, retrying without real waits
+    fn test_backend(server: &MockServer) -> AnthropicBackend {
+        let mut backend = AnthropicBackend::new("key".into(), Some(server.uri()), None);
+        backend.retry = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_secs(2),
+        };
+        backend
+    }
+
+    fn api_error(status: u16, error_type: &str, message: &str) -> ResponseTemplate {
+        ResponseTemplate::new(status).set_body_json(serde_json::json!({
+            "type": "error",
+            "error": {"type": error_type, "message": message}
+        }))
+    }
+
+    fn text_response(text: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "content": [{"type": "text", "text": text}]
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_complete_sends_messages_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .and(header("x-api-key", "sk-ant-test"))
+            .and(header("anthropic-version", "2023-06-01"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "claude-test",
+                "max_tokens": 256,
+                "messages": [{"role": "user", "content": "Summarize this"}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "content": [
+                    {"type": "text", "text": "First line"},
+                    {"type": "text", "text": "Second line"}
+                ],
+                "usage": {"input_tokens": 1200, "output_tokens": 85}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let backend = AnthropicBackend::new(
+            "sk-ant-test".into(),
+            Some(server.uri()),
+            Some("claude-test".into()),
+        );
+        let completion = backend.complete("Summarize this", 256).await.unwrap();
+        assert_eq!(completion.text, "First line\nSecond line");
+        assert_eq!(
+            completion.usage,
+            Some(Usage {
+                input_tokens: 1200,
+                output_tokens: 85
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_reports_api_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("invalid x-api-key"))
+            .mount(&server)
+            .await;
+
+        let backend = AnthropicBackend::new("bad".into(), Some(server.uri()), None);
+        let err = backend.complete("prompt", 100).await.unwrap_err();
+        assert!(
+            matches!(err, AppError::ClaudeApi(ref e) if e.kind == ClaudeErrorKind::Authentication && e.message.contains("invalid x-api-key"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_streaming_sends_deltas() {
+        let body = concat!(
+            "event: message_start\n",
+            "data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_1\",\"usage\":{\"input_tokens\":900,\"output_tokens\":1}}}\n\n",
+            "event: content_block_start\n",
+            "data: {\"type\":\"content_block_start\",\"index\":0,\"content_block\":{\"type\":\"text\",\"text\":\"\"}}\n\n",
+            "event: ping\n",
+            "data: {\"type\":\"ping\"}\n\n",
+            "event: content_block_delta\n",
+            "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hello\"}}\n\n",
+            "event: content_block_delta\n",
+            "data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\", world\"}}\n\n",
+            "event: message_delta\n",
+            "data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\"},\"usage\":{\"output_tokens\":42}}\n\n",
+            "event: message_stop\n",
+            "data: {\"type\":\"message_stop\"}\n\n",
+        );
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .and(body_partial_json(serde_json::json!({"stream": true})))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(body),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let backend = AnthropicBackend::new("key".into(), Some(server.uri()), None);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let completion = backend
+            .complete_streaming("prompt", 100, &tx)
+            .await
+            .unwrap();
+        assert_eq!(completion.text, "Hello, world");
+        assert_eq!(
+            completion.usage,
+            Some(Usage {
+                input_tokens: 900,
+                output_tokens: 42
+            })
+        );
+
+        drop(tx);
+        let mut pieces = Vec::new();
+        while let Some(piece) = rx.recv().await {
+            pieces.push(piece);
+        }
+        assert_eq!(pieces, vec!["Hello", ", world"]);
+    }
+
+    #[tokio::test]
+    async fn test_complete_streaming_reports_error_events() {
+        let body = concat!(
+            "event: error\n",
+            "data: {\"type\":\"error\",\"error\":{\"type\":\"overloaded_error\",\"message\":\"Overloaded\"}}\n\n",
+        );
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .mount(&server)
+            .await;
+
+        let backend = AnthropicBackend::new("key".into(), Some(server.uri()), None);
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let err = backend
+            .complete_streaming("prompt", 100, &tx)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, AppError::ClaudeApi(ref e) if e.kind == ClaudeErrorKind::Overloaded && e.message == "Overloaded")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transient_errors_are_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(api_error(529, "overloaded_error", "Overloaded"))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(text_response("Third time lucky"))
+            .mount(&server)
+            .await;
+
+        let completion = test_backend(&server).complete("prompt", 100).await.unwrap();
+        assert_eq!(completion.text, "Third time lucky");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_give_up_after_max_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(api_error(500, "api_error", "Internal server error"))
+            .mount(&server)
+            .await;
+
+        let err = test_backend(&server)
+            .complete("prompt", 100)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::ClaudeApi(ref e) if e.kind == ClaudeErrorKind::Server));
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_honors_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                api_error(
+                    429,
+                    "rate_limit_error",
+                    "Number of request tokens has exceeded your per-minute rate limit",
+                )
+                .insert_header("retry-after", "1"),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(text_response("ok"))
+            .mount(&server)
+            .await;
+
+        let started = std::time::Instant::now();
+        let completion = test_backend(&server).complete("prompt", 100).await.unwrap();
+        assert_eq!(completion.text, "ok");
+        assert!(started.elapsed() >= Duration::from_secs(1));
+
+        // A wait longer than the policy allows is reported instead of waited out
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                api_error(429, "rate_limit_error", "Rate limited")
+                    .insert_header("retry-after", "60"),
+            )
+            .mount(&server)
+            .await;
+        let err = test_backend(&server)
+            .complete("prompt", 100)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::ClaudeApi(ref e) if e.retry_after == Some(Duration::from_secs(60))
+        ));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_errors_are_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(api_error(
+                400,
+                "invalid_request_error",
+                "prompt is too long: 210000 tokens > 200000 maximum",
+            ))
+            .mount(&server)
+            .await;
+
+        let err = test_backend(&server)
+            .complete("prompt", 100)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, AppError::ClaudeApi(ref e) if e.kind == ClaudeErrorKind::ContextTooLong)
+        );
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use crate::config::{SummarizerBackend, SummarizerConfig};
+use crate::error::Result;
+
+use super::anthropic::AnthropicBackend;
+use super::ollama::OllamaBackend;
+use super::openai::OpenAiBackend;
+
+/// Tokens a request used, as reported by the backend
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+}
+
+/// A model's reply and what it cost, when the backend reports usage
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completion {
+    pub text: String,
+    pub usage: Option<Usage>,
+}
+
+/// A model that turns a prompt into text. Implemented for the Anthropic Messages API,
+/// OpenAI-compatible chat completions (OpenAI, llama.cpp, LM Studio, vLLM...) and Ollama.
+#[async_trait]
+pub trait SummaryBackend: Send + Sync {
+    /// Send a single user prompt and return the model's reply
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<Completion>;
+
+    /// Like `complete`, but also sends the reply to `on_text` piece by piece as the model
+    /// writes it. Backends that can't stream send the whole reply at once.
+    async fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mpsc::UnboundedSender<String>,
+    ) -> Result<Completion> {
+        let completion = self.complete(prompt, max_tokens).await?;
+        let _ = on_text.send(completion.text.clone());
+        Ok(completion)
+    }
+
+    /// Model name, stored with each summary
+    fn model(&self) -> &str;
+}
+
+/// Build the backend selected in config. `claude_api_key` is the top-level key, used by the
+/// Anthropic backend when `[summarizer]` doesn't set one. Returns None when a hosted
+/// backend has no API key.
+pub fn backend_from_config(
+    config: &SummarizerConfig,
+    claude_api_key: Option<&str>,
+) -> Option<Box<dyn SummaryBackend>> {
+    let base_url = config.base_url.clone();
+    let model = config.model.clone();
+
+    match config.backend {
+        SummarizerBackend::Anthropic => {
+            let api_key = config.api_key.as_deref().or(claude_api_key)?;
+            Some(Box::new(AnthropicBackend::new(
+                api_key.to_string(),
+                base_url,
+                model,
+            )))
+        }
+        SummarizerBackend::OpenAi => {
+            let api_key = config.api_key.clone()?;
+            Some(Box::new(OpenAiBackend::new(
+                Some(api_key),
+                base_url.unwrap_or_else(|| OpenAiBackend::OPENAI_URL.to_string()),
+                model.unwrap_or_else(|| OpenAiBackend::OPENAI_MODEL.to_string()),
+            )))
+        }
+        // Local llama.cpp server; the key is optional (llama-server --api-key)
+        SummarizerBackend::LlamaCpp => Some(Box::new(OpenAiBackend::new(
+            config.api_key.clone(),
+            base_url.unwrap_or_else(|| OpenAiBackend::LLAMA_CPP_URL.to_string()),
+            model.unwrap_or_else(|| OpenAiBackend::LLAMA_CPP_MODEL.to_string()),
+        ))),
+        SummarizerBackend::Ollama => Some(Box::new(OllamaBackend::new(base_url, model))),
+    }
+}
+
+/// Cut `text` to at most `max` bytes on a UTF-8 boundary, for logging error bodies
+pub(super) fn truncate_for_error(text: &str, max: usize) -> &str {
+    if text.len() <= max {
+        return text;
+    }
+    let mut end = max;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anthropic_needs_a_key() {
+        let config = SummarizerConfig::default();
+        assert!(backend_from_config(&config, None).is_none());
+
+        let backend = backend_from_config(&config, Some("sk-ant-test")).unwrap();
+        assert_eq!(backend.model(), AnthropicBackend::DEFAULT_MODEL);
+    }
+
+    #[test]
+    fn test_openai_needs_a_key() {
+        let config = SummarizerConfig {
+            backend: SummarizerBackend::OpenAi,
+            ..Default::default()
+        };
+        // The Claude key is not sent to another provider
+        assert!(backend_from_config(&config, Some("sk-ant-test")).is_none());
+
+        let config = SummarizerConfig {
+            api_key: Some("sk-test".into()),
+            ..config
+        };
+        let backend = backend_from_config(&config, None).unwrap();
+        assert_eq!(backend.model(), OpenAiBackend::OPENAI_MODEL);
+    }
+
+    #[test]
+    fn test_local_backends_need_no_key() {
+        let config = SummarizerConfig {
+            backend: SummarizerBackend::Ollama,
+            model: Some("mistral".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            backend_from_config(&config, None).unwrap().model(),
+            "mistral"
+        );
+
+        let config = SummarizerConfig {
+            backend: SummarizerBackend::LlamaCpp,
+            ..Default::default()
+        };
+        assert!(backend_from_config(&config, None).is_some());
+    }
+
+    #[test]
+    fn test_truncate_for_error() {
+        assert_eq!(truncate_for_error("short", 10), "short");
+        assert_eq!(truncate_for_error("héllo", 2), "h");
+    }
+}
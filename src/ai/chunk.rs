@@ -0,0 +1,109 @@
+/// Cut `text` at a char boundary at or before `max_bytes`
+pub fn truncate(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while end > 0 && !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// Split `text` into chunks of at most `max_bytes`, breaking between paragraphs where
+/// possible, else between sentences, else between words. Chunks are trimmed and never
+/// empty.
+pub fn split(text: &str, max_bytes: usize) -> Vec<&str> {
+    let max_bytes = max_bytes.max(1);
+    let mut chunks = Vec::new();
+    let mut rest = text.trim();
+    while !rest.is_empty() {
+        if rest.len() <= max_bytes {
+            chunks.push(rest);
+            break;
+        }
+        let window = truncate(rest, max_bytes);
+        let end = break_point(window).unwrap_or(window.len());
+        // A single character wider than max_bytes still has to go somewhere
+        let end = if end == 0 {
+            rest.chars().next().map_or(rest.len(), char::len_utf8)
+        } else {
+            end
+        };
+        let chunk = rest[..end].trim();
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+        rest = rest[end..].trim_start();
+    }
+    chunks
+}
+
+/// Where to end a chunk within `window`: after the last paragraph break, sentence end or
+/// space, whichever kind comes first in that order. None when there's none of them.
+fn break_point(window: &str) -> Option<usize> {
+    // Breaks in the first quarter would make tiny chunks; prefer a weaker break later on
+    let min = window.len() / 4;
+    let candidates = [
+        window.rfind("\n\n").map(|i| i + 2),
+        window.rfind('\n').map(|i| i + 1),
+        [". ", "? ", "! ", ".\" ", ".” "]
+            .iter()
+            .filter_map(|end| window.rfind(end).map(|i| i + end.len()))
+            .max(),
+        window
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map(|(i, c)| i + c.len_utf8()),
+    ];
+    candidates.into_iter().flatten().find(|&end| end > min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_respects_char_boundary() {
+        assert_eq!(truncate("short", 100), "short");
+        assert_eq!(truncate("héllo", 2), "h");
+        assert_eq!(truncate("héllo", 3), "hé");
+    }
+
+    #[test]
+    fn test_short_text_is_one_chunk() {
+        assert_eq!(split("  One paragraph.  ", 100), vec!["One paragraph."]);
+        assert!(split("   ", 100).is_empty());
+    }
+
+    #[test]
+    fn test_split_between_paragraphs() {
+        let text = "First paragraph here.\n\nSecond paragraph here.\n\nThird paragraph here.";
+        let chunks = split(text, 50);
+        assert_eq!(
+            chunks,
+            vec![
+                "First paragraph here.\n\nSecond paragraph here.",
+                "Third paragraph here."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_long_paragraph_between_sentences() {
+        let text = "One sentence is here. Another sentence follows it. A third one ends it.";
+        let chunks = split(text, 40);
+        assert_eq!(chunks[0], "One sentence is here.");
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 40));
+        assert_eq!(chunks.join(" "), text);
+    }
+
+    #[test]
+    fn test_split_without_breaks_keeps_all_text() {
+        let text = "é".repeat(30);
+        let chunks = split(&text, 7);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 7));
+        assert_eq!(chunks.concat(), text);
+    }
+}
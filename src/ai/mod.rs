@@ -1,3 +1,18 @@
+mod anthropic;
+mod backend;
+mod chunk;
+mod ollama;
+mod openai;
+mod pricing;
+mod prompt;
+mod quotes;
+mod retry;
+mod sse;
+mod structured;
 mod summarizer;
+mod tags;
 
-pub use summarizer::Summarizer;
+pub use chunk::truncate;
+pub use prompt::PromptFields;
+pub use quotes::strip_unverified;
+pub use summarizer::{preview_summary, GeneratedSummary, Summarizer};
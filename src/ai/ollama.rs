@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AppError, Result};
+
+use super::backend::{truncate_for_error, Completion, SummaryBackend, Usage};
+
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    stream: bool,
+    options: Options,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct Options {
+    num_predict: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    message: ResponseMessage,
+    /// Input tokens evaluated (missing when the prompt was cached)
+    prompt_eval_count: Option<u32>,
+    /// Output tokens generated
+    eval_count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseMessage {
+    content: String,
+}
+
+/// Ollama's native chat API
+pub struct OllamaBackend {
+    client: Client,
+    base_url: String,
+    model: String,
+}
+
+impl OllamaBackend {
+    pub const DEFAULT_URL: &'static str = "http://localhost:11434";
+    pub const DEFAULT_MODEL: &'static str = "llama3.2";
+
+    pub fn new(base_url: Option<String>, model: Option<String>) -> Self {
+        // Local models can take a while on long articles
+        let client = Client::builder()
+            .timeout(Duration::from_secs(180))
+            .build()
+            .expect("Failed to create HTTP client");
+        Self {
+            client,
+            base_url: base_url.unwrap_or_else(|| Self::DEFAULT_URL.to_string()),
+            model: model.unwrap_or_else(|| Self::DEFAULT_MODEL.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl SummaryBackend for OllamaBackend {
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<Completion> {
+        let request = ChatRequest {
+            model: &self.model,
+            messages: vec![ChatMessage {
+                role: "user",
+                content: prompt,
+            }],
+            stream: false,
+            options: Options {
+                num_predict: max_tokens,
+            },
+        };
+
+        let url = format!("{}/api/chat", self.base_url.trim_end_matches('/'));
+        let response = self.client.post(&url).json(&request).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await?;
+            return Err(AppError::SummaryApi(format!(
+                "Ollama returned {}: {}",
+                status,
+                truncate_for_error(&error_text, 500)
+            )));
+        }
+
+        let chat: ChatResponse = response.json().await?;
+        let usage = chat.eval_count.map(|output_tokens| Usage {
+            input_tokens: chat.prompt_eval_count.unwrap_or_default(),
+            output_tokens,
+        });
+        Ok(Completion {
+            text: chat.message.content,
+            usage,
+        })
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_complete_sends_chat_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/chat"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "mistral",
+                "stream": false,
+                "options": {"num_predict": 300},
+                "messages": [{"role": "user", "content": "Summarize this"}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "model": "mistral",
+                "message": {"role": "assistant", "content": "Local summary"},
+                "done": true,
+                "prompt_eval_count": 320,
+                "eval_count": 48
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let backend = OllamaBackend::new(Some(server.uri()), Some("mistral".into()));
+        let completion = backend.complete("Summarize this", 300).await.unwrap();
+        assert_eq!(completion.text, "Local summary");
+        assert_eq!(
+            completion.usage,
+            Some(Usage {
+                input_tokens: 320,
+                output_tokens: 48
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_model_is_an_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({"error": "model 'nope' not found"})),
+            )
+            .mount(&server)
+            .await;
+
+        let backend = OllamaBackend::new(Some(server.uri()), Some("nope".into()));
+        let err = backend.complete("prompt", 100).await.unwrap_err();
+        assert!(matches!(err, AppError::SummaryApi(ref msg) if msg.contains("not found")));
+    }
+}
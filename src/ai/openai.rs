@@ -0,0 +1,352 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+use crate::error::{AppError, Result};
+
+use super::backend::{truncate_for_error, Completion, SummaryBackend, Usage};
+use super::sse::SseParser;
+
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    max_tokens: u32,
+    messages: Vec<ChatMessage<'a>>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+    /// Asks for a final chunk with token usage when streaming
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+}
+
+#[derive(Debug, Serialize)]
+struct StreamOptions {
+    include_usage: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    choices: Vec<Choice>,
+    usage: Option<ResponseUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseUsage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+}
+
+impl From<ResponseUsage> for Usage {
+    fn from(usage: ResponseUsage) -> Self {
+        Usage {
+            input_tokens: usage.prompt_tokens,
+            output_tokens: usage.completion_tokens,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Choice {
+    message: ChoiceMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChoiceMessage {
+    content: Option<String>,
+}
+
+/// One `data:` line of a streamed completion
+#[derive(Debug, Deserialize)]
+struct ChatChunk {
+    choices: Vec<ChunkChoice>,
+    usage: Option<ResponseUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChunkChoice {
+    delta: ChoiceMessage,
+}
+
+/// OpenAI-compatible chat completions: OpenAI itself, and local servers that speak the
+/// same API such as llama.cpp's llama-server, LM Studio and vLLM
+pub struct OpenAiBackend {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+    model: String,
+}
+
+impl OpenAiBackend {
+    pub const OPENAI_URL: &'static str = "https://api.openai.com/v1";
+    pub const OPENAI_MODEL: &'static str = "gpt-4o-mini";
+    pub const LLAMA_CPP_URL: &'static str = "http://localhost:8080/v1";
+    /// llama-server answers with whatever model it loaded, whatever the name
+    pub const LLAMA_CPP_MODEL: &'static str = "local";
+
+    pub fn new(api_key: Option<String>, base_url: String, model: String) -> Self {
+        // Local models can take a while on long articles
+        let client = Client::builder()
+            .timeout(Duration::from_secs(180))
+            .build()
+            .expect("Failed to create HTTP client");
+        Self {
+            client,
+            base_url,
+            api_key,
+            model,
+        }
+    }
+}
+
+impl OpenAiBackend {
+    async fn send(&self, prompt: &str, max_tokens: u32, stream: bool) -> Result<reqwest::Response> {
+        let request = ChatRequest {
+            model: &self.model,
+            max_tokens,
+            messages: vec![ChatMessage {
+                role: "user",
+                content: prompt,
+            }],
+            stream,
+            stream_options: stream.then_some(StreamOptions {
+                include_usage: true,
+            }),
+        };
+
+        let url = format!("{}/chat/completions", self.base_url.trim_end_matches('/'));
+        let mut builder = self.client.post(&url).json(&request);
+        if let Some(key) = &self.api_key {
+            builder = builder.bearer_auth(key);
+        }
+        let response = builder.send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await?;
+            return Err(AppError::SummaryApi(format!(
+                "{} returned {}: {}",
+                url,
+                status,
+                truncate_for_error(&error_text, 500)
+            )));
+        }
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl SummaryBackend for OpenAiBackend {
+    async fn complete(&self, prompt: &str, max_tokens: u32) -> Result<Completion> {
+        let response = self.send(prompt, max_tokens, false).await?;
+        let chat: ChatResponse = response.json().await?;
+        let text = chat
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.message.content)
+            .ok_or_else(|| AppError::SummaryApi("Response had no message content".to_string()))?;
+        Ok(Completion {
+            text,
+            usage: chat.usage.map(Usage::from),
+        })
+    }
+
+    async fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mpsc::UnboundedSender<String>,
+    ) -> Result<Completion> {
+        let mut response = self.send(prompt, max_tokens, true).await?;
+
+        let mut parser = SseParser::new();
+        let mut text = String::new();
+        let mut usage = None;
+        while let Some(chunk) = response.chunk().await? {
+            for event in parser.feed(&chunk) {
+                if event.data == "[DONE]" {
+                    return Ok(Completion { text, usage });
+                }
+                let Ok(chunk) = serde_json::from_str::<ChatChunk>(&event.data) else {
+                    continue;
+                };
+                if let Some(u) = chunk.usage {
+                    usage = Some(Usage::from(u));
+                }
+                if let Some(delta) = chunk
+                    .choices
+                    .into_iter()
+                    .next()
+                    .and_then(|choice| choice.delta.content)
+                {
+                    text.push_str(&delta);
+                    let _ = on_text.send(delta);
+                }
+            }
+        }
+        Ok(Completion { text, usage })
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    fn chat_response(text: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": text},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 700, "completion_tokens": 60, "total_tokens": 760}
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_complete_sends_chat_completion() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(header("authorization", "Bearer sk-test"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "gpt-test",
+                "max_tokens": 512,
+                "messages": [{"role": "user", "content": "Summarize this"}]
+            })))
+            .respond_with(chat_response("A summary"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let backend = OpenAiBackend::new(
+            Some("sk-test".into()),
+            format!("{}/v1/", server.uri()),
+            "gpt-test".into(),
+        );
+        let completion = backend.complete("Summarize this", 512).await.unwrap();
+        assert_eq!(completion.text, "A summary");
+        assert_eq!(
+            completion.usage,
+            Some(Usage {
+                input_tokens: 700,
+                output_tokens: 60
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_server_without_key_sends_no_auth_header() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .respond_with(chat_response("Local summary"))
+            .mount(&server)
+            .await;
+        Mock::given(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(500))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+
+        let backend = OpenAiBackend::new(None, format!("{}/v1", server.uri()), "local".into());
+        assert_eq!(
+            backend.complete("prompt", 100).await.unwrap().text,
+            "Local summary"
+        );
+        let requests: Vec<Request> = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_complete_reports_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("model not found"))
+            .mount(&server)
+            .await;
+
+        let backend = OpenAiBackend::new(None, server.uri(), "missing".into());
+        let err = backend.complete("prompt", 100).await.unwrap_err();
+        assert!(matches!(err, AppError::SummaryApi(ref msg) if msg.contains("model not found")));
+    }
+
+    #[tokio::test]
+    async fn test_complete_rejects_empty_choices() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"choices": []})),
+            )
+            .mount(&server)
+            .await;
+
+        let backend = OpenAiBackend::new(None, server.uri(), "local".into());
+        assert!(backend.complete("prompt", 100).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_complete_streaming_collects_deltas() {
+        let body = concat!(
+            "data: {\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\"}}]}\n\n",
+            "data: {\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Local \"}}]}\n\n",
+            "data: {\"choices\":[{\"index\":0,\"delta\":{\"content\":\"summary\"}}]}\n\n",
+            "data: {\"choices\":[],\"usage\":{\"prompt_tokens\":50,\"completion_tokens\":2}}\n\n",
+            "data: [DONE]\n\n",
+        );
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/chat/completions"))
+            .and(body_partial_json(serde_json::json!({
+                "stream": true,
+                "stream_options": {"include_usage": true}
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(body),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let backend = OpenAiBackend::new(None, format!("{}/v1", server.uri()), "local".into());
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let completion = backend
+            .complete_streaming("prompt", 100, &tx)
+            .await
+            .unwrap();
+        assert_eq!(completion.text, "Local summary");
+        assert_eq!(
+            completion.usage,
+            Some(Usage {
+                input_tokens: 50,
+                output_tokens: 2
+            })
+        );
+
+        drop(tx);
+        let mut pieces = Vec::new();
+        while let Some(piece) = rx.recv().await {
+            pieces.push(piece);
+        }
+        assert_eq!(pieces, vec!["Local ", "summary"]);
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use crate::config::ModelPrice;
+
+use super::backend::Usage;
+
+/// Published prices in USD per million tokens (input, output), matched by model name
+/// prefix so dated snapshots like `claude-haiku-4-5-20251001` find their family.
+/// Config entries are added on top and win.
+const BUILTIN_PRICES: &[(&str, f64, f64)] = &[
+    ("claude-haiku-4-5", 1.0, 5.0),
+    ("claude-3-5-haiku", 0.8, 4.0),
+    ("claude-3-haiku", 0.25, 1.25),
+    ("claude-sonnet-4", 3.0, 15.0),
+    ("claude-3-7-sonnet", 3.0, 15.0),
+    ("claude-opus-4-5", 5.0, 25.0),
+    ("claude-opus-4-1", 15.0, 75.0),
+    ("claude-opus-4", 15.0, 75.0),
+    ("gpt-4o-mini", 0.15, 0.6),
+    ("gpt-4o", 2.5, 10.0),
+    ("gpt-4.1-nano", 0.1, 0.4),
+    ("gpt-4.1-mini", 0.4, 1.6),
+    ("gpt-4.1", 2.0, 8.0),
+];
+
+/// Token prices by model
+#[derive(Debug, Clone)]
+pub struct PriceTable {
+    prices: BTreeMap<String, ModelPrice>,
+}
+
+impl Default for PriceTable {
+    fn default() -> Self {
+        Self::new(&BTreeMap::new())
+    }
+}
+
+impl PriceTable {
+    /// The built-in prices plus `overrides` from config
+    pub fn new(overrides: &BTreeMap<String, ModelPrice>) -> Self {
+        let mut prices: BTreeMap<String, ModelPrice> = BUILTIN_PRICES
+            .iter()
+            .map(|&(model, input, output)| (model.to_string(), ModelPrice { input, output }))
+            .collect();
+        prices.extend(overrides.iter().map(|(k, v)| (k.clone(), *v)));
+        Self { prices }
+    }
+
+    /// Price for a model: an exact match, else the longest matching prefix
+    pub fn price(&self, model: &str) -> Option<ModelPrice> {
+        if let Some(price) = self.prices.get(model) {
+            return Some(*price);
+        }
+        self.prices
+            .iter()
+            .filter(|(name, _)| model.starts_with(name.as_str()))
+            .max_by_key(|(name, _)| name.len())
+            .map(|(_, price)| *price)
+    }
+
+    /// Cost of a request in USD, or None when the model has no known price
+    pub fn cost(&self, model: &str, usage: Usage) -> Option<f64> {
+        let price = self.price(model)?;
+        Some(
+            (f64::from(usage.input_tokens) * price.input
+                + f64::from(usage.output_tokens) * price.output)
+                / 1_000_000.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(input_tokens: u32, output_tokens: u32) -> Usage {
+        Usage {
+            input_tokens,
+            output_tokens,
+        }
+    }
+
+    #[test]
+    fn test_cost_uses_longest_prefix() {
+        let table = PriceTable::default();
+        // 2000 * $1/M + 500 * $5/M
+        let cost = table
+            .cost("claude-haiku-4-5-20251001", usage(2000, 500))
+            .unwrap();
+        assert!((cost - 0.0045).abs() < 1e-9);
+
+        // gpt-4o-mini must not get gpt-4o's price
+        assert_eq!(table.price("gpt-4o-mini-2024-07-18").unwrap().input, 0.15);
+        assert_eq!(table.price("gpt-4o-2024-08-06").unwrap().input, 2.5);
+    }
+
+    #[test]
+    fn test_unknown_model_has_no_cost() {
+        let table = PriceTable::default();
+        assert_eq!(table.cost("llama3.2", usage(100, 100)), None);
+    }
+
+    #[test]
+    fn test_config_prices_override_builtins() {
+        let overrides = BTreeMap::from([
+            (
+                "claude-haiku-4-5".to_string(),
+                ModelPrice {
+                    input: 2.0,
+                    output: 10.0,
+                },
+            ),
+            (
+                "llama3.2".to_string(),
+                ModelPrice {
+                    input: 0.0,
+                    output: 0.0,
+                },
+            ),
+        ]);
+        let table = PriceTable::new(&overrides);
+        assert_eq!(table.price("claude-haiku-4-5-20251001").unwrap().input, 2.0);
+        assert_eq!(table.cost("llama3.2", usage(100, 100)), Some(0.0));
+    }
+}
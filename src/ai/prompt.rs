@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+
+use crate::models::{ChatMessage, ChatRole};
+
+/// Name of the built-in nut graph template
+pub const DEFAULT_TEMPLATE: &str = "nutgraph";
+
+/// The built-in nut graph prompt. It asks for JSON so each part of the summary can be
+/// stored and shown on its own; see `structured::parse`.
+const NUT_GRAPH_PROMPT: &str = r#"You are a journalist summarizing articles using the nut graph structure. Summarize the article below.
+
+First, determine: Is this article primarily about a specific PRODUCT (hardware, software, app, device) or is it EDITORIAL (news, policy, analysis, industry event)?
+
+RULES:
+1. Use ONLY information from the article - no external knowledge
+2. If the article has insufficient content, respond with just: Insufficient content for summary
+3. The quote must be copied VERBATIM from the article — the exact words as they appear, with clear speaker attribution. Do not paraphrase or alter the quote in any way.
+
+Respond with ONLY a JSON object, no other text, with these keys:
+
+"type": "editorial" or "product"
+"lede": If EDITORIAL, one strong sentence identifying WHO is involved and WHAT happened or was announced. If PRODUCT, what the product is and what it does (1-2 sentences).
+"why_it_matters": If EDITORIAL, a paragraph (2-4 sentences) explaining WHY this matters. Contextualize the most important facts and give the reader a clear understanding of the central issue or topic. null for PRODUCT.
+"price": Pricing details, or null if not mentioned or EDITORIAL.
+"availability": When and where it is available, or null if not mentioned or EDITORIAL.
+"platforms": What platforms or operating systems it runs on, or null for hardware-only products, if not mentioned, or EDITORIAL.
+"quote": An exact verbatim quote from the article, without quotation marks, or null if there are no direct quotes with clear speaker attribution.
+"speaker": The name of the person quoted, or null.
+
+Title: {title}
+
+Article:
+{content}"#;
+
+/// Prompt for the notes on one part of a long article. The notes, in order, stand in
+/// for the article text when the final summary is written.
+const CHUNK_PROMPT: &str = r#"You are taking notes on part {part} of {parts} of a long article, to be combined into a summary later.
+
+RULES:
+1. Use ONLY information from this part - no external knowledge
+2. Write short plain-text notes: who is involved, what happened, key facts, figures, prices and dates
+3. Copy up to three direct quotes VERBATIM, in quotation marks, each followed by -- and the speaker's name. Only quotes with clear speaker attribution. Do not paraphrase or alter them.
+4. No headings or preamble
+
+Title: {title}
+
+Part {part} of {parts}:
+{content}"#;
+
+/// Prompt for a follow-up question about an article; the conversation so far and the
+/// question follow it
+const CHAT_PROMPT: &str = r#"You are helping a journalist who has just read the article below and has a follow-up question about it.
+
+RULES:
+1. Answer ONLY from the article - no external knowledge, even if you know the answer
+2. If the article doesn't say, answer that the article doesn't say, and mention anything related it does say
+3. Quote the article VERBATIM, in quotation marks, when its exact words matter
+4. Be brief: a few sentences, no headings or preamble
+
+Title: {title}
+
+Article:
+{content}"#;
+
+/// Prompt for choosing bookmark tags for an article; the allowed tags follow it
+const TAG_PROMPT: &str = r#"Choose tags for a bookmark of the article below. Use ONLY tags from the list of allowed tags, spelled exactly as listed. Choose up to five that clearly fit the article, most fitting first, and fewer if only a few fit.
+
+Respond with ONLY the chosen tags, separated by commas. If none fit, respond with: none
+
+Title: {title}
+
+Article:
+{content}"#;
+
+/// Prompt for translating an article's title and text into `{language}`
+const TRANSLATE_PROMPT: &str = r#"Translate the article title and text below into {language}. Translate everything faithfully: keep names, numbers and quotes exact, and don't summarize, explain or add anything.
+
+Respond with the translated title on the first line, then a blank line, then the translated text. No preamble.
+
+Title: {title}
+
+Text:
+{content}"#;
+
+/// Heading for the combined notes that replace the text of a long article
+const NOTES_HEADING: &str = "(Notes on each part of a long article, in order. Quotes in the notes are verbatim from the article.)";
+
+/// Article fields available to prompt templates
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PromptFields<'a> {
+    pub title: &'a str,
+    pub content: &'a str,
+    pub feed: Option<&'a str>,
+    pub author: Option<&'a str>,
+}
+
+/// Built-in templates plus the ones from config (which may override a built-in by name)
+pub fn templates_with_builtins(custom: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    let mut templates = BTreeMap::new();
+    templates.insert(DEFAULT_TEMPLATE.to_string(), NUT_GRAPH_PROMPT.to_string());
+    templates.extend(custom.iter().map(|(k, v)| (k.clone(), v.clone())));
+    templates
+}
+
+/// Prompt for the notes on part `part` (1-based) of `parts` of an article
+pub fn chunk_prompt(title: &str, part: usize, parts: usize, content: &str) -> String {
+    let template = CHUNK_PROMPT
+        .replace("{part}", &part.to_string())
+        .replace("{parts}", &parts.to_string());
+    render(
+        &template,
+        &PromptFields {
+            title,
+            content,
+            ..Default::default()
+        },
+    )
+}
+
+/// Prompt for `question` about an article, after the earlier questions and answers
+pub fn chat_prompt(title: &str, content: &str, history: &[ChatMessage], question: &str) -> String {
+    let mut prompt = render(
+        CHAT_PROMPT,
+        &PromptFields {
+            title,
+            content,
+            ..Default::default()
+        },
+    );
+    if !history.is_empty() {
+        prompt.push_str("\n\nConversation so far:");
+        for message in history {
+            let speaker = match message.role {
+                ChatRole::User => "Question",
+                ChatRole::Assistant => "Answer",
+            };
+            prompt.push_str(&format!("\n{}: {}", speaker, message.content.trim()));
+        }
+    }
+    prompt.push_str(&format!("\n\nQuestion: {}", question.trim()));
+    prompt
+}
+
+/// Prompt for tags for an article, chosen from `vocabulary`
+pub fn tag_prompt(title: &str, content: &str, vocabulary: &[String]) -> String {
+    let mut prompt = render(
+        TAG_PROMPT,
+        &PromptFields {
+            title,
+            content,
+            ..Default::default()
+        },
+    );
+    prompt.push_str("\n\nAllowed tags: ");
+    prompt.push_str(&vocabulary.join(", "));
+    prompt
+}
+
+/// Tell the model which language to answer in, after a rendered template
+pub fn with_language(prompt: String, language: &str) -> String {
+    format!(
+        "{}\n\nWrite your response in {}, translating from the article where needed. If the \
+         response is JSON, keep its keys and the \"type\" value as specified.",
+        prompt, language
+    )
+}
+
+/// Prompt for translating an article's title and text into `language`
+pub fn translate_prompt(title: &str, content: &str, language: &str) -> String {
+    render(
+        &TRANSLATE_PROMPT.replace("{language}", language),
+        &PromptFields {
+            title,
+            content,
+            ..Default::default()
+        },
+    )
+}
+
+/// Combine the notes on each part into the content for the final summary
+pub fn combine_notes(notes: &[String]) -> String {
+    let mut content = NOTES_HEADING.to_string();
+    for (i, note) in notes.iter().enumerate() {
+        content.push_str(&format!("\n\nPart {}:\n{}", i + 1, note.trim()));
+    }
+    content
+}
+
+/// Fill in `{title}`, `{content}`, `{feed}` and `{author}`. Substituted text is never
+/// scanned again, so an article containing "{title}" stays as written. Other braces
+/// are left alone.
+pub fn render(template: &str, fields: &PromptFields) -> String {
+    let mut out = String::with_capacity(template.len() + fields.content.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let value = [
+            ("{title}", fields.title),
+            ("{content}", fields.content),
+            ("{feed}", fields.feed.unwrap_or("Unknown feed")),
+            ("{author}", fields.author.unwrap_or("Unknown author")),
+        ]
+        .into_iter()
+        .find(|(placeholder, _)| after.starts_with(placeholder));
+
+        match value {
+            Some((placeholder, value)) => {
+                out.push_str(value);
+                rest = &after[placeholder.len()..];
+            }
+            None => {
+                out.push('{');
+                rest = &after[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chat_prompt_includes_history_after_article() {
+        let history = [
+            ChatMessage::user("Who makes it?"),
+            ChatMessage::assistant("Apple."),
+        ];
+        let prompt = chat_prompt(
+            "Phone",
+            "Apple announced a {title} phone.",
+            &history,
+            " Price? ",
+        );
+        assert!(prompt.contains("Title: Phone\n\nArticle:\nApple announced a {title} phone."));
+        assert!(prompt.ends_with(
+            "Conversation so far:\nQuestion: Who makes it?\nAnswer: Apple.\n\nQuestion: Price?"
+        ));
+    }
+
+    #[test]
+    fn test_render_placeholders() {
+        let fields = PromptFields {
+            title: "Apple event",
+            content: "Apple announced a phone.",
+            feed: Some("MacRumors"),
+            author: None,
+        };
+        assert_eq!(
+            render("{feed}: {title} by {author}\n\n{content}", &fields),
+            "MacRumors: Apple event by Unknown author\n\nApple announced a phone."
+        );
+    }
+
+    #[test]
+    fn test_render_leaves_other_braces() {
+        let fields = PromptFields {
+            title: "T",
+            ..Default::default()
+        };
+        assert_eq!(
+            render(r#"Reply as JSON: {"title": "{title}"} {unknown}"#, &fields),
+            r#"Reply as JSON: {"title": "T"} {unknown}"#
+        );
+    }
+
+    #[test]
+    fn test_render_does_not_expand_article_text() {
+        let fields = PromptFields {
+            title: "Templates",
+            content: "Use {title} in your prompt",
+            ..Default::default()
+        };
+        assert_eq!(render("{content}", &fields), "Use {title} in your prompt");
+    }
+
+    #[test]
+    fn test_builtin_template() {
+        let templates = templates_with_builtins(&BTreeMap::new());
+        let builtin = &templates[DEFAULT_TEMPLATE];
+        assert!(builtin.contains("nut graph"));
+        assert!(builtin.ends_with("Title: {title}\n\nArticle:\n{content}"));
+
+        let mut custom = BTreeMap::new();
+        custom.insert("brief".to_string(), "Summarize {title}".to_string());
+        let templates = templates_with_builtins(&custom);
+        assert_eq!(templates.len(), 2);
+        assert_eq!(templates["brief"], "Summarize {title}");
+    }
+
+    #[test]
+    fn test_chunk_prompt_numbers_parts() {
+        let prompt = chunk_prompt("Long read {content}", 2, 5, "Body of part two");
+        assert!(prompt.contains("part 2 of 5"));
+        assert!(prompt.contains("Title: Long read {content}\n"));
+        assert!(prompt.ends_with("Part 2 of 5:\nBody of part two"));
+    }
+
+    #[test]
+    fn test_combine_notes_in_order() {
+        let content = combine_notes(&["First notes.\n".to_string(), "Second notes.".to_string()]);
+        assert!(content.starts_with(NOTES_HEADING));
+        assert!(content.ends_with("Part 1:\nFirst notes.\n\nPart 2:\nSecond notes."));
+    }
+}
@@ -0,0 +1,219 @@
+use std::ops::Range;
+
+/// Quotes shorter than this many words aren't checked: scare quotes and single terms
+/// ("smart", "Pro Max") say nothing about whether someone was quoted correctly
+const MIN_QUOTE_WORDS: usize = 3;
+
+/// Byte ranges of the quoted spans in `text`, quote marks included. A quote opens with
+/// `"` or `“` and must close on the same line.
+pub fn quoted_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let mut rest = 0;
+        while let Some(open) = line[rest..].find(['"', '“']).map(|i| rest + i) {
+            let close_mark = if line[open..].starts_with('“') {
+                '”'
+            } else {
+                '"'
+            };
+            let inner = open + line[open..].chars().next().map_or(1, char::len_utf8);
+            let Some(close) = line[inner..].find(close_mark).map(|i| inner + i) else {
+                break;
+            };
+            let end = close + close_mark.len_utf8();
+            spans.push(line_start + open..line_start + end);
+            rest = end;
+        }
+        line_start += line.len();
+    }
+    spans
+}
+
+/// The text of a quoted span without its quote marks
+fn inner(span: &str) -> &str {
+    span.trim_start_matches(['"', '“'])
+        .trim_end_matches(['"', '”'])
+}
+
+/// Lowercase words only: punctuation, quote styles and whitespace don't matter
+fn normalize(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The parts of a quote that must appear in the source, normalized. Ellipses split a
+/// quote into parts, and [bracketed] insertions are the writer's words, not the speaker's.
+fn quote_parts(quote: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_brackets = false;
+    for c in quote.replace("...", "…").chars() {
+        match c {
+            '[' => {
+                in_brackets = true;
+                parts.push(std::mem::take(&mut current));
+            }
+            ']' => in_brackets = false,
+            '…' => parts.push(std::mem::take(&mut current)),
+            _ if !in_brackets => current.push(c),
+            _ => {}
+        }
+    }
+    parts.push(current);
+    parts
+        .iter()
+        .map(|part| normalize(part))
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Whether every part of `quote` appears in the source, whole words and in order
+fn is_verbatim(quote: &str, normalized_source: &str) -> bool {
+    let mut from = 0;
+    for part in quote_parts(quote) {
+        let needle = format!(" {} ", part);
+        match normalized_source[from..].find(&needle) {
+            // The next part may start at this part's trailing space
+            Some(i) => from += i + needle.len() - 1,
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Quotes in `summary` (without their quote marks) that don't appear word for word in
+/// `source`, the article text the summary was written from
+pub fn unverified_quotes(summary: &str, source: &str) -> Vec<String> {
+    let normalized_source = format!(" {} ", normalize(source));
+    quoted_spans(summary)
+        .into_iter()
+        .map(|span| inner(&summary[span]).trim().to_string())
+        .filter(|quote| quote.split_whitespace().count() >= MIN_QUOTE_WORDS)
+        .filter(|quote| !is_verbatim(quote, &normalized_source))
+        .collect()
+}
+
+/// Remove the unverified quotes from a summary: a line that is just the quote (with its
+/// attribution) is dropped, and a quote within a sentence loses its quote marks so it no
+/// longer claims to be verbatim
+pub fn strip_unverified(summary: &str, unverified: &[String]) -> String {
+    if unverified.is_empty() {
+        return summary.to_string();
+    }
+    let is_unverified = |span: &str| unverified.iter().any(|q| inner(span).trim() == q);
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in summary.lines() {
+        let spans: Vec<Range<usize>> = quoted_spans(line)
+            .into_iter()
+            .filter(|span| is_unverified(&line[span.clone()]))
+            .collect();
+        if spans.is_empty() {
+            lines.push(line.to_string());
+            continue;
+        }
+        if spans
+            .iter()
+            .any(|span| line[..span.start].trim().is_empty())
+        {
+            continue;
+        }
+        let mut kept = String::new();
+        let mut at = 0;
+        for span in spans {
+            kept.push_str(&line[at..span.start]);
+            kept.push_str(inner(&line[span.clone()]));
+            at = span.end;
+        }
+        kept.push_str(&line[at..]);
+        lines.push(kept);
+    }
+
+    // Don't leave a gap where a quote paragraph was
+    let mut text = lines.join("\n");
+    while text.contains("\n\n\n") {
+        text = text.replace("\n\n\n", "\n\n");
+    }
+    text.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "Apple's new phone ships Friday. “We think it’s the best iPhone \
+        we've ever made,” said Tim Cook, adding that it was \"years in the making.\"\n\n\
+        Analysts weren't convinced.";
+
+    #[test]
+    fn test_quoted_spans() {
+        let text = "He said \"one two\" and “three four”.\n\"unclosed\nquote\"";
+        let spans: Vec<&str> = quoted_spans(text).into_iter().map(|s| &text[s]).collect();
+        assert_eq!(spans, vec!["\"one two\"", "“three four”"]);
+    }
+
+    #[test]
+    fn test_verbatim_quotes_pass_despite_punctuation_and_quote_style() {
+        let summary =
+            "Apple ships a phone.\n\n\"We think it's the best iPhone we've ever made\" -- Tim Cook";
+        assert!(unverified_quotes(summary, SOURCE).is_empty());
+
+        // Curly apostrophes and different case
+        let summary = "“we think it’s THE BEST iPhone” -- Tim Cook";
+        assert!(unverified_quotes(summary, SOURCE).is_empty());
+    }
+
+    #[test]
+    fn test_paraphrased_quotes_are_flagged() {
+        let summary = "\"We believe it's the best iPhone we have ever made\" -- Tim Cook";
+        assert_eq!(
+            unverified_quotes(summary, SOURCE),
+            vec!["We believe it's the best iPhone we have ever made"]
+        );
+    }
+
+    #[test]
+    fn test_partial_words_do_not_match() {
+        // "hink it" is inside "think it" but isn't the same words
+        let summary = "\"hink it's the best\" -- Tim Cook";
+        assert_eq!(unverified_quotes(summary, SOURCE).len(), 1);
+    }
+
+    #[test]
+    fn test_ellipsis_and_brackets() {
+        let summary = "\"We think it's the best iPhone … years in the making\" -- Tim Cook";
+        assert!(unverified_quotes(summary, SOURCE).is_empty());
+
+        let summary = "\"We think [the phone is] the best iPhone we've ever made\" -- Tim Cook";
+        assert!(unverified_quotes(summary, SOURCE).is_empty());
+
+        // Parts out of order
+        let summary = "\"years in the making ... We think it's the best\" -- Tim Cook";
+        assert_eq!(unverified_quotes(summary, SOURCE).len(), 1);
+    }
+
+    #[test]
+    fn test_short_quotes_are_not_checked() {
+        let summary = "Apple calls it \"magical\" and \"Pro Max\".";
+        assert!(unverified_quotes(summary, SOURCE).is_empty());
+    }
+
+    #[test]
+    fn test_strip_unverified() {
+        let summary = "Apple ships a phone.\n\n\"We made the best phone ever\" -- Tim Cook\n\n\
+                       Cook called it \"our most ambitious phone yet\" on stage.";
+        let unverified = unverified_quotes(summary, SOURCE);
+        assert_eq!(unverified.len(), 2);
+        assert_eq!(
+            strip_unverified(summary, &unverified),
+            "Apple ships a phone.\n\nCook called it our most ambitious phone yet on stage."
+        );
+        assert_eq!(strip_unverified(summary, &[]), summary);
+    }
+}
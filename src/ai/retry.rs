@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+/// How often and how long to retry transient API failures
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles with each attempt
+    pub base_delay: Duration,
+    /// Longest wait between attempts. A `retry-after` longer than this isn't waited out.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before retry number `attempt` (0 for the first retry): exponential backoff
+    /// with jitter between half and the full delay, or at least `retry_after` when the
+    /// API asked for it. None when the API wants a longer wait than `max_delay`.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if retry_after.is_some_and(|wait| wait > self.max_delay) {
+            return None;
+        }
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jittered = backoff.mul_f64(rand::rng().random_range(0.5..=1.0));
+        Some(retry_after.map_or(jittered, |wait| wait.max(jittered)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_with_jitter() {
+        let policy = RetryPolicy::default();
+        for attempt in 0..3 {
+            let full = Duration::from_secs(1 << attempt);
+            for _ in 0..20 {
+                let delay = policy.delay(attempt, None).unwrap();
+                assert!(delay >= full / 2 && delay <= full, "{:?}", delay);
+            }
+        }
+        // Capped at max_delay
+        assert!(policy.delay(10, None).unwrap() <= policy.max_delay);
+    }
+
+    #[test]
+    fn test_retry_after_is_honored() {
+        let policy = RetryPolicy::default();
+        let delay = policy.delay(0, Some(Duration::from_secs(7))).unwrap();
+        assert_eq!(delay, Duration::from_secs(7));
+
+        // Too long to wait out
+        assert_eq!(policy.delay(0, Some(Duration::from_secs(120))), None);
+    }
+}
@@ -0,0 +1,114 @@
+/// One server-sent event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    /// The `event:` field, if the server sent one
+    pub event: Option<String>,
+    /// `data:` lines joined with newlines
+    pub data: String,
+}
+
+/// Incremental parser for a `text/event-stream` body. Feed it chunks as they arrive;
+/// it returns the events completed so far and keeps partial ones for the next chunk.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: String,
+    /// Bytes of a UTF-8 character split across chunks
+    pending: Vec<u8>,
+}
+
+impl SseParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.pending.extend_from_slice(chunk);
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        let text = String::from_utf8_lossy(&self.pending[..valid]).into_owned();
+        self.pending.drain(..valid);
+        self.buffer.push_str(&text.replace("\r\n", "\n"));
+
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.find("\n\n") {
+            let block: String = self.buffer.drain(..end + 2).collect();
+            if let Some(event) = Self::parse_block(&block) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    fn parse_block(block: &str) -> Option<SseEvent> {
+        let mut event = None;
+        let mut data: Vec<&str> = Vec::new();
+        for line in block.lines() {
+            // Lines starting with ':' are comments (keep-alives)
+            if line.is_empty() || line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => event = Some(value.to_string()),
+                "data" => data.push(value),
+                _ => {}
+            }
+        }
+        if event.is_none() && data.is_empty() {
+            return None;
+        }
+        Some(SseEvent {
+            event,
+            data: data.join("\n"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_events() {
+        let mut parser = SseParser::new();
+        let events = parser.feed(
+            b"event: message_start\ndata: {\"a\":1}\n\n: ping\n\ndata: line one\ndata: line two\n\n",
+        );
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: Some("message_start".into()),
+                    data: "{\"a\":1}".into()
+                },
+                SseEvent {
+                    event: None,
+                    data: "line one\nline two".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_split_across_chunks() {
+        let mut parser = SseParser::new();
+        assert!(parser.feed(b"event: delta\nda").is_empty());
+        assert!(parser.feed(b"ta: hel").is_empty());
+        let events = parser.feed(b"lo\r\n\r\ndata: next");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "hello");
+        assert_eq!(parser.feed(b"\n\n")[0].data, "next");
+    }
+
+    #[test]
+    fn test_utf8_split_across_chunks() {
+        let mut parser = SseParser::new();
+        let bytes = "data: café\n\n".as_bytes();
+        let split = bytes.len() - 3; // inside the two-byte 'é'
+        assert!(parser.feed(&bytes[..split]).is_empty());
+        assert_eq!(parser.feed(&bytes[split..])[0].data, "café");
+    }
+}
@@ -0,0 +1,153 @@
+use crate::models::{SummaryFields, SummaryKind};
+
+/// Keys of a structured summary, in display order
+const KEYS: &[&str] = &[
+    "lede",
+    "why_it_matters",
+    "price",
+    "availability",
+    "platforms",
+    "quote",
+    "speaker",
+];
+
+/// The JSON object in a model reply, without the Markdown code fence models like to add
+fn json_object(reply: &str) -> Option<&str> {
+    let start = reply.find('{')?;
+    let end = reply.rfind('}')?;
+    (start < end).then(|| &reply[start..=end])
+}
+
+/// Whether a reply is (the start of) a structured summary rather than plain text
+pub fn looks_structured(reply: &str) -> bool {
+    let reply = reply.trim_start();
+    reply.starts_with('{') || reply.starts_with("```")
+}
+
+/// Parse a structured summary reply. None when the reply is plain text (e.g. from a
+/// custom template, or "Insufficient content for summary") or has no lede.
+pub fn parse(reply: &str) -> Option<SummaryFields> {
+    let mut fields: SummaryFields = serde_json::from_str(json_object(reply)?).ok()?;
+    fields.lede = fields.lede.trim().to_string();
+    for part in [
+        &mut fields.why_it_matters,
+        &mut fields.price,
+        &mut fields.availability,
+        &mut fields.platforms,
+        &mut fields.quote,
+        &mut fields.speaker,
+    ] {
+        *part = part
+            .take()
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty() && !text.eq_ignore_ascii_case("null"));
+    }
+    // Quote marks are added when the quote is shown
+    fields.quote = fields.quote.map(|quote| {
+        quote
+            .trim_matches(|c| matches!(c, '"' | '“' | '”'))
+            .to_string()
+    });
+    (!fields.lede.is_empty()).then_some(fields)
+}
+
+/// The string value of `key` in partial JSON: what has arrived so far, unescaped.
+/// None when the key hasn't started or isn't a string.
+fn partial_string(json: &str, key: &str) -> Option<String> {
+    let key_at = json.find(&format!("\"{}\"", key))?;
+    let rest = json[key_at + key.len() + 2..].trim_start();
+    let rest = rest.strip_prefix(':')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+
+    let mut value = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                        value.push(c);
+                    }
+                }
+                Some(other) => value.push(other),
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+    Some(value)
+}
+
+/// Readable text for a structured summary that is still streaming in: the parts that
+/// have arrived, in the plain-text layout
+pub fn preview(partial: &str) -> String {
+    let mut fields = SummaryFields {
+        kind: partial_string(partial, "type")
+            .and_then(|kind| SummaryKind::parse(&kind))
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+    for key in KEYS {
+        let value = partial_string(partial, key).filter(|v| !v.trim().is_empty());
+        match *key {
+            "lede" => fields.lede = value.unwrap_or_default(),
+            "why_it_matters" => fields.why_it_matters = value,
+            "price" => fields.price = value,
+            "availability" => fields.availability = value,
+            "platforms" => fields.platforms = value,
+            "quote" => fields.quote = value,
+            _ => fields.speaker = value,
+        }
+    }
+    fields.to_plain_text()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPLY: &str = r#"```json
+{
+  "type": "product",
+  "lede": "Apple's new iPhone has a faster chip.",
+  "why_it_matters": null,
+  "price": "Starts at $799.",
+  "availability": "",
+  "platforms": "null",
+  "quote": "“It's our best phone”",
+  "speaker": "Tim Cook"
+}
+```"#;
+
+    #[test]
+    fn test_parse_fenced_reply() {
+        let fields = parse(REPLY).unwrap();
+        assert_eq!(fields.kind, SummaryKind::Product);
+        assert_eq!(fields.lede, "Apple's new iPhone has a faster chip.");
+        assert_eq!(fields.why_it_matters, None);
+        assert_eq!(fields.price.as_deref(), Some("Starts at $799."));
+        assert_eq!(fields.availability, None);
+        assert_eq!(fields.platforms, None);
+        assert_eq!(fields.quote.as_deref(), Some("It's our best phone"));
+        assert!(looks_structured(REPLY));
+    }
+
+    #[test]
+    fn test_plain_replies_are_not_structured() {
+        assert_eq!(parse("Insufficient content for summary"), None);
+        assert_eq!(parse("Apple announced a phone {maybe}."), None);
+        assert_eq!(parse(r#"{"type": "editorial", "lede": " "}"#), None);
+        assert!(!looks_structured("Apple announced a phone."));
+    }
+
+    #[test]
+    fn test_preview_of_partial_reply() {
+        let partial = "{\"type\": \"editorial\", \"lede\": \"Apple said \\\"hi\\\".\\nMore\", \"why_it_matters\": \"Because it";
+        assert_eq!(preview(partial), "Apple said \"hi\".\nMore\n\nBecause it");
+        assert_eq!(preview("```json\n{\"ty"), "");
+    }
+}
@@ -1,162 +1,752 @@
-use std::time::Duration;
-
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-
-use crate::error::{AppError, Result};
-
-const CLAUDE_API_URL: &str = "https://api.anthropic.com/v1/messages";
-const CLAUDE_MODEL: &str = "claude-haiku-4-5-20251001";
-
-#[derive(Debug, Serialize)]
-struct MessageRequest {
-    model: String,
-    max_tokens: u32,
-    messages: Vec<Message>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    system: Option<String>,
-}
-
-#[derive(Debug, Serialize)]
-struct Message {
-    role: String,
-    content: String,
+use std::collections::BTreeMap;
+
+use futures::stream::{self, StreamExt};
+use tokio::sync::mpsc;
+
+use crate::config::Config;
+use crate::error::Result;
+use crate::language;
+use crate::models::{ChatMessage, SummaryFields};
+
+use super::backend::{backend_from_config, Completion, SummaryBackend, Usage};
+use super::chunk;
+use super::pricing::PriceTable;
+use super::prompt::{self, PromptFields, DEFAULT_TEMPLATE};
+use super::quotes;
+use super::structured;
+use super::tags;
+
+/// Default response length limit
+const DEFAULT_MAX_TOKENS: u32 = 1024;
+
+/// Article text sent in one request, in bytes. Longer articles are summarized in parts.
+const DEFAULT_CHUNK_SIZE: usize = 10_000;
+
+/// Most parts of a long article that are summarized; text past them is left out
+const DEFAULT_MAX_CHUNKS: usize = 8;
+
+/// Parts of one article summarized at the same time
+const CHUNK_CONCURRENCY: usize = 3;
+
+/// Response length limit for a tag suggestion (a short list)
+const TAG_MAX_TOKENS: u32 = 100;
+
+/// Response length limit for a translation, which is as long as the text
+const TRANSLATE_MAX_TOKENS: u32 = 4096;
+
+/// A finished summary with the tokens it took and their cost, when known
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedSummary {
+    pub text: String,
+    pub usage: Option<Usage>,
+    pub cost_usd: Option<f64>,
+    /// Quotes in the summary that aren't word for word in the article text
+    pub unverified_quotes: Vec<String>,
+    /// The summary's parts when the model answered with JSON; `text` is their plain text
+    pub fields: Option<SummaryFields>,
 }
 
-#[derive(Debug, Deserialize)]
-struct MessageResponse {
-    content: Vec<ContentBlock>,
+/// Tags suggested for a bookmark, with the tokens the suggestion took
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuggestedTags {
+    pub tags: Vec<String>,
+    pub usage: Option<Usage>,
+    pub cost_usd: Option<f64>,
 }
 
-#[derive(Debug, Deserialize)]
-struct ContentBlock {
-    #[serde(rename = "type")]
-    #[allow(dead_code)]
-    content_type: String,
-    text: Option<String>,
+/// An article's title and text in the target language
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslatedArticle {
+    pub title: String,
+    pub text: String,
+    pub usage: Option<Usage>,
+    pub cost_usd: Option<f64>,
 }
 
 pub struct Summarizer {
-    client: Client,
-    api_key: String,
+    backend: Box<dyn SummaryBackend>,
+    max_tokens: u32,
+    templates: BTreeMap<String, String>,
+    default_template: String,
+    prices: PriceTable,
+    chunk_size: usize,
+    max_chunks: usize,
+    /// Language summaries are written in, when configured
+    language: Option<String>,
 }
 
 impl Summarizer {
-    pub fn new(api_key: String) -> Self {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(60))
-            .build()
-            .expect("Failed to create HTTP client");
-        Self { client, api_key }
+    pub fn new(backend: Box<dyn SummaryBackend>) -> Self {
+        Self {
+            backend,
+            max_tokens: DEFAULT_MAX_TOKENS,
+            templates: prompt::templates_with_builtins(&BTreeMap::new()),
+            default_template: DEFAULT_TEMPLATE.to_string(),
+            prices: PriceTable::default(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            max_chunks: DEFAULT_MAX_CHUNKS,
+            language: None,
+        }
     }
 
+    /// Summarizer for the backend selected in config, or None if it isn't usable
+    /// (e.g. no API key for a hosted backend)
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let settings = &config.summarizer;
+        let backend = backend_from_config(settings, config.claude_api_key.as_deref())?;
+
+        let mut summarizer = Self::new(backend);
+        if let Some(max_tokens) = settings.max_tokens {
+            summarizer.max_tokens = max_tokens;
+        }
+        summarizer.templates = prompt::templates_with_builtins(&settings.templates);
+        summarizer.prices = PriceTable::new(&config.usage.prices);
+        if let Some(chunk_size) = settings.chunk_size {
+            summarizer.chunk_size = chunk_size.max(1);
+        }
+        if let Some(max_chunks) = settings.max_chunks {
+            summarizer.max_chunks = max_chunks.max(1);
+        }
+        summarizer.language = settings
+            .language
+            .as_deref()
+            .map(str::trim)
+            .filter(|language| !language.is_empty())
+            .map(str::to_string);
+        if let Some(template) = &settings.template {
+            if summarizer.templates.contains_key(template) {
+                summarizer.default_template = template.clone();
+            } else {
+                tracing::warn!(
+                    "Prompt template '{}' is not defined, using '{}'",
+                    template,
+                    DEFAULT_TEMPLATE
+                );
+            }
+        }
+        Some(summarizer)
+    }
+
+    /// Names of the available prompt templates, sorted
+    pub fn template_names(&self) -> Vec<&str> {
+        self.templates.keys().map(String::as_str).collect()
+    }
+
+    /// Template used unless another one is picked
+    pub fn default_template(&self) -> &str {
+        &self.default_template
+    }
+
+    /// Language articles are translated into
+    pub fn target_language(&self) -> &str {
+        self.language
+            .as_deref()
+            .unwrap_or(language::DEFAULT_TARGET_LANGUAGE)
+    }
+
+    /// A rendered template, told to answer in the configured language if there is one
+    fn in_language(&self, prompt: String) -> String {
+        match &self.language {
+            Some(language) => prompt::with_language(prompt, language),
+            None => prompt,
+        }
+    }
+
+    /// Whether a summary of `source` is a translation, so its quotes can't be found
+    /// word for word in the article
+    fn translates(&self, source: &str) -> bool {
+        let Some(target) = &self.language else {
+            return false;
+        };
+        language::detect("", source)
+            .is_some_and(|detected| !language::is_language(detected, target))
+    }
+
+    /// Summarize an article with `template`. Articles longer than the chunk size are
+    /// summarized in parts first, and the template is filled with the notes on the parts.
     pub async fn generate_summary(
         &self,
-        article_title: &str,
-        article_content: &str,
-    ) -> Result<String> {
-        // Truncate content if too long (find valid UTF-8 boundary)
-        let content = if article_content.len() > 10000 {
-            let mut end = 10000;
-            while end > 0 && !article_content.is_char_boundary(end) {
-                end -= 1;
-            }
-            &article_content[..end]
-        } else {
-            article_content
+        fields: &PromptFields<'_>,
+        template: &str,
+    ) -> Result<GeneratedSummary> {
+        let template = self.template(template)?;
+        let (content, notes_usage) = self.condense(fields).await?;
+        let prompt = self.in_language(prompt::render(
+            template,
+            &PromptFields {
+                content: &content,
+                ..*fields
+            },
+        ));
+        let completion = self.backend.complete(&prompt, self.max_tokens).await?;
+        Ok(self.finish(completion, notes_usage, fields.content))
+    }
+
+    /// Like `generate_summary`, but sends the text to `on_text` as it's written so it can
+    /// be shown before the model finishes. The returned summary is the cleaned-up whole.
+    /// Notes on the parts of a long article aren't streamed.
+    pub async fn generate_summary_streaming(
+        &self,
+        fields: &PromptFields<'_>,
+        template: &str,
+        on_text: &mpsc::UnboundedSender<String>,
+    ) -> Result<GeneratedSummary> {
+        let template = self.template(template)?;
+        let (content, notes_usage) = self.condense(fields).await?;
+        let prompt = self.in_language(prompt::render(
+            template,
+            &PromptFields {
+                content: &content,
+                ..*fields
+            },
+        ));
+        let completion = self
+            .backend
+            .complete_streaming(&prompt, self.max_tokens, on_text)
+            .await?;
+        Ok(self.finish(completion, notes_usage, fields.content))
+    }
+
+    /// Send a prompt of our own (e.g. the daily briefing) rather than a template, and
+    /// price it. The reply is trimmed but otherwise kept as written.
+    pub async fn complete_prompt(&self, prompt: &str, max_tokens: u32) -> Result<GeneratedSummary> {
+        let completion = self.backend.complete(prompt, max_tokens).await?;
+        Ok(GeneratedSummary {
+            text: completion.text.trim().to_string(),
+            usage: completion.usage,
+            cost_usd: self.cost(completion.usage),
+            unverified_quotes: Vec::new(),
+            fields: None,
+        })
+    }
+
+    /// Answer a follow-up question about an article from its text. The text is cut to
+    /// what a long-article summary would read (`chunk_size` times `max_chunks`).
+    pub async fn answer(
+        &self,
+        title: &str,
+        content: &str,
+        history: &[ChatMessage],
+        question: &str,
+    ) -> Result<GeneratedSummary> {
+        let content = chunk::truncate(content, self.chunk_size * self.max_chunks);
+        let prompt = prompt::chat_prompt(title, content, history, question);
+        self.complete_prompt(&prompt, self.max_tokens).await
+    }
+
+    /// Pick tags for an article from `vocabulary`, the tags already in use. `content` is
+    /// the article's summary or text; only its first chunk is sent.
+    pub async fn suggest_tags(
+        &self,
+        title: &str,
+        content: &str,
+        vocabulary: &[String],
+    ) -> Result<SuggestedTags> {
+        let content = chunk::truncate(content, self.chunk_size);
+        let prompt = prompt::tag_prompt(title, content, vocabulary);
+        let completion = self.backend.complete(&prompt, TAG_MAX_TOKENS).await?;
+        Ok(SuggestedTags {
+            tags: tags::pick(&completion.text, vocabulary),
+            usage: completion.usage,
+            cost_usd: self.cost(completion.usage),
+        })
+    }
+
+    /// Translate an article's title and text into the target language. Text past the
+    /// chunk size is left out.
+    pub async fn translate(&self, title: &str, text: &str) -> Result<TranslatedArticle> {
+        let text = chunk::truncate(text, self.chunk_size);
+        let prompt = prompt::translate_prompt(title, text, self.target_language());
+        let completion = self.backend.complete(&prompt, TRANSLATE_MAX_TOKENS).await?;
+        let reply = completion.text.trim();
+        let (translated_title, translated_text) = match reply.split_once('\n') {
+            Some((title, text)) => (title.trim(), text.trim()),
+            None => (reply, ""),
         };
+        Ok(TranslatedArticle {
+            title: translated_title.to_string(),
+            text: translated_text.to_string(),
+            usage: completion.usage,
+            cost_usd: self.cost(completion.usage),
+        })
+    }
 
-        let user_message = format!(
-            r#"You are a journalist summarizing articles using the nut graph structure. Summarize the article below using the appropriate format.
+    fn template(&self, name: &str) -> Result<&str> {
+        match self.templates.get(name) {
+            Some(template) => Ok(template),
+            None => Err(anyhow::anyhow!("Unknown prompt template: {}", name).into()),
+        }
+    }
 
-First, determine: Is this article primarily about a specific PRODUCT (hardware, software, app, device) or is it EDITORIAL (news, policy, analysis, industry event)?
+    /// The article text to summarize: the content itself when it fits in one chunk,
+    /// else notes on each chunk (map), which the template then summarizes (reduce).
+    /// Returns the tokens the notes took.
+    async fn condense(&self, fields: &PromptFields<'_>) -> Result<(String, Option<Usage>)> {
+        if fields.content.len() <= self.chunk_size {
+            return Ok((fields.content.to_string(), None));
+        }
 
-RULES:
-1. Use ONLY information from the article - no external knowledge
-2. If the article has insufficient content, respond with just: "Insufficient content for summary"
-3. QUOTE must be copied VERBATIM from the article — the exact words as they appear, with clear speaker attribution. Do not paraphrase or alter the quote in any way.
+        let mut chunks = chunk::split(fields.content, self.chunk_size);
+        if chunks.len() > self.max_chunks {
+            tracing::warn!(
+                "Article '{}' has {} parts, summarizing the first {}",
+                fields.title,
+                chunks.len(),
+                self.max_chunks
+            );
+            chunks.truncate(self.max_chunks);
+        }
+        if chunks.len() == 1 {
+            return Ok((chunks[0].to_string(), None));
+        }
 
-If EDITORIAL, respond with:
-One strong sentence identifying WHO is involved and WHAT happened or was announced.
+        let parts = chunks.len();
+        let requests: Vec<_> = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let prompt = prompt::chunk_prompt(fields.title, i + 1, parts, text);
+                async move { self.backend.complete(&prompt, self.max_tokens).await }
+            })
+            .collect();
+        let completions: Vec<Result<Completion>> = stream::iter(requests)
+            .buffered(CHUNK_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut notes = Vec::with_capacity(parts);
+        let mut usage = None;
+        for completion in completions {
+            let completion = completion?;
+            usage = add_usage(usage, completion.usage);
+            notes.push(completion.text);
+        }
+        Ok((prompt::combine_notes(&notes), usage))
+    }
 
-A paragraph (2-4 sentences) explaining WHY this matters. Contextualize the most important facts and give the reader a clear understanding of the central issue or topic.
+    /// Parse or clean up the reply, price its usage (including the notes that led to
+    /// it), and check its quotes against `source`, the whole article text
+    fn finish(
+        &self,
+        completion: Completion,
+        notes_usage: Option<Usage>,
+        source: &str,
+    ) -> GeneratedSummary {
+        let usage = add_usage(notes_usage, completion.usage);
+        let fields = structured::parse(&completion.text);
+        let text = match &fields {
+            Some(fields) => fields.to_plain_text(),
+            None => clean_summary(&completion.text),
+        };
+        // Translated quotes can't match the article; the reader checks those themselves
+        let unverified_quotes = if self.translates(source) {
+            Vec::new()
+        } else {
+            quotes::unverified_quotes(&text, source)
+        };
+        if !unverified_quotes.is_empty() {
+            tracing::warn!(
+                "Summary has {} quote(s) not found in the article",
+                unverified_quotes.len()
+            );
+        }
+        GeneratedSummary {
+            text,
+            usage,
+            cost_usd: self.cost(usage),
+            unverified_quotes,
+            fields,
+        }
+    }
 
-"exact verbatim quote from the article" -- Speaker Name
+    fn cost(&self, usage: Option<Usage>) -> Option<f64> {
+        usage.and_then(|usage| self.prices.cost(self.backend.model(), usage))
+    }
+
+    pub fn model_version(&self) -> &str {
+        self.backend.model()
+    }
+}
 
-If PRODUCT, respond with:
-What the product is and what it does (1-2 sentences).
+/// Total of two requests' usage; None only when neither reported any
+fn add_usage(a: Option<Usage>, b: Option<Usage>) -> Option<Usage> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(Usage {
+            input_tokens: a.input_tokens.saturating_add(b.input_tokens),
+            output_tokens: a.output_tokens.saturating_add(b.output_tokens),
+        }),
+        (a, b) => a.or(b),
+    }
+}
 
-Pricing details. Omit if not mentioned.
+/// What to show of a summary that is still streaming in
+pub fn preview_summary(partial: &str) -> String {
+    if structured::looks_structured(partial) {
+        structured::preview(partial)
+    } else {
+        clean_summary(partial)
+    }
+}
 
-When and where it is available. Omit if not mentioned.
+/// Strip format/type labels the model sometimes adds despite instructions
+pub fn clean_summary(summary: &str) -> String {
+    summary
+        .lines()
+        .filter(|line| {
+            let upper = line.trim().to_uppercase();
+            !upper.starts_with("FORMAT:")
+                && upper != "EDITORIAL"
+                && upper != "PRODUCT"
+                && upper != "**EDITORIAL**"
+                && upper != "**PRODUCT**"
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
 
-What platforms or operating systems it runs on. Omit for hardware-only products or if not mentioned.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
 
-"exact verbatim quote from the article" -- Speaker Name
+    /// Backend that records the prompts and answers with a canned reply
+    struct FakeBackend {
+        reply: String,
+        prompts: Arc<Mutex<Vec<String>>>,
+    }
 
-Do NOT include any labels, prefixes, or headings. Just the plain text. Omit the quote line if there are no direct quotes with clear speaker attribution in the article.
+    impl FakeBackend {
+        fn new(reply: &str) -> (Self, Arc<Mutex<Vec<String>>>) {
+            let prompts = Arc::new(Mutex::new(Vec::new()));
+            let backend = Self {
+                reply: reply.to_string(),
+                prompts: Arc::clone(&prompts),
+            };
+            (backend, prompts)
+        }
+    }
 
-Title: {}
+    #[async_trait]
+    impl SummaryBackend for FakeBackend {
+        async fn complete(&self, prompt: &str, _max_tokens: u32) -> Result<Completion> {
+            self.prompts.lock().unwrap().push(prompt.to_string());
+            Ok(Completion {
+                text: self.reply.clone(),
+                usage: Some(Usage {
+                    input_tokens: 1000,
+                    output_tokens: 100,
+                }),
+            })
+        }
 
-Article:
-{}"#,
-            article_title, content
+        fn model(&self) -> &str {
+            "fake-model"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summary_strips_format_labels() {
+        let (backend, _) = FakeBackend::new("EDITORIAL\nApple announced a phone.\n\nIt matters.\n");
+        let summarizer = Summarizer::new(Box::new(backend));
+
+        let fields = PromptFields {
+            title: "Apple event",
+            content: "Apple announced a phone.",
+            ..Default::default()
+        };
+        let summary = summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+        assert_eq!(summary.text, "Apple announced a phone.\n\nIt matters.");
+        assert_eq!(summarizer.model_version(), "fake-model");
+        // No price for the fake model
+        assert_eq!(summary.cost_usd, None);
+    }
+
+    #[tokio::test]
+    async fn test_json_reply_is_stored_as_fields() {
+        let (backend, _) = FakeBackend::new(
+            r#"{"type": "editorial", "lede": "Apple announced a phone.", "why_it_matters": "It matters.", "price": null, "availability": null, "platforms": null, "quote": "We made the best phone", "speaker": "Tim Cook"}"#,
         );
+        let summarizer = Summarizer::new(Box::new(backend));
 
-        let request = MessageRequest {
-            model: CLAUDE_MODEL.to_string(),
-            max_tokens: 1024,
-            messages: vec![Message {
-                role: "user".to_string(),
-                content: user_message,
-            }],
-            system: None,
+        let fields = PromptFields {
+            title: "Apple event",
+            content: "Apple announced a phone. \"We made the best phone,\" Tim Cook said.",
+            ..Default::default()
         };
+        let summary = summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+        let parsed = summary.fields.unwrap();
+        assert_eq!(parsed.lede, "Apple announced a phone.");
+        assert_eq!(parsed.speaker.as_deref(), Some("Tim Cook"));
+        assert_eq!(
+            summary.text,
+            "Apple announced a phone.\n\nIt matters.\n\n\"We made the best phone\" -- Tim Cook"
+        );
+        assert!(summary.unverified_quotes.is_empty());
+        assert_eq!(
+            preview_summary(r#"{"type": "editorial", "lede": "Apple anno"#),
+            "Apple anno"
+        );
+    }
 
-        let response = self
-            .client
-            .post(CLAUDE_API_URL)
-            .header("x-api-key", &self.api_key)
-            .header("anthropic-version", "2023-06-01")
-            .header("content-type", "application/json")
-            .json(&request)
-            .send()
-            .await?;
+    #[tokio::test]
+    async fn test_suggested_tags_come_from_vocabulary() {
+        let (backend, prompts) = FakeBackend::new("Apple, iPhone, twit");
+        let summarizer = Summarizer::new(Box::new(backend));
+
+        let vocabulary = vec!["twit".to_string(), "Apple".to_string(), "AI".to_string()];
+        let suggested = summarizer
+            .suggest_tags("Apple event", "Apple announced a phone.", &vocabulary)
+            .await
+            .unwrap();
+        assert_eq!(suggested.tags, vec!["Apple", "twit"]);
+        assert!(prompts.lock().unwrap()[0].ends_with("Allowed tags: twit, Apple, AI"));
+    }
 
-        if !response.status().is_success() {
-            let error_text = response.text().await?;
-            return Err(AppError::ClaudeApi(format!("API error: {}", error_text)));
-        }
+    #[tokio::test]
+    async fn test_translation_splits_title_and_text() {
+        let (backend, prompts) =
+            FakeBackend::new("Apple announces a new iPhone\n\nApple unveiled a faster phone.\n");
+        let summarizer = Summarizer::new(Box::new(backend));
+
+        let translated = summarizer
+            .translate(
+                "Apple kündigt neues iPhone an",
+                "Apple hat ein schnelleres Telefon vorgestellt.",
+            )
+            .await
+            .unwrap();
+        assert_eq!(translated.title, "Apple announces a new iPhone");
+        assert_eq!(translated.text, "Apple unveiled a faster phone.");
+        assert!(prompts.lock().unwrap()[0].contains("into English."));
+    }
 
-        let message_response: MessageResponse = response.json().await?;
+    #[tokio::test]
+    async fn test_summary_in_configured_language() {
+        let (backend, prompts) = FakeBackend::new(
+            "Apple unveiled a faster phone.\n\n\"It is our best phone ever\" -- Tim Cook",
+        );
+        let mut summarizer = Summarizer::new(Box::new(backend));
+        summarizer.language = Some("English".to_string());
+
+        let fields = PromptFields {
+            title: "Apple kündigt neues iPhone an",
+            content: "Apple hat am Dienstag ein neues iPhone vorgestellt, das schneller ist. \
+                      „Es ist unser bestes Telefon aller Zeiten“, sagte Tim Cook.",
+            ..Default::default()
+        };
+        let summary = summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+        assert!(prompts.lock().unwrap()[0].contains("Write your response in English"));
+        // The quote is a translation, so it isn't checked against the German text
+        assert!(summary.unverified_quotes.is_empty());
+    }
 
-        let summary = message_response
-            .content
-            .into_iter()
-            .filter_map(|block| block.text)
+    #[tokio::test]
+    async fn test_long_content_is_summarized_in_parts() {
+        let (backend, prompts) = FakeBackend::new("Notes.");
+        let mut summarizer = Summarizer::new(Box::new(backend));
+        summarizer.chunk_size = 120;
+
+        let content = (1..=6)
+            .map(|i| {
+                format!(
+                    "Paragraph {} is exactly long enough to fill half a chunk.",
+                    i
+                )
+            })
             .collect::<Vec<_>>()
-            .join("\n");
-
-        // Strip format/type labels the model sometimes adds despite instructions
-        let summary = summary
-            .lines()
-            .filter(|line| {
-                let upper = line.trim().to_uppercase();
-                !upper.starts_with("FORMAT:")
-                    && upper != "EDITORIAL"
-                    && upper != "PRODUCT"
-                    && upper != "**EDITORIAL**"
-                    && upper != "**PRODUCT**"
+            .join("\n\n");
+        let fields = PromptFields {
+            title: "Long read",
+            content: &content,
+            ..Default::default()
+        };
+        let summary = summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+
+        // Three parts of two paragraphs, then the summary of the notes
+        let prompts = prompts.lock().unwrap().clone();
+        assert_eq!(prompts.len(), 4);
+        assert!(prompts[0].contains("part 1 of 3"));
+        assert!(prompts[0].contains("Paragraph 1 ") && prompts[0].contains("Paragraph 2 "));
+        assert!(prompts[2].contains("Paragraph 6 "));
+        let last = &prompts[3];
+        assert!(last.contains("Title: Long read"));
+        assert!(last.contains("Part 1:\nNotes.\n\nPart 2:\nNotes.\n\nPart 3:\nNotes."));
+        assert!(!last.contains("Paragraph 1 "));
+
+        // Usage covers every request
+        assert_eq!(
+            summary.usage,
+            Some(Usage {
+                input_tokens: 4000,
+                output_tokens: 400
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parts_past_max_chunks_are_left_out() {
+        let (backend, prompts) = FakeBackend::new("Notes.");
+        let mut summarizer = Summarizer::new(Box::new(backend));
+        summarizer.chunk_size = 120;
+        summarizer.max_chunks = 2;
+
+        let content = (1..=6)
+            .map(|i| {
+                format!(
+                    "Paragraph {} is exactly long enough to fill half a chunk.",
+                    i
+                )
             })
             .collect::<Vec<_>>()
-            .join("\n")
-            .trim()
-            .to_string();
+            .join("\n\n");
+        let fields = PromptFields {
+            title: "Long read",
+            content: &content,
+            ..Default::default()
+        };
+        summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+
+        let prompts = prompts.lock().unwrap().clone();
+        assert_eq!(prompts.len(), 3);
+        assert!(prompts[1].contains("part 2 of 2"));
+        assert!(prompts.iter().all(|p| !p.contains("Paragraph 5 ")));
+    }
+
+    #[tokio::test]
+    async fn test_short_content_is_summarized_in_one_request() {
+        let (backend, prompts) = FakeBackend::new("ok");
+        let summarizer = Summarizer::new(Box::new(backend));
+
+        let content = "é".repeat(5000); // 10000 bytes, exactly one chunk
+        let fields = PromptFields {
+            title: "Title",
+            content: &content,
+            ..Default::default()
+        };
+        summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+
+        let prompts = prompts.lock().unwrap().clone();
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("Title: Title"));
+        assert_eq!(prompts[0].matches('é').count(), 5000);
+    }
+
+    #[tokio::test]
+    async fn test_custom_template_from_config() {
+        let (backend, prompts) = FakeBackend::new("Short summary");
+        let mut summarizer = Summarizer::new(Box::new(backend));
+        summarizer.templates.insert(
+            "brief".to_string(),
+            "{feed} / {author}: {title}\n{content}".to_string(),
+        );
 
-        Ok(summary)
+        let fields = PromptFields {
+            title: "Apple event",
+            content: "Body",
+            feed: Some("MacRumors"),
+            author: Some("Jane Doe"),
+        };
+        summarizer.generate_summary(&fields, "brief").await.unwrap();
+        assert_eq!(
+            prompts.lock().unwrap().as_slice(),
+            ["MacRumors / Jane Doe: Apple event\nBody"]
+        );
+        assert_eq!(summarizer.template_names(), vec!["brief", "nutgraph"]);
+
+        assert!(summarizer
+            .generate_summary(&fields, "missing")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_streaming_sends_text_and_returns_cleaned_summary() {
+        let (backend, _) = FakeBackend::new("EDITORIAL\nStreamed summary.");
+        let summarizer = Summarizer::new(Box::new(backend));
+
+        let fields = PromptFields {
+            title: "Title",
+            content: "Body",
+            ..Default::default()
+        };
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let summary = summarizer
+            .generate_summary_streaming(&fields, DEFAULT_TEMPLATE, &tx)
+            .await
+            .unwrap();
+        assert_eq!(summary.text, "Streamed summary.");
+        assert_eq!(rx.recv().await.unwrap(), "EDITORIAL\nStreamed summary.");
     }
 
-    pub fn model_version(&self) -> &'static str {
-        CLAUDE_MODEL
+    #[tokio::test]
+    async fn test_quotes_are_checked_against_the_article() {
+        let (backend, _) = FakeBackend::new(
+            "Apple shipped a phone.\n\n\"It's our best phone ever\" -- Tim Cook\n\n\"We are thrilled with it\" -- Jane Doe",
+        );
+        let summarizer = Summarizer::new(Box::new(backend));
+
+        let fields = PromptFields {
+            title: "Apple event",
+            content: "“It’s our best phone ever,” said Tim Cook.",
+            ..Default::default()
+        };
+        let summary = summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+        assert_eq!(summary.unverified_quotes, vec!["We are thrilled with it"]);
+    }
+
+    #[tokio::test]
+    async fn test_summary_cost_from_price_table() {
+        let (backend, _) = FakeBackend::new("Summary");
+        let mut summarizer = Summarizer::new(Box::new(backend));
+        summarizer.prices = PriceTable::new(&BTreeMap::from([(
+            "fake-model".to_string(),
+            crate::config::ModelPrice {
+                input: 3.0,
+                output: 15.0,
+            },
+        )]));
+
+        let fields = PromptFields {
+            title: "Title",
+            content: "Body",
+            ..Default::default()
+        };
+        let summary = summarizer
+            .generate_summary(&fields, DEFAULT_TEMPLATE)
+            .await
+            .unwrap();
+        assert_eq!(
+            summary.usage,
+            Some(Usage {
+                input_tokens: 1000,
+                output_tokens: 100
+            })
+        );
+        // 1000 * $3/M + 100 * $15/M
+        assert!((summary.cost_usd.unwrap() - 0.0045).abs() < 1e-9);
     }
 }
@@ -0,0 +1,57 @@
+/// Most tags suggested for one article
+pub const MAX_SUGGESTED_TAGS: usize = 5;
+
+/// The tags in a model reply that are in `vocabulary`, spelled as in the vocabulary, in
+/// the order the model gave them. Anything else the model made up is dropped.
+pub fn pick(reply: &str, vocabulary: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for candidate in reply.split([',', '\n']) {
+        // List markers and quote marks aren't part of the tag
+        let candidate = candidate
+            .trim_start_matches(|c: char| c.is_whitespace() || matches!(c, '-' | '*' | '#'))
+            .trim()
+            .trim_matches(['"', '\'', '`', '.']);
+        let Some(tag) = vocabulary
+            .iter()
+            .find(|tag| tag.to_lowercase() == candidate.to_lowercase())
+        else {
+            continue;
+        };
+        if !tags.contains(tag) {
+            tags.push(tag.clone());
+        }
+        if tags.len() == MAX_SUGGESTED_TAGS {
+            break;
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocabulary() -> Vec<String> {
+        ["twit", "mbw", "Apple", "AI"]
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_pick_keeps_only_known_tags() {
+        assert_eq!(
+            pick("apple, iPhone, \"mbw\", apple", &vocabulary()),
+            vec!["Apple", "mbw"]
+        );
+        assert_eq!(pick("- AI\n- #twit\n", &vocabulary()), vec!["AI", "twit"]);
+        assert!(pick("None of these fit.", &vocabulary()).is_empty());
+    }
+
+    #[test]
+    fn test_pick_stops_at_max() {
+        let vocabulary: Vec<String> = (0..10).map(|i| format!("tag{}", i)).collect();
+        let reply = vocabulary.join(", ");
+        assert_eq!(pick(&reply, &vocabulary).len(), MAX_SUGGESTED_TAGS);
+    }
+}
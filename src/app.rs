@@ -1,23 +1,120 @@
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 
-use crate::ai::Summarizer;
-use crate::blocklist::Blocklist;
-use crate::config::Config;
+use crate::ai::{strip_unverified, GeneratedSummary, PromptFields, Summarizer};
+use crate::blocklist::{BlockTarget, Blocklist};
+use crate::cluster::{self, StoryText};
+use crate::config::{BriefingConfig, Config, PresummarizeConfig, RundownConfig, UnverifiedQuotes};
 use crate::db::Repository;
 use crate::error::Result;
 use crate::feed::{export_opml_file, parse_opml_file, FeedFetcher};
-use crate::models::{Article, Feed, Summary, SummaryStatus};
+use crate::models::{
+    Article, BlockedArticle, ChatMessage, Feed, NewSummary, Rundown, Summary, SummaryStatus,
+};
+use crate::rundown::{self, SHOWS};
 use crate::services::{ContentFetcher, RaindropClient};
-use crate::tui::AppAction;
+use crate::tui::{AppAction, InputMode};
+use crate::usage::UsageReport;
+
+/// One row of the article list
+pub enum ArticleRow<'a> {
+    Single(&'a Article),
+    /// Duplicate stories collapsed into one row, shown as the newest of them
+    Cluster {
+        lead: &'a Article,
+        members: Vec<&'a Article>,
+        expanded: bool,
+    },
+    /// One source of an expanded cluster, listed under the cluster row
+    Member(&'a Article),
+}
+
+impl<'a> ArticleRow<'a> {
+    pub fn article(&self) -> &'a Article {
+        match self {
+            ArticleRow::Single(article) | ArticleRow::Member(article) => article,
+            ArticleRow::Cluster { lead, .. } => lead,
+        }
+    }
+
+    /// The articles an action on this row applies to
+    pub fn articles(&self) -> Vec<&'a Article> {
+        match self {
+            ArticleRow::Cluster { members, .. } => members.clone(),
+            _ => vec![self.article()],
+        }
+    }
+}
+
+/// A row of the article list by position in `App::articles`, kept between frames so
+/// lookups don't regroup the clusters
+enum RowSlot {
+    Single(usize),
+    Cluster {
+        lead: usize,
+        members: Vec<usize>,
+        expanded: bool,
+    },
+    Member(usize),
+}
+
+impl RowSlot {
+    fn article(&self) -> usize {
+        match self {
+            RowSlot::Single(i) | RowSlot::Member(i) => *i,
+            RowSlot::Cluster { lead, .. } => *lead,
+        }
+    }
+}
 
 // Message for completed summary
 pub struct SummaryResult {
     pub article_id: i64,
-    pub result: std::result::Result<(String, String), String>, // (content, model) or error
+    pub template: String,
+    /// Hash of the text that was summarized, so an identical story can reuse the summary
+    pub content_hash: Option<String>,
+    pub result: std::result::Result<(GeneratedSummary, String), String>, // (summary, model) or error
+}
+
+/// An answer in an article's follow-up chat, with the article text it was read from
+/// so the next question doesn't fetch it again
+pub struct ChatReply {
+    pub article_id: i64,
+    pub source: String,
+    pub answer: String,
+}
+
+/// An article's title and feed text in the target language
+pub struct Translation {
+    pub article_id: i64,
+    pub title: String,
+    pub text: String,
+}
+
+/// A Raindrop bookmark waiting for its note: the article, the cluster sources it covers
+/// and its tags
+pub struct PendingBookmark {
+    article: Article,
+    cluster_ids: Vec<i64>,
+    tags: Vec<String>,
+}
+
+/// A bookmark's summary written again, or why it couldn't be, with the summary it replaces
+pub struct RewrittenNote {
+    bookmark: PendingBookmark,
+    old: Summary,
+    result: std::result::Result<Summary, String>,
+}
+
+/// Tags suggested for an article's bookmark
+pub struct TagSuggestion {
+    pub article_id: i64,
+    pub tags: Vec<String>,
 }
 
 // Message for completed refresh
@@ -44,6 +141,12 @@ pub struct App {
     pub bookmark_status: Option<(String, Instant)>, // (message, timestamp)
     pub tag_input_active: bool,
     pub tag_input: String,
+    /// Tags the summarizer suggested for the tag prompt
+    pub suggested_tags: Vec<String>,
+    /// Show the selected article's translation in place of its title and text
+    pub show_translation: bool,
+    /// Translations made this session, by article id
+    translations: HashMap<i64, Translation>,
     pub feed_input_active: bool,
     pub feed_input: String,
     pub feed_input_status: Option<String>,
@@ -54,18 +157,84 @@ pub struct App {
     pub opml_export_input: String,
     pub opml_export_status: Option<String>,
     pub is_saved_to_raindrop: bool,
-    pub last_deleted: Option<(i64, String)>, // (feed_id, guid) for undo
+    pub last_deleted: Vec<(i64, String)>, // (feed_id, guid) for undo
     pub spinner_frame: usize,
     pub saved_count: usize,
+    /// Duplicate clusters currently showing their individual sources
+    pub expanded_clusters: HashSet<i64>,
+    /// The article list rows, rebuilt when `articles` or `expanded_clusters` change
+    rows: Vec<RowSlot>,
+    pub filtered_view_active: bool,
+    pub blocked_articles: Vec<BlockedArticle>,
+    pub blocked_selected_index: usize,
+    pub filter_rule_hits: Vec<(String, usize, usize)>, // (rule, hits, rescued)
+    pub blocklist_view_active: bool,
+    pub blocklist_selected_index: usize,
+    pub blocklist_input_active: bool,
+    pub blocklist_input: String,
+    pub blocklist_status: Option<String>,
+    pub block_suggest_active: bool,
+    pub block_suggestions: Vec<String>,
+    pub block_suggest_index: usize,
+    /// Follow-up chat about the selected article
+    pub chat_active: bool,
+    pub chat_input: String,
+    pub chat_messages: Vec<ChatMessage>,
+    pub chat_error: Option<String>,
+    chat_article_id: Option<i64>,
+    /// Article text the last answer was read from: (article id, text)
+    chat_source: Option<(i64, String)>,
+    /// Show rundown screen: the rundown of `SHOWS[rundown_show]`
+    pub rundown_active: bool,
+    pub rundown: Option<Rundown>,
+    pub rundown_show: usize,
+    pub rundown_selected_index: usize,
+    pub rundown_note_active: bool,
+    pub rundown_note_input: String,
+    pub rundown_status: Option<String>,
+    /// `N` was pressed once; pressing it again starts a new rundown
+    pub rundown_confirm_finish: bool,
+    rundown_settings: RundownConfig,
 
     // Async state
     pub is_refreshing: bool,
     pub summary_status: SummaryStatus,
     pub pending_summary_article_id: Option<i64>,
+    pub template_picker_active: bool,
+    pub template_picker_index: usize,
+    pub stats_active: bool,
+    pub usage_report: UsageReport,
+    /// Text of the summary being generated, as it streams in
+    pub streaming_summary: String,
+    /// Why the last summary failed, worded for the summary pane
+    pub summary_error: Option<String>,
     summary_rx: mpsc::Receiver<SummaryResult>,
     summary_tx: mpsc::Sender<SummaryResult>,
+    summary_chunk_rx: mpsc::UnboundedReceiver<(i64, String)>,
+    summary_chunk_tx: mpsc::UnboundedSender<(i64, String)>,
+    summary_task: Option<JoinHandle<()>>,
     refresh_rx: mpsc::Receiver<RefreshResult>,
     refresh_tx: mpsc::Sender<RefreshResult>,
+    last_refresh: Instant,
+    /// When the latest refresh started; articles fetched since then are new
+    refresh_started_at: chrono::DateTime<chrono::Utc>,
+    refresh_interval: std::time::Duration,
+    presummarize: PresummarizeConfig,
+    monthly_budget: Option<f64>,
+    presummarize_due: bool,
+    presummarize_task: Option<JoinHandle<()>>,
+    presummarized_rx: mpsc::UnboundedReceiver<i64>,
+    presummarized_tx: mpsc::UnboundedSender<i64>,
+    briefing: BriefingConfig,
+    unverified_quotes: UnverifiedQuotes,
+    briefing_task: Option<JoinHandle<std::result::Result<PathBuf, String>>>,
+    chat_task: Option<JoinHandle<std::result::Result<ChatReply, String>>>,
+    default_tags: Vec<String>,
+    suggest_tags: bool,
+    tag_task: Option<JoinHandle<std::result::Result<TagSuggestion, String>>>,
+    /// A bookmark whose summary is being rewritten before it's sent
+    bookmark_task: Option<JoinHandle<RewrittenNote>>,
+    translate_task: Option<JoinHandle<std::result::Result<Translation, String>>>,
     discovery_rx: mpsc::Receiver<FeedDiscoveryResult>,
     discovery_tx: mpsc::Sender<FeedDiscoveryResult>,
 
@@ -74,7 +243,7 @@ pub struct App {
     fetcher: FeedFetcher,
     summarizer: Option<Arc<Summarizer>>,
     raindrop: Option<RaindropClient>,
-    content_fetcher: ContentFetcher,
+    content_fetcher: Arc<ContentFetcher>,
 }
 
 impl App {
@@ -82,17 +251,14 @@ impl App {
         let repository = Repository::new(&config.db_path).await?;
         let fetcher = FeedFetcher::new();
 
-        let summarizer = config
-            .claude_api_key
-            .as_ref()
-            .map(|key| Arc::new(Summarizer::new(key.clone())));
+        let summarizer = Summarizer::from_config(config).map(Arc::new);
 
         let raindrop = config
             .raindrop_token
             .as_ref()
             .map(|token| RaindropClient::new(token.clone()));
 
-        let content_fetcher = ContentFetcher::new();
+        let content_fetcher = Arc::new(ContentFetcher::new(config.cookies.clone()));
 
         // Clean up articles older than 7 days
         let deleted = repository.delete_old_articles(7).await?;
@@ -104,12 +270,14 @@ impl App {
         let articles = repository.get_all_articles_sorted().await?;
 
         let (summary_tx, summary_rx) = mpsc::channel(1);
+        let (summary_chunk_tx, summary_chunk_rx) = mpsc::unbounded_channel();
         let (refresh_tx, refresh_rx) = mpsc::channel(1);
+        let (presummarized_tx, presummarized_rx) = mpsc::unbounded_channel();
         let (discovery_tx, discovery_rx) = mpsc::channel(1);
 
         let blocklist = Blocklist::load();
 
-        Ok(Self {
+        let mut app = Self {
             feeds,
             articles,
             current_summary: None,
@@ -120,6 +288,9 @@ impl App {
             bookmark_status: None,
             tag_input_active: false,
             tag_input: String::new(),
+            suggested_tags: Vec::new(),
+            show_translation: false,
+            translations: HashMap::new(),
             feed_input_active: false,
             feed_input: String::new(),
             feed_input_status: None,
@@ -130,16 +301,51 @@ impl App {
             opml_export_input: String::new(),
             opml_export_status: None,
             is_saved_to_raindrop: false,
-            last_deleted: None,
+            last_deleted: Vec::new(),
             spinner_frame: 0,
             saved_count: 0,
+            expanded_clusters: HashSet::new(),
+            rows: Vec::new(),
+            filtered_view_active: false,
+            blocked_articles: Vec::new(),
+            blocked_selected_index: 0,
+            filter_rule_hits: Vec::new(),
             is_refreshing: false,
             summary_status: SummaryStatus::NotGenerated,
             pending_summary_article_id: None,
+            template_picker_active: false,
+            template_picker_index: 0,
+            stats_active: false,
+            usage_report: UsageReport::default(),
+            streaming_summary: String::new(),
+            summary_error: None,
             summary_rx,
             summary_tx,
+            summary_chunk_rx,
+            summary_chunk_tx,
+            summary_task: None,
             refresh_rx,
             refresh_tx,
+            last_refresh: Instant::now(),
+            refresh_started_at: chrono::Utc::now(),
+            refresh_interval: std::time::Duration::from_secs(
+                u64::from(config.refresh_interval_minutes) * 60,
+            ),
+            presummarize: config.presummarize.clone(),
+            monthly_budget: config.usage.monthly_budget,
+            presummarize_due: false,
+            presummarize_task: None,
+            presummarized_rx,
+            presummarized_tx,
+            briefing: config.briefing.clone(),
+            unverified_quotes: config.summarizer.unverified_quotes,
+            briefing_task: None,
+            chat_task: None,
+            default_tags: config.default_tags.clone(),
+            suggest_tags: config.summarizer.suggest_tags.unwrap_or(true),
+            tag_task: None,
+            bookmark_task: None,
+            translate_task: None,
             discovery_rx,
             discovery_tx,
             repository,
@@ -147,23 +353,159 @@ impl App {
             summarizer,
             raindrop,
             content_fetcher,
-        })
+            blocklist_view_active: false,
+            blocklist_selected_index: 0,
+            blocklist_input_active: false,
+            blocklist_input: String::new(),
+            blocklist_status: None,
+            block_suggest_active: false,
+            block_suggestions: Vec::new(),
+            block_suggest_index: 0,
+            chat_active: false,
+            chat_input: String::new(),
+            chat_messages: Vec::new(),
+            chat_error: None,
+            chat_article_id: None,
+            chat_source: None,
+            rundown_active: false,
+            rundown: None,
+            rundown_show: 0,
+            rundown_selected_index: 0,
+            rundown_note_active: false,
+            rundown_note_input: String::new(),
+            rundown_status: None,
+            rundown_confirm_finish: false,
+            rundown_settings: config.rundown.clone(),
+        };
+
+        // The blocklist may have been edited while the app was closed
+        app.reapply_blocklist().await?;
+        app.cluster_articles().await?;
+
+        Ok(app)
     }
 
     pub fn filtered_articles(&self) -> Vec<&Article> {
-        self.articles.iter().collect()
+        self.rows
+            .iter()
+            .map(|row| &self.articles[row.article()])
+            .collect()
+    }
+
+    /// The article list as shown: duplicates collapsed into one row per cluster,
+    /// at the position of the cluster's newest article
+    pub fn visible_rows(&self) -> Vec<ArticleRow<'_>> {
+        self.rows.iter().map(|row| self.article_row(row)).collect()
+    }
+
+    fn article_row(&self, row: &RowSlot) -> ArticleRow<'_> {
+        match row {
+            RowSlot::Single(i) => ArticleRow::Single(&self.articles[*i]),
+            RowSlot::Cluster {
+                lead,
+                members,
+                expanded,
+            } => ArticleRow::Cluster {
+                lead: &self.articles[*lead],
+                members: members.iter().map(|i| &self.articles[*i]).collect(),
+                expanded: *expanded,
+            },
+            RowSlot::Member(i) => ArticleRow::Member(&self.articles[*i]),
+        }
+    }
+
+    /// Regroup `articles` into list rows; call after changing `articles` or
+    /// `expanded_clusters`
+    fn rebuild_rows(&mut self) {
+        let mut clusters: HashMap<i64, Vec<usize>> = HashMap::new();
+        for (i, article) in self.articles.iter().enumerate() {
+            if let Some(cluster_id) = article.cluster_id {
+                clusters.entry(cluster_id).or_default().push(i);
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut seen = HashSet::new();
+        for (i, article) in self.articles.iter().enumerate() {
+            let members = article.cluster_id.and_then(|id| clusters.get(&id));
+            match members {
+                Some(members) if members.len() > 1 => {
+                    let cluster_id = article.cluster_id.unwrap_or_default();
+                    if !seen.insert(cluster_id) {
+                        continue;
+                    }
+                    let expanded = self.expanded_clusters.contains(&cluster_id);
+                    rows.push(RowSlot::Cluster {
+                        lead: i,
+                        members: members.clone(),
+                        expanded,
+                    });
+                    if expanded {
+                        rows.extend(members.iter().map(|member| RowSlot::Member(*member)));
+                    }
+                }
+                _ => rows.push(RowSlot::Single(i)),
+            }
+        }
+        self.rows = rows;
+    }
+
+    /// The articles an action on the selected row applies to: every source
+    /// of a cluster row, otherwise just the selected article
+    fn selected_articles(&self) -> Vec<&Article> {
+        self.rows
+            .get(self.selected_index)
+            .map(|row| self.article_row(row).articles())
+            .unwrap_or_default()
     }
 
     pub fn selected_article(&self) -> Option<&Article> {
-        let articles = self.filtered_articles();
-        articles.get(self.selected_index).copied()
+        self.rows
+            .get(self.selected_index)
+            .map(|row| &self.articles[row.article()])
     }
 
-    #[allow(dead_code)]
     pub fn blocklist(&self) -> &Blocklist {
         &self.blocklist
     }
 
+    /// The popup or prompt that currently receives key presses
+    pub fn input_mode(&self) -> InputMode {
+        if self.show_help {
+            InputMode::Help
+        } else if self.bookmark_prefix_active {
+            InputMode::BookmarkPrefix
+        } else if self.tag_input_active {
+            InputMode::TagInput
+        } else if self.feed_input_active {
+            InputMode::FeedInput
+        } else if self.opml_input_active {
+            InputMode::OpmlInput
+        } else if self.opml_export_active {
+            InputMode::OpmlExport
+        } else if self.filtered_view_active {
+            InputMode::FilteredView
+        } else if self.blocklist_input_active {
+            InputMode::BlocklistInput
+        } else if self.blocklist_view_active {
+            InputMode::BlocklistView
+        } else if self.block_suggest_active {
+            InputMode::BlockSuggest
+        } else if self.template_picker_active {
+            InputMode::TemplatePicker
+        } else if self.stats_active {
+            InputMode::Stats
+        } else if self.chat_active {
+            InputMode::Chat
+        } else if self.rundown_note_active {
+            InputMode::RundownNote
+        } else if self.rundown_active {
+            InputMode::Rundown
+        } else {
+            InputMode::Normal
+        }
+    }
+
     pub async fn handle_action(&mut self, action: AppAction) -> Result<bool> {
         match action {
             AppAction::Quit => {
@@ -204,9 +546,20 @@ impl App {
             }
 
             AppAction::SelectArticle => {
+                // Reading one copy of a story reads them all
+                let ids = self.selected_articles().iter().map(|a| a.id).collect();
+                self.repository.mark_articles_read(ids).await?;
                 self.generate_summary().await?;
             }
 
+            AppAction::ToggleCluster => {
+                let previous = self.selected_index;
+                self.toggle_selected_cluster();
+                if self.selected_index != previous {
+                    self.on_selection_changed().await?;
+                }
+            }
+
             AppAction::RefreshFeeds => {
                 self.refresh_feeds();
             }
@@ -240,6 +593,7 @@ impl App {
                 if self.raindrop.is_some() && self.selected_article().is_some() {
                     self.tag_input_active = true;
                     self.tag_input.clear();
+                    self.start_tag_suggestion().await?;
                 }
             }
 
@@ -251,21 +605,226 @@ impl App {
             }
 
             AppAction::RegenerateSummary => {
-                self.summary_status = SummaryStatus::NotGenerated;
-                self.current_summary = None;
-                self.generate_summary().await?;
+                // Same template as the summary being replaced
+                let template = self
+                    .current_summary
+                    .as_ref()
+                    .and_then(|s| s.template.clone());
+                self.regenerate_summary(template).await?;
+            }
+
+            AppAction::ShowTemplatePicker => {
+                if let Some(summarizer) = &self.summarizer {
+                    if self.selected_article().is_some() {
+                        let current = self
+                            .current_summary
+                            .as_ref()
+                            .and_then(|s| s.template.as_deref())
+                            .unwrap_or(summarizer.default_template());
+                        self.template_picker_index = summarizer
+                            .template_names()
+                            .iter()
+                            .position(|name| *name == current)
+                            .unwrap_or(0);
+                        self.template_picker_active = true;
+                    }
+                } else {
+                    self.summary_status = SummaryStatus::NoApiKey;
+                }
+            }
+
+            AppAction::TemplatePickerMoveUp => {
+                self.template_picker_index = self.template_picker_index.saturating_sub(1);
+            }
+
+            AppAction::TemplatePickerMoveDown => {
+                if self.template_picker_index + 1 < self.template_names().len() {
+                    self.template_picker_index += 1;
+                }
+            }
+
+            AppAction::TemplatePickerConfirm => {
+                self.template_picker_active = false;
+                let template = self
+                    .template_names()
+                    .get(self.template_picker_index)
+                    .cloned();
+                self.regenerate_summary(template).await?;
+            }
+
+            AppAction::TemplatePickerCancel => {
+                self.template_picker_active = false;
+            }
+
+            AppAction::ShowStats => {
+                self.usage_report =
+                    UsageReport::load(&self.repository, self.monthly_budget).await?;
+                self.stats_active = true;
+            }
+
+            AppAction::CloseStats => {
+                self.stats_active = false;
+            }
+
+            AppAction::WriteBriefing => {
+                self.start_briefing();
+            }
+
+            AppAction::Translate => {
+                self.translate_selected();
+            }
+
+            AppAction::ShowChat => {
+                self.open_chat().await?;
+            }
+
+            AppAction::ChatInputChar(c) => {
+                self.chat_input.push(c);
+            }
+
+            AppAction::ChatInputBackspace => {
+                self.chat_input.pop();
+            }
+
+            AppAction::ChatSend => {
+                self.send_chat_question().await?;
+            }
+
+            AppAction::ChatClear => {
+                if let Some(article_id) = self.chat_article_id {
+                    if !self.is_answering() {
+                        self.repository.clear_chat(article_id).await?;
+                        self.chat_messages.clear();
+                        self.chat_error = None;
+                    }
+                }
+            }
+
+            AppAction::CloseChat => {
+                self.chat_active = false;
+            }
+
+            AppAction::ShowRundown => {
+                self.rundown_active = true;
+                self.rundown_selected_index = 0;
+                self.rundown_status = None;
+                self.rundown_confirm_finish = false;
+                self.load_rundown().await?;
+            }
+
+            AppAction::CloseRundown => {
+                self.rundown_active = false;
+                self.rundown = None;
+            }
+
+            AppAction::RundownNextShow | AppAction::RundownPrevShow => {
+                self.rundown_show = if matches!(action, AppAction::RundownNextShow) {
+                    (self.rundown_show + 1) % SHOWS.len()
+                } else {
+                    (self.rundown_show + SHOWS.len() - 1) % SHOWS.len()
+                };
+                self.rundown_selected_index = 0;
+                self.rundown_status = None;
+                self.rundown_confirm_finish = false;
+                self.load_rundown().await?;
+            }
+
+            AppAction::RundownMoveUp => {
+                self.rundown_confirm_finish = false;
+                self.rundown_selected_index = self.rundown_selected_index.saturating_sub(1);
+            }
+
+            AppAction::RundownMoveDown => {
+                self.rundown_confirm_finish = false;
+                if self.rundown_selected_index + 1 < self.rundown_len() {
+                    self.rundown_selected_index += 1;
+                }
+            }
+
+            AppAction::RundownMoveItemUp => {
+                self.move_rundown_item(false).await?;
+            }
+
+            AppAction::RundownMoveItemDown => {
+                self.move_rundown_item(true).await?;
+            }
+
+            AppAction::RundownNoteStart => {
+                self.rundown_confirm_finish = false;
+                if let Some(item) = self
+                    .rundown
+                    .as_ref()
+                    .and_then(|r| r.items.get(self.rundown_selected_index))
+                {
+                    self.rundown_note_input = item.note.clone().unwrap_or_default();
+                    self.rundown_note_active = true;
+                }
+            }
+
+            AppAction::RundownNoteChar(c) => {
+                self.rundown_note_input.push(c);
+            }
+
+            AppAction::RundownNoteBackspace => {
+                self.rundown_note_input.pop();
+            }
+
+            AppAction::RundownNoteConfirm => {
+                self.save_rundown_note().await?;
+            }
+
+            AppAction::RundownNoteCancel => {
+                self.rundown_note_active = false;
+                self.rundown_note_input.clear();
+            }
+
+            AppAction::RundownExport => {
+                self.rundown_confirm_finish = false;
+                let show = SHOWS[self.rundown_show];
+                self.rundown_status = Some(
+                    match rundown::export(&self.repository, show, &self.rundown_settings).await {
+                        Ok(path) => format!("Exported to {}", path.display()),
+                        Err(e) => format!("Error: {}", e),
+                    },
+                );
+            }
+
+            AppAction::RundownFinish => {
+                let show = SHOWS[self.rundown_show];
+                if self.rundown_len() == 0 {
+                    self.rundown_status = Some(format!("The {} rundown is empty", show));
+                } else if self.rundown_confirm_finish {
+                    self.rundown_confirm_finish = false;
+                    self.repository.finish_rundown(show).await?;
+                    self.rundown_selected_index = 0;
+                    self.load_rundown().await?;
+                    self.rundown_status = Some(format!("Started a new {} rundown", show));
+                } else {
+                    self.rundown_confirm_finish = true;
+                    self.rundown_status = Some(format!(
+                        "Press N again to clear the {} rundown for the next show",
+                        show
+                    ));
+                }
             }
 
             AppAction::DeleteArticle => {
-                if let Some(article) = self.selected_article() {
-                    let id = article.id;
-                    let feed_id = article.feed_id;
-                    let guid = article.guid.clone();
-                    self.repository.delete_article(id).await?;
+                let targets: Vec<(i64, i64, String)> = self
+                    .selected_articles()
+                    .iter()
+                    .map(|a| (a.id, a.feed_id, a.guid.clone()))
+                    .collect();
+                if !targets.is_empty() {
+                    let ids: Vec<i64> = targets.iter().map(|(id, _, _)| *id).collect();
+                    self.repository.delete_articles(ids.clone()).await?;
                     // Store for undo
-                    self.last_deleted = Some((feed_id, guid));
+                    self.last_deleted = targets
+                        .into_iter()
+                        .map(|(_, feed_id, guid)| (feed_id, guid))
+                        .collect();
                     // Remove from local list
-                    self.articles.retain(|a| a.id != id);
+                    self.articles.retain(|a| !ids.contains(&a.id));
+                    self.rebuild_rows();
                     // Adjust selection if needed
                     let len = self.filtered_articles().len();
                     if len > 0 && self.selected_index >= len {
@@ -284,6 +843,7 @@ impl App {
                     self.repository.delete_feed(feed_id).await?;
                     // Remove all articles from this feed from local list
                     self.articles.retain(|a| a.feed_id != feed_id);
+                    self.rebuild_rows();
                     // Reload feeds list
                     self.feeds = self.repository.get_all_feeds().await?;
                     // Adjust selection if needed
@@ -298,7 +858,7 @@ impl App {
             }
 
             AppAction::UndeleteArticle => {
-                if let Some((feed_id, guid)) = self.last_deleted.take() {
+                for (feed_id, guid) in std::mem::take(&mut self.last_deleted) {
                     self.repository.undelete_article(feed_id, &guid).await?;
                     // Article will return on next refresh (press 'r')
                 }
@@ -402,6 +962,160 @@ impl App {
                 self.opml_export_input.clear();
                 self.opml_export_status = None;
             }
+
+            AppAction::ReapplyBlocklist => {
+                self.blocklist.reload();
+                let (hidden, unhidden) = self.reapply_blocklist().await?;
+                self.bookmark_status = Some((
+                    format!("Blocklist: {} hidden, {} unhidden", hidden, unhidden),
+                    Instant::now(),
+                ));
+            }
+
+            AppAction::ShowBlocklist => {
+                if self.blocklist.reload() {
+                    self.reapply_blocklist().await?;
+                }
+                self.blocklist_view_active = true;
+                self.blocklist_selected_index = 0;
+                self.blocklist_status = None;
+            }
+
+            AppAction::HideBlocklist => {
+                self.blocklist_view_active = false;
+                self.blocklist_status = None;
+            }
+
+            AppAction::BlocklistMoveUp => {
+                self.blocklist_selected_index = self.blocklist_selected_index.saturating_sub(1);
+            }
+
+            AppAction::BlocklistMoveDown => {
+                if self.blocklist_selected_index + 1 < self.blocklist.rules().len() {
+                    self.blocklist_selected_index += 1;
+                }
+            }
+
+            AppAction::BlocklistRemove => {
+                self.remove_selected_rule().await?;
+            }
+
+            AppAction::BlocklistInputStart => {
+                self.blocklist_input_active = true;
+                self.blocklist_input.clear();
+                self.blocklist_status = None;
+            }
+
+            AppAction::BlocklistInputChar(c) => {
+                self.blocklist_input.push(c);
+            }
+
+            AppAction::BlocklistInputBackspace => {
+                self.blocklist_input.pop();
+            }
+
+            AppAction::BlocklistInputConfirm => {
+                let line = self.blocklist_input.trim().to_string();
+                if line.is_empty() {
+                    self.blocklist_input_active = false;
+                } else if let Some(hidden) = self.add_blocklist_rule(&line).await? {
+                    self.blocklist_input_active = false;
+                    self.blocklist_input.clear();
+                    self.blocklist_selected_index = self.blocklist.rules().len().saturating_sub(1);
+                    self.blocklist_status =
+                        Some(format!("Added: {} ({} articles hidden)", line, hidden));
+                }
+            }
+
+            AppAction::BlocklistInputCancel => {
+                self.blocklist_input_active = false;
+                self.blocklist_input.clear();
+                self.blocklist_status = None;
+            }
+
+            AppAction::BlockThis => {
+                self.suggest_block_rules();
+            }
+
+            AppAction::BlockSuggestMoveUp => {
+                self.block_suggest_index = self.block_suggest_index.saturating_sub(1);
+            }
+
+            AppAction::BlockSuggestMoveDown => {
+                if self.block_suggest_index + 1 < self.block_suggestions.len() {
+                    self.block_suggest_index += 1;
+                }
+            }
+
+            AppAction::BlockSuggestConfirm => {
+                if let Some(line) = self
+                    .block_suggestions
+                    .get(self.block_suggest_index)
+                    .cloned()
+                {
+                    // On failure the popup stays open with the error
+                    if let Some(hidden) = self.add_blocklist_rule(&line).await? {
+                        self.block_suggest_active = false;
+                        self.bookmark_status = Some((
+                            format!("Blocked: {} ({} articles hidden)", line, hidden),
+                            Instant::now(),
+                        ));
+                    }
+                }
+            }
+
+            AppAction::BlockSuggestEdit => {
+                // Open the blocklist screen with the suggestion ready to edit
+                if let Some(line) = self
+                    .block_suggestions
+                    .get(self.block_suggest_index)
+                    .cloned()
+                {
+                    self.block_suggest_active = false;
+                    self.blocklist_view_active = true;
+                    self.blocklist_selected_index = 0;
+                    self.blocklist_status = None;
+                    self.blocklist_input_active = true;
+                    self.blocklist_input = line;
+                }
+            }
+
+            AppAction::BlockSuggestCancel => {
+                self.block_suggest_active = false;
+            }
+
+            AppAction::ShowFiltered => {
+                self.load_filtered_view().await?;
+                self.blocked_selected_index = 0;
+                self.filtered_view_active = true;
+            }
+
+            AppAction::HideFiltered => {
+                self.filtered_view_active = false;
+            }
+
+            AppAction::FilteredMoveUp => {
+                self.blocked_selected_index = self.blocked_selected_index.saturating_sub(1);
+            }
+
+            AppAction::FilteredMoveDown => {
+                if self.blocked_selected_index + 1 < self.blocked_articles.len() {
+                    self.blocked_selected_index += 1;
+                }
+            }
+
+            AppAction::RescueFiltered => {
+                self.rescue_selected_filtered().await?;
+            }
+
+            AppAction::OpenFilteredInBrowser => {
+                if let Some(entry) = self.blocked_articles.get(self.blocked_selected_index) {
+                    let url = entry.url.clone();
+                    std::thread::spawn(move || {
+                        let _ = open::that(&url);
+                    });
+                }
+            }
         }
 
         Ok(false)
@@ -409,10 +1123,12 @@ impl App {
 
     async fn on_selection_changed(&mut self) -> Result<()> {
         // Reset state when selection changes
+        self.cancel_summary();
         self.summary_status = SummaryStatus::NotGenerated;
         self.current_summary = None;
         self.is_saved_to_raindrop = false;
         self.bookmark_status = None;
+        self.show_translation = false;
 
         // Check if current article is saved to raindrop
         let article_id = self.selected_article().map(|a| a.id);
@@ -429,8 +1145,33 @@ impl App {
         Ok(())
     }
 
+    /// Template summaries use unless another one is picked
+    pub fn default_template(&self) -> Option<&str> {
+        self.summarizer.as_ref().map(|s| s.default_template())
+    }
+
+    /// Names of the prompt templates the summarizer offers
+    pub fn template_names(&self) -> Vec<String> {
+        self.summarizer
+            .as_ref()
+            .map(|s| s.template_names().into_iter().map(String::from).collect())
+            .unwrap_or_default()
+    }
+
     async fn generate_summary(&mut self) -> Result<()> {
-        let Some(summarizer) = &self.summarizer else {
+        self.start_summary(None, true).await
+    }
+
+    /// Write a fresh summary for the selected article, replacing the stored one.
+    /// `template` picks the prompt template (the configured default when None).
+    async fn regenerate_summary(&mut self, template: Option<String>) -> Result<()> {
+        self.summary_status = SummaryStatus::NotGenerated;
+        self.current_summary = None;
+        self.start_summary(template, false).await
+    }
+
+    async fn start_summary(&mut self, template: Option<String>, use_cache: bool) -> Result<()> {
+        let Some(summarizer) = self.summarizer.clone() else {
             self.summary_status = SummaryStatus::NoApiKey;
             return Ok(());
         };
@@ -440,57 +1181,93 @@ impl App {
         };
 
         // Check cache first
-        if let Some(summary) = self.repository.get_summary(article.id).await? {
-            self.current_summary = Some(summary);
-            self.summary_status = SummaryStatus::Generated;
-            return Ok(());
+        if use_cache {
+            if let Some(summary) = self.repository.get_summary(article.id).await? {
+                self.current_summary = Some(summary);
+                self.summary_status = SummaryStatus::Generated;
+                return Ok(());
+            }
         }
 
+        // Fall back to the default if a stored summary names a template that was removed
+        let template = template
+            .filter(|name| summarizer.template_names().contains(&name.as_str()))
+            .unwrap_or_else(|| summarizer.default_template().to_string());
+
+        let article = article.clone();
         let article_id = article.id;
         let title = article.title.clone();
-        let article_url = article.url.clone();
+        let feed_title = article.feed_title.clone();
+        let author = article.author.clone();
 
-        // Get RSS content as fallback
-        let rss_content = article
-            .content_text
-            .clone()
-            .or_else(|| article.content.clone())
-            .unwrap_or_default();
+        // The same story syndicated under another feed may already be summarized
+        if use_cache && self.reuse_summary(&article, &template, None).await? {
+            return Ok(());
+        }
 
+        self.cancel_summary();
         self.summary_status = SummaryStatus::Generating;
         self.pending_summary_article_id = Some(article_id);
 
-        // Try to fetch full content using browser cookies
-        let content = match self.content_fetcher.fetch_full_content(&article_url).await {
-            Ok(Some(full_content)) => {
-                tracing::info!("Fetched full content for: {}", article_url);
-                full_content
-            }
-            Ok(None) => {
-                tracing::debug!("No full content available, using RSS content");
-                rss_content
-            }
-            Err(e) => {
-                tracing::debug!("Failed to fetch full content: {}, using RSS", e);
-                rss_content
-            }
-        };
+        let content = self.source_text(&article).await;
+        let content_hash = cluster::content_hash(&content);
+        if use_cache
+            && content_hash.is_some()
+            && self
+                .reuse_summary(&article, &template, content_hash.clone())
+                .await?
+        {
+            self.pending_summary_article_id = None;
+            return Ok(());
+        }
 
         // Spawn background task for summary generation
-        let summarizer = Arc::clone(summarizer);
         let tx = self.summary_tx.clone();
+        let chunk_tx = self.summary_chunk_tx.clone();
+
+        self.summary_task = Some(tokio::spawn(async move {
+            let fields = PromptFields {
+                title: &title,
+                content: &content,
+                feed: feed_title.as_deref(),
+                author: author.as_deref(),
+            };
 
-        tokio::spawn(async move {
-            let result = match summarizer.generate_summary(&title, &content).await {
-                Ok(summary_text) => {
+            // Forward streamed text tagged with the article it belongs to
+            let (text_tx, mut text_rx) = mpsc::unbounded_channel();
+            let forward = tokio::spawn(async move {
+                while let Some(text) = text_rx.recv().await {
+                    if chunk_tx.send((article_id, text)).is_err() {
+                        break;
+                    }
+                }
+            });
+            let result = summarizer
+                .generate_summary_streaming(&fields, &template, &text_tx)
+                .await;
+            drop(text_tx);
+            let _ = forward.await;
+
+            let result = match result {
+                Ok(summary) => {
                     let model = summarizer.model_version().to_string();
-                    Ok((summary_text, model))
+                    Ok((summary, model))
+                }
+                Err(e) => {
+                    tracing::error!("Failed to generate summary: {}", e);
+                    Err(e.user_message())
                 }
-                Err(e) => Err(e.to_string()),
             };
 
-            let _ = tx.send(SummaryResult { article_id, result }).await;
-        });
+            let _ = tx
+                .send(SummaryResult {
+                    article_id,
+                    template,
+                    content_hash,
+                    result,
+                })
+                .await;
+        }));
 
         // Don't update local is_read state - keep article visible in filtered list
         // Database is already updated, so it will show as read next session
@@ -498,28 +1275,111 @@ impl App {
         Ok(())
     }
 
-    /// Advance the spinner animation frame
-    pub fn tick_spinner(&mut self) {
-        self.spinner_frame = (self.spinner_frame + 1) % 10;
+    /// Show a copy of the summary of an identical story, found by canonical URL or
+    /// `content_hash`. Returns whether one was found.
+    async fn reuse_summary(
+        &mut self,
+        article: &Article,
+        template: &str,
+        content_hash: Option<String>,
+    ) -> Result<bool> {
+        let reused = self
+            .repository
+            .reuse_summary(
+                article.id,
+                template.to_string(),
+                cluster::canonical_url(&article.url),
+                content_hash,
+                false,
+            )
+            .await?;
+        if !reused {
+            return Ok(false);
+        }
+        let Some(summary) = self.repository.get_summary(article.id).await? else {
+            return Ok(false);
+        };
+        if let Some(source) = &summary.reused_from {
+            self.bookmark_status = Some((
+                format!("Reused the summary from {}", source),
+                Instant::now(),
+            ));
+        }
+        self.current_summary = Some(summary);
+        self.summary_status = SummaryStatus::Generated;
+        Ok(true)
     }
 
-    /// Get the current spinner character
-    pub fn spinner_char(&self) -> char {
-        const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
-        SPINNER[self.spinner_frame]
+    /// The text to summarize: the full article fetched with browser cookies when
+    /// possible, else the feed's content
+    async fn source_text(&self, article: &Article) -> String {
+        Self::fetch_source_text(&self.content_fetcher, article).await
     }
 
-    /// Clear bookmark status after 2 seconds
-    pub fn check_bookmark_status_timeout(&mut self) {
-        if let Some((_, timestamp)) = &self.bookmark_status {
-            if timestamp.elapsed().as_secs() >= 2 {
-                self.bookmark_status = None;
-            }
-        }
+    async fn fetch_source_text(content_fetcher: &ContentFetcher, article: &Article) -> String {
+        let rss_content = article
+            .content_text
+            .clone()
+            .or_else(|| article.content.clone())
+            .unwrap_or_default();
+
+        match content_fetcher.fetch_full_content(&article.url).await {
+            Ok(Some(full_content)) => {
+                tracing::info!("Fetched full content for: {}", article.url);
+                full_content
+            }
+            Ok(None) => {
+                tracing::debug!("No full content available, using RSS content");
+                rss_content
+            }
+            Err(e) => {
+                tracing::debug!("Failed to fetch full content: {}, using RSS", e);
+                rss_content
+            }
+        }
+    }
+
+    /// Advance the spinner animation frame
+    pub fn tick_spinner(&mut self) {
+        self.spinner_frame = (self.spinner_frame + 1) % 10;
+    }
+
+    /// Get the current spinner character
+    pub fn spinner_char(&self) -> char {
+        const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+        SPINNER[self.spinner_frame]
+    }
+
+    /// Clear bookmark status after 2 seconds
+    pub fn check_bookmark_status_timeout(&mut self) {
+        if let Some((_, timestamp)) = &self.bookmark_status {
+            if timestamp.elapsed().as_secs() >= 2 {
+                self.bookmark_status = None;
+            }
+        }
+    }
+
+    /// Stop the summary being generated, if any. Nothing is saved for it.
+    fn cancel_summary(&mut self) {
+        if let Some(task) = self.summary_task.take() {
+            task.abort();
+        }
+        self.pending_summary_article_id = None;
+        self.streaming_summary.clear();
+        // Drop text and results the old task sent before it stopped
+        while self.summary_chunk_rx.try_recv().is_ok() {}
+        while self.summary_rx.try_recv().is_ok() {}
     }
 
     /// Poll for completed summary results (non-blocking)
     pub async fn poll_summary_result(&mut self) -> Result<()> {
+        // Append text streamed so far
+        while let Ok((article_id, text)) = self.summary_chunk_rx.try_recv() {
+            if self.pending_summary_article_id == Some(article_id) {
+                self.streaming_summary.push_str(&text);
+            }
+        }
+
         if let Ok(result) = self.summary_rx.try_recv() {
             // Only process if this is the summary we're waiting for
             if self.pending_summary_article_id == Some(result.article_id) {
@@ -527,16 +1387,30 @@ impl App {
                 let article_exists = self.articles.iter().any(|a| a.id == result.article_id);
 
                 match result.result {
-                    Ok((summary_text, model)) => {
+                    Ok((summary, model)) => {
+                        let canonical_url = self
+                            .articles
+                            .iter()
+                            .find(|a| a.id == result.article_id)
+                            .map(|a| cluster::canonical_url(&a.url));
                         if article_exists {
                             // Save to database only if article still exists
                             if let Err(e) = self
                                 .repository
-                                .save_summary(
-                                    result.article_id,
-                                    summary_text.clone(),
-                                    model.clone(),
-                                )
+                                .save_summary(NewSummary {
+                                    article_id: result.article_id,
+                                    content: summary.text.clone(),
+                                    model_version: model.clone(),
+                                    template: result.template.clone(),
+                                    automatic: false,
+                                    input_tokens: summary.usage.map(|u| u.input_tokens),
+                                    output_tokens: summary.usage.map(|u| u.output_tokens),
+                                    cost_usd: summary.cost_usd,
+                                    unverified_quotes: summary.unverified_quotes.clone(),
+                                    fields: summary.fields.clone(),
+                                    content_hash: result.content_hash.clone(),
+                                    canonical_url,
+                                })
                                 .await
                             {
                                 tracing::warn!(
@@ -548,9 +1422,13 @@ impl App {
                             self.current_summary = Some(Summary {
                                 id: 0,
                                 article_id: result.article_id,
-                                content: summary_text,
+                                content: summary.text,
                                 model_version: model,
                                 generated_at: chrono::Utc::now(),
+                                template: Some(result.template),
+                                unverified_quotes: Some(summary.unverified_quotes),
+                                fields: summary.fields,
+                                reused_from: None,
                             });
                             self.summary_status = SummaryStatus::Generated;
                         } else {
@@ -562,11 +1440,13 @@ impl App {
                         }
                     }
                     Err(e) => {
-                        tracing::error!("Failed to generate summary: {}", e);
+                        self.summary_error = Some(e);
                         self.summary_status = SummaryStatus::Failed;
                     }
                 }
                 self.pending_summary_article_id = None;
+                self.summary_task = None;
+                self.streaming_summary.clear();
             }
         }
         Ok(())
@@ -648,7 +1528,8 @@ impl App {
             return; // Already refreshing
         }
         self.is_refreshing = true;
-        self.blocklist.reload();
+        self.last_refresh = Instant::now();
+        self.refresh_started_at = chrono::Utc::now();
 
         let feeds = self.feeds.clone();
         let fetcher = self.fetcher.clone();
@@ -663,19 +1544,36 @@ impl App {
     /// Poll for completed refresh results (non-blocking)
     pub async fn poll_refresh_result(&mut self) -> Result<()> {
         if let Ok(result) = self.refresh_rx.try_recv() {
+            // Pick up blocklist edits before filtering new articles with it
+            let blocklist_changed = self.blocklist.reload();
+
             // Process the refresh results
             for (feed_id, articles) in result.results {
+                let feed = self.feeds.iter().find(|f| f.id == feed_id);
+                let feed_title = feed.map(|f| f.title.as_str());
+                let folder = feed.and_then(|f| f.folder.as_deref());
+
                 for article in articles {
-                    // Filter: skip articles containing blocked keywords
-                    let content_ref = article
-                        .content_text
-                        .as_deref()
-                        .or(article.content.as_deref());
-                    if self
-                        .blocklist
-                        .contains_blocked_keyword(&article.title, content_ref)
-                    {
-                        continue; // Silent skip - FILTER-06
+                    // Filter: hide articles matching a blocklist rule
+                    let target = BlockTarget {
+                        title: &article.title,
+                        content: article
+                            .content_text
+                            .as_deref()
+                            .or(article.content.as_deref()),
+                        author: article.author.as_deref(),
+                        url: &article.url,
+                        feed: feed_title,
+                        folder,
+                    };
+                    if let Some(rule) = self.blocklist.find_match(&target) {
+                        // Stored hidden so the filtered view can show (and rescue) it
+                        let rule = rule.source.clone();
+                        if let Err(e) = self.repository.upsert_blocked_article(article, rule).await
+                        {
+                            tracing::warn!("Failed to store blocked article: {}", e);
+                        }
+                        continue;
                     }
 
                     if let Err(e) = self.repository.upsert_article(article).await {
@@ -693,12 +1591,546 @@ impl App {
                 tracing::info!("Deleted {} articles older than 7 days", deleted);
             }
 
+            if blocklist_changed {
+                self.reapply_blocklist().await?;
+            }
+
             self.reload_articles().await?;
             self.is_refreshing = false;
+            self.presummarize_due = self.presummarize.enabled;
+        }
+        Ok(())
+    }
+
+    /// Start a refresh once `refresh_interval_minutes` have passed since the last one
+    /// (0 turns auto-refresh off)
+    pub fn check_auto_refresh(&mut self) {
+        if self.refresh_interval.is_zero() || self.is_refreshing {
+            return;
+        }
+        if self.last_refresh.elapsed() >= self.refresh_interval {
+            tracing::info!("Auto-refreshing feeds");
+            self.refresh_feeds();
+        }
+    }
+
+    /// Summarize new articles in the background after a refresh, if enabled, and show
+    /// background summaries for the selected article as they arrive
+    pub async fn poll_presummarize(&mut self) -> Result<()> {
+        if self
+            .presummarize_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            self.presummarize_task = None;
+        }
+        if self.presummarize_due && self.presummarize_task.is_none() {
+            self.presummarize_due = false;
+            if let Some(summarizer) = &self.summarizer {
+                let run = crate::presummarize::run(
+                    self.repository.clone(),
+                    Arc::clone(summarizer),
+                    Arc::clone(&self.content_fetcher),
+                    self.presummarize.clone(),
+                    self.refresh_started_at,
+                    self.monthly_budget,
+                    Some(self.presummarized_tx.clone()),
+                );
+                self.presummarize_task = Some(tokio::spawn(async move {
+                    if let Err(e) = run.await {
+                        tracing::warn!("Background summaries failed: {}", e);
+                    }
+                }));
+            }
+        }
+
+        while let Ok(article_id) = self.presummarized_rx.try_recv() {
+            let selected = self.selected_article().map(|a| a.id);
+            if selected == Some(article_id) && self.summary_status == SummaryStatus::NotGenerated {
+                if let Some(summary) = self.repository.get_summary(article_id).await? {
+                    self.current_summary = Some(summary);
+                    self.summary_status = SummaryStatus::Generated;
+                }
+            }
         }
         Ok(())
     }
 
+    /// Whether a briefing is being written
+    pub fn is_writing_briefing(&self) -> bool {
+        self.briefing_task.is_some()
+    }
+
+    /// Write the daily briefing in the background
+    fn start_briefing(&mut self) {
+        if self.briefing_task.is_some() {
+            return;
+        }
+        let Some(summarizer) = self.summarizer.clone() else {
+            self.bookmark_status = Some((
+                "Briefing needs a summarizer API key".to_string(),
+                Instant::now(),
+            ));
+            return;
+        };
+        let repository = self.repository.clone();
+        let settings = self.briefing.clone();
+        self.briefing_task = Some(tokio::spawn(async move {
+            crate::briefing::run(&repository, &summarizer, &settings)
+                .await
+                .map_err(|e| e.user_message())
+        }));
+    }
+
+    /// Report the briefing once it's written
+    pub async fn poll_briefing(&mut self) {
+        if !self
+            .briefing_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            return;
+        }
+        let Some(task) = self.briefing_task.take() else {
+            return;
+        };
+        let message = match task.await {
+            Ok(Ok(path)) => format!("Briefing saved to {}", path.display()),
+            Ok(Err(e)) => {
+                tracing::warn!("Briefing failed: {}", e);
+                format!("Briefing failed: {}", e)
+            }
+            Err(e) => format!("Briefing failed: {}", e),
+        };
+        self.bookmark_status = Some((message, Instant::now()));
+    }
+
+    /// Open the follow-up chat for the selected article, with its saved conversation
+    async fn open_chat(&mut self) -> Result<()> {
+        let Some(article_id) = self.selected_article().map(|a| a.id) else {
+            return Ok(());
+        };
+        if self.summarizer.is_none() {
+            self.summary_status = SummaryStatus::NoApiKey;
+            return Ok(());
+        }
+        self.chat_messages = self.repository.get_chat(article_id).await?;
+        self.chat_article_id = Some(article_id);
+        self.chat_input.clear();
+        self.chat_error = None;
+        self.chat_active = true;
+        Ok(())
+    }
+
+    /// Whether an answer in the chat is being written
+    pub fn is_answering(&self) -> bool {
+        self.chat_task.is_some()
+    }
+
+    /// Save the typed question and ask the summarizer to answer it from the article
+    async fn send_chat_question(&mut self) -> Result<()> {
+        let question = self.chat_input.trim().to_string();
+        if question.is_empty() || self.is_answering() {
+            return Ok(());
+        }
+        let (Some(summarizer), Some(article_id)) = (self.summarizer.clone(), self.chat_article_id)
+        else {
+            return Ok(());
+        };
+        let Some(article) = self.articles.iter().find(|a| a.id == article_id).cloned() else {
+            return Ok(());
+        };
+
+        let history = self.chat_messages.clone();
+        let message = ChatMessage::user(question.clone());
+        self.repository
+            .add_chat_message(article_id, message.clone())
+            .await?;
+        self.chat_messages.push(message);
+        self.chat_input.clear();
+        self.chat_error = None;
+
+        let source = self
+            .chat_source
+            .as_ref()
+            .filter(|(id, _)| *id == article_id)
+            .map(|(_, text)| text.clone());
+        let repository = self.repository.clone();
+        let content_fetcher = Arc::clone(&self.content_fetcher);
+        self.chat_task = Some(tokio::spawn(async move {
+            let source = match source {
+                Some(source) => source,
+                None => Self::fetch_source_text(&content_fetcher, &article).await,
+            };
+            let answer = summarizer
+                .answer(&article.title, &source, &history, &question)
+                .await
+                .map_err(|e| e.user_message())?;
+            let saved = repository
+                .add_chat_message(article_id, ChatMessage::assistant(answer.text.clone()))
+                .await;
+            if let Err(e) = saved {
+                tracing::warn!("Failed to save chat answer: {}", e);
+            }
+            if let Some(usage) = answer.usage {
+                let logged = repository
+                    .log_usage(
+                        summarizer.model_version().to_string(),
+                        usage.input_tokens,
+                        usage.output_tokens,
+                        answer.cost_usd,
+                    )
+                    .await;
+                if let Err(e) = logged {
+                    tracing::warn!("Failed to log chat usage: {}", e);
+                }
+            }
+            Ok(ChatReply {
+                article_id,
+                source,
+                answer: answer.text,
+            })
+        }));
+        Ok(())
+    }
+
+    /// Show the chat answer once it's written
+    pub async fn poll_chat(&mut self) {
+        if !self
+            .chat_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            return;
+        }
+        let Some(task) = self.chat_task.take() else {
+            return;
+        };
+        match task.await {
+            Ok(Ok(reply)) => {
+                if self.chat_article_id == Some(reply.article_id) {
+                    self.chat_messages
+                        .push(ChatMessage::assistant(reply.answer));
+                }
+                self.chat_source = Some((reply.article_id, reply.source));
+            }
+            Ok(Err(e)) => {
+                tracing::warn!("Chat answer failed: {}", e);
+                self.chat_error = Some(e);
+            }
+            Err(e) => self.chat_error = Some(e.to_string()),
+        }
+    }
+
+    /// Tags for suggestions to be picked from: those on earlier bookmarks, then the
+    /// configured default tags
+    async fn tag_vocabulary(&self) -> Result<Vec<String>> {
+        let mut vocabulary = self.repository.get_raindrop_tags().await?;
+        for tag in &self.default_tags {
+            if !vocabulary.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                vocabulary.push(tag.clone());
+            }
+        }
+        Ok(vocabulary)
+    }
+
+    /// Ask the summarizer for tags for the selected article, to pre-fill the tag prompt
+    async fn start_tag_suggestion(&mut self) -> Result<()> {
+        self.suggested_tags.clear();
+        if let Some(task) = self.tag_task.take() {
+            task.abort();
+        }
+        if !self.suggest_tags {
+            return Ok(());
+        }
+        let (Some(summarizer), Some(article)) =
+            (self.summarizer.clone(), self.selected_article().cloned())
+        else {
+            return Ok(());
+        };
+        let vocabulary = self.tag_vocabulary().await?;
+        if vocabulary.is_empty() {
+            return Ok(());
+        }
+
+        // The summary says what the article is about in few tokens
+        let content = self
+            .current_summary
+            .as_ref()
+            .filter(|summary| summary.article_id == article.id)
+            .map(|summary| summary.content.clone())
+            .or_else(|| article.content_text.clone())
+            .or_else(|| article.content.clone())
+            .unwrap_or_default();
+        let repository = self.repository.clone();
+        self.tag_task = Some(tokio::spawn(async move {
+            let suggested = summarizer
+                .suggest_tags(&article.title, &content, &vocabulary)
+                .await
+                .map_err(|e| e.user_message())?;
+            if let Some(usage) = suggested.usage {
+                let logged = repository
+                    .log_usage(
+                        summarizer.model_version().to_string(),
+                        usage.input_tokens,
+                        usage.output_tokens,
+                        suggested.cost_usd,
+                    )
+                    .await;
+                if let Err(e) = logged {
+                    tracing::warn!("Failed to log tag suggestion usage: {}", e);
+                }
+            }
+            Ok(TagSuggestion {
+                article_id: article.id,
+                tags: suggested.tags,
+            })
+        }));
+        Ok(())
+    }
+
+    /// Whether tags for the tag prompt are being suggested
+    pub fn is_suggesting_tags(&self) -> bool {
+        self.tag_task.is_some()
+    }
+
+    /// Pre-fill the tag prompt with the suggested tags, unless something was typed
+    /// meanwhile or the prompt was closed
+    pub async fn poll_tag_suggestion(&mut self) {
+        if !self
+            .tag_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            return;
+        }
+        let Some(task) = self.tag_task.take() else {
+            return;
+        };
+        match task.await {
+            Ok(Ok(suggestion)) => {
+                let still_open = self.tag_input_active
+                    && self.selected_article().map(|a| a.id) == Some(suggestion.article_id);
+                if still_open && self.tag_input.trim().is_empty() && !suggestion.tags.is_empty() {
+                    self.tag_input = format!("{}, ", suggestion.tags.join(", "));
+                    self.suggested_tags = suggestion.tags;
+                }
+            }
+            Ok(Err(e)) => tracing::warn!("Tag suggestion failed: {}", e),
+            Err(e) => tracing::debug!("Tag suggestion stopped: {}", e),
+        }
+    }
+
+    /// The selected article's language, by English name, when it can be told
+    pub fn article_language(&self) -> Option<&'static str> {
+        let article = self.selected_article()?;
+        let text = article
+            .content_text
+            .as_deref()
+            .or(article.content.as_deref())
+            .unwrap_or_default();
+        crate::language::detect(&article.title, text)
+    }
+
+    /// The detected language of the selected article, when it isn't the language
+    /// translations go into
+    pub fn translatable_language(&self) -> Option<&'static str> {
+        let target = self.summarizer.as_ref()?.target_language();
+        self.article_language()
+            .filter(|language| !crate::language::is_language(language, target))
+    }
+
+    /// The selected article's translation, when it's being shown
+    pub fn shown_translation(&self) -> Option<&Translation> {
+        if !self.show_translation {
+            return None;
+        }
+        let article_id = self.selected_article()?.id;
+        self.translations.get(&article_id)
+    }
+
+    /// Load the rundown of the show being viewed
+    async fn load_rundown(&mut self) -> Result<()> {
+        let rundown = self
+            .repository
+            .get_rundown(SHOWS[self.rundown_show])
+            .await?;
+        if self.rundown_selected_index >= rundown.items.len() {
+            self.rundown_selected_index = rundown.items.len().saturating_sub(1);
+        }
+        self.rundown = Some(rundown);
+        Ok(())
+    }
+
+    fn rundown_len(&self) -> usize {
+        self.rundown.as_ref().map_or(0, |r| r.items.len())
+    }
+
+    /// Move the selected rundown item one place down (or up) and store the new order
+    async fn move_rundown_item(&mut self, down: bool) -> Result<()> {
+        self.rundown_confirm_finish = false;
+        let Some(rundown) = self.rundown.as_mut() else {
+            return Ok(());
+        };
+        let from = self.rundown_selected_index;
+        let to = if down {
+            from + 1
+        } else {
+            match from.checked_sub(1) {
+                Some(to) => to,
+                None => return Ok(()),
+            }
+        };
+        if to >= rundown.items.len() {
+            return Ok(());
+        }
+        rundown.items.swap(from, to);
+        self.rundown_selected_index = to;
+        let order = rundown.items.iter().map(|item| item.article_id).collect();
+        self.repository
+            .set_rundown_order(&rundown.show, order)
+            .await
+    }
+
+    /// Store the typed note on the selected rundown item; an empty note removes it
+    async fn save_rundown_note(&mut self) -> Result<()> {
+        self.rundown_note_active = false;
+        let note = std::mem::take(&mut self.rundown_note_input);
+        let note = Some(note.trim().to_string()).filter(|note| !note.is_empty());
+        let Some(rundown) = self.rundown.as_mut() else {
+            return Ok(());
+        };
+        let Some(item) = rundown.items.get_mut(self.rundown_selected_index) else {
+            return Ok(());
+        };
+        item.note = note.clone();
+        self.repository
+            .set_rundown_note(&rundown.show, item.article_id, note)
+            .await
+    }
+
+    /// Export a show's rundown and wait for it (for `--rundown`)
+    pub async fn export_rundown(&self, show: &str) -> Result<PathBuf> {
+        rundown::export(&self.repository, show, &self.rundown_settings).await
+    }
+
+    pub fn is_translating(&self) -> bool {
+        self.translate_task.is_some()
+    }
+
+    /// Show the selected article translated, translating it first if needed. Shows the
+    /// original again when the translation is showing.
+    fn translate_selected(&mut self) {
+        let Some(article) = self.selected_article().cloned() else {
+            return;
+        };
+        if self.translations.contains_key(&article.id) {
+            self.show_translation = !self.show_translation;
+            return;
+        }
+        if self.is_translating() {
+            return;
+        }
+        let Some(summarizer) = self.summarizer.clone() else {
+            self.summary_status = SummaryStatus::NoApiKey;
+            return;
+        };
+
+        let text = article
+            .content_text
+            .clone()
+            .or_else(|| article.content.clone())
+            .unwrap_or_default();
+        let repository = self.repository.clone();
+        self.translate_task = Some(tokio::spawn(async move {
+            let translated = summarizer
+                .translate(&article.title, &text)
+                .await
+                .map_err(|e| e.user_message())?;
+            if let Some(usage) = translated.usage {
+                let logged = repository
+                    .log_usage(
+                        summarizer.model_version().to_string(),
+                        usage.input_tokens,
+                        usage.output_tokens,
+                        translated.cost_usd,
+                    )
+                    .await;
+                if let Err(e) = logged {
+                    tracing::warn!("Failed to log translation usage: {}", e);
+                }
+            }
+            Ok(Translation {
+                article_id: article.id,
+                title: translated.title,
+                text: translated.text,
+            })
+        }));
+    }
+
+    /// Show a finished translation if its article is still selected
+    pub async fn poll_translation(&mut self) {
+        if !self
+            .translate_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            return;
+        }
+        let Some(task) = self.translate_task.take() else {
+            return;
+        };
+        match task.await {
+            Ok(Ok(translation)) => {
+                if self.selected_article().map(|a| a.id) == Some(translation.article_id) {
+                    self.show_translation = true;
+                }
+                self.translations
+                    .insert(translation.article_id, translation);
+            }
+            Ok(Err(e)) => {
+                tracing::warn!("Translation failed: {}", e);
+                self.bookmark_status = Some((format!("Translation failed: {}", e), Instant::now()));
+            }
+            Err(e) => {
+                self.bookmark_status = Some((format!("Translation failed: {}", e), Instant::now()));
+            }
+        }
+    }
+
+    /// Write the daily briefing and wait for it (for `--briefing`)
+    pub async fn briefing_blocking(&self, settings: &BriefingConfig) -> Result<PathBuf> {
+        let Some(summarizer) = &self.summarizer else {
+            return Err(anyhow::anyhow!(
+                "No summarizer configured; add claude_api_key or a [summarizer] section to config.toml"
+            )
+            .into());
+        };
+        crate::briefing::run(&self.repository, summarizer, settings).await
+    }
+
+    /// Summarize new articles and wait for completion (for `--refresh`).
+    /// Returns the number of summaries written.
+    pub async fn presummarize_blocking(&mut self) -> Result<usize> {
+        self.presummarize_due = false;
+        let Some(summarizer) = &self.summarizer else {
+            return Ok(0);
+        };
+        if !self.presummarize.enabled {
+            return Ok(0);
+        }
+        crate::presummarize::run(
+            self.repository.clone(),
+            Arc::clone(summarizer),
+            Arc::clone(&self.content_fetcher),
+            self.presummarize.clone(),
+            self.refresh_started_at,
+            self.monthly_budget,
+            None,
+        )
+        .await
+    }
+
     /// Refresh feeds and wait for completion (blocking, for CLI/headless use)
     pub async fn refresh_feeds_blocking(&mut self) -> Result<()> {
         self.refresh_feeds();
@@ -712,105 +2144,441 @@ impl App {
         Ok(())
     }
 
-    async fn reload_articles(&mut self) -> Result<()> {
-        self.articles = self.repository.get_all_articles_sorted().await?;
+    /// Load filtered entries and per-rule hit counts for the filtered view
+    async fn load_filtered_view(&mut self) -> Result<()> {
+        self.blocked_articles = self.repository.get_blocked_articles().await?;
+
+        // Every current rule is listed (even with zero hits) so unused rules stand out
+        let counts = self.repository.get_blocked_rule_counts().await?;
+        let mut hits: Vec<(String, usize, usize)> = self
+            .blocklist
+            .rules()
+            .iter()
+            .map(|rule| {
+                let (hits, rescued) = counts
+                    .iter()
+                    .find(|(source, _, _)| *source == rule.source)
+                    .map(|(_, hits, rescued)| (*hits, *rescued))
+                    .unwrap_or((0, 0));
+                (rule.source.clone(), hits, rescued)
+            })
+            .collect();
+        hits.sort_by_key(|(_, hits, _)| std::cmp::Reverse(*hits));
+        self.filter_rule_hits = hits;
+
+        if self.blocked_selected_index >= self.blocked_articles.len() {
+            self.blocked_selected_index = self.blocked_articles.len().saturating_sub(1);
+        }
         Ok(())
     }
 
-    async fn save_to_raindrop(&mut self) -> Result<()> {
-        let Some(raindrop) = &self.raindrop else {
+    /// Move the selected filtered entry back into the article list
+    async fn rescue_selected_filtered(&mut self) -> Result<()> {
+        let Some(entry) = self.blocked_articles.get(self.blocked_selected_index) else {
             return Ok(());
         };
 
-        let Some(article) = self.selected_article() else {
+        let title = entry.title.clone();
+        self.repository.rescue_blocked_article(entry.id).await?;
+        tracing::info!("Rescued filtered article: {}", title);
+
+        self.reload_articles().await?;
+        self.load_filtered_view().await?;
+        self.bookmark_status = Some(("Rescued".to_string(), Instant::now()));
+        Ok(())
+    }
+
+    /// Re-check every stored article against the current blocklist: hide articles a
+    /// new rule matches and bring back ones no rule matches anymore.
+    /// Returns (hidden, unhidden) counts.
+    pub async fn reapply_blocklist(&mut self) -> Result<(usize, usize)> {
+        let stored = self.repository.get_articles_for_blocklist().await?;
+
+        let mut changes = Vec::new();
+        let (mut hidden, mut unhidden) = (0, 0);
+        for (article, current_rule) in stored {
+            let feed = self.feeds.iter().find(|f| f.id == article.feed_id);
+            let target = BlockTarget {
+                title: &article.title,
+                content: article
+                    .content_text
+                    .as_deref()
+                    .or(article.content.as_deref()),
+                author: article.author.as_deref(),
+                url: &article.url,
+                feed: feed.map(|f| f.title.as_str()),
+                folder: feed.and_then(|f| f.folder.as_deref()),
+            };
+            let rule = self
+                .blocklist
+                .find_match(&target)
+                .map(|rule| rule.source.clone());
+
+            if rule == current_rule {
+                continue;
+            }
+            match (&current_rule, &rule) {
+                (None, Some(_)) => hidden += 1,
+                (Some(_), None) => unhidden += 1,
+                _ => {} // Still hidden, now by a different rule
+            }
+            changes.push((article.id, rule));
+        }
+
+        if !changes.is_empty() {
+            self.repository.set_blocked_rules(changes).await?;
+            tracing::info!(
+                "Blocklist re-applied: {} articles hidden, {} unhidden",
+                hidden,
+                unhidden
+            );
+            self.reload_articles().await?;
+            if self.selected_index >= self.filtered_articles().len() {
+                self.selected_index = self.filtered_articles().len().saturating_sub(1);
+            }
+        }
+        Ok((hidden, unhidden))
+    }
+
+    /// Add a rule to blocklist.txt and hide the stored articles it matches.
+    /// Returns the number of articles hidden, or None (with `blocklist_status` set)
+    /// when the rule couldn't be added.
+    async fn add_blocklist_rule(&mut self, line: &str) -> Result<Option<usize>> {
+        if let Err(e) = self.blocklist.add_rule(line) {
+            tracing::warn!("Failed to add blocklist rule: {}", e);
+            self.blocklist_status = Some(format!("Error: {}", e));
+            return Ok(None);
+        }
+        tracing::info!("Added blocklist rule: {}", line);
+
+        let (hidden, _) = self.reapply_blocklist().await?;
+        Ok(Some(hidden))
+    }
+
+    /// Remove the selected rule from blocklist.txt and bring back the articles it hid
+    async fn remove_selected_rule(&mut self) -> Result<()> {
+        let Some(rule) = self.blocklist.rules().get(self.blocklist_selected_index) else {
+            return Ok(());
+        };
+
+        let source = rule.source.clone();
+        if let Err(e) = self.blocklist.remove_rule(&source) {
+            tracing::warn!("Failed to remove blocklist rule: {}", e);
+            self.blocklist_status = Some(format!("Error: {}", e));
             return Ok(());
+        }
+        tracing::info!("Removed blocklist rule: {}", source);
+
+        let (_, unhidden) = self.reapply_blocklist().await?;
+        let len = self.blocklist.rules().len();
+        if self.blocklist_selected_index >= len {
+            self.blocklist_selected_index = len.saturating_sub(1);
+        }
+        self.blocklist_status = Some(format!(
+            "Removed: {} ({} articles unhidden)",
+            source, unhidden
+        ));
+        Ok(())
+    }
+
+    /// Open the "block this" popup with rules proposed from the selected article
+    fn suggest_block_rules(&mut self) {
+        let Some(article) = self.selected_article() else {
+            return;
+        };
+
+        let suggestions = self.blocklist.suggest_rules(
+            &article.title,
+            article.author.as_deref(),
+            article.feed_title.as_deref(),
+        );
+        if suggestions.is_empty() {
+            self.bookmark_status = Some(("Nothing to block".to_string(), Instant::now()));
+            return;
+        }
+
+        self.block_suggestions = suggestions;
+        self.block_suggest_index = 0;
+        self.blocklist_status = None;
+        self.block_suggest_active = true;
+    }
+
+    async fn reload_articles(&mut self) -> Result<()> {
+        self.articles = self.repository.get_all_articles_sorted().await?;
+        self.cluster_articles().await?;
+
+        let len = self.filtered_articles().len();
+        if self.selected_index >= len {
+            self.selected_index = len.saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    /// Group duplicate and near-duplicate stories across feeds and store the clusters.
+    /// A cluster is identified by the id of its oldest article, so it keeps its id as
+    /// new copies of the story arrive.
+    async fn cluster_articles(&mut self) -> Result<()> {
+        let stories: Vec<StoryText> = self
+            .articles
+            .iter()
+            .map(|a| StoryText {
+                feed_id: a.feed_id,
+                url: &a.url,
+                title: &a.title,
+                content: a.content_text.as_deref().or(a.content.as_deref()),
+            })
+            .collect();
+        let groups = cluster::find_clusters(&stories);
+
+        let mut first_id: HashMap<usize, i64> = HashMap::new();
+        let mut sizes: HashMap<usize, usize> = HashMap::new();
+        for (article, group) in self.articles.iter().zip(&groups) {
+            let id = first_id.entry(*group).or_insert(article.id);
+            *id = (*id).min(article.id);
+            *sizes.entry(*group).or_default() += 1;
+        }
+
+        let mut changes = Vec::new();
+        for (article, group) in self.articles.iter_mut().zip(&groups) {
+            let cluster_id = (sizes[group] > 1).then(|| first_id[group]);
+            if article.cluster_id != cluster_id {
+                article.cluster_id = cluster_id;
+                changes.push((article.id, cluster_id));
+            }
+        }
+
+        self.rebuild_rows();
+
+        if !changes.is_empty() {
+            tracing::debug!("Updated duplicate clusters for {} articles", changes.len());
+            self.repository.set_article_clusters(changes).await?;
+        }
+        Ok(())
+    }
+
+    /// Expand the selected cluster to list its sources, or collapse it again
+    fn toggle_selected_cluster(&mut self) {
+        let rows = self.visible_rows();
+        let Some(cluster_id) = rows.get(self.selected_index).and_then(|row| match row {
+            ArticleRow::Cluster { lead, .. } | ArticleRow::Member(lead) => lead.cluster_id,
+            ArticleRow::Single(_) => None,
+        }) else {
+            return;
         };
 
+        // Collapsing from one of the sources moves the selection back to the cluster row
+        let header = rows.iter().position(|row| {
+            matches!(row, ArticleRow::Cluster { lead, .. } if lead.cluster_id == Some(cluster_id))
+        });
+        drop(rows);
+
+        if !self.expanded_clusters.remove(&cluster_id) {
+            self.expanded_clusters.insert(cluster_id);
+        } else if let Some(header) = header {
+            self.selected_index = header;
+        }
+        self.rebuild_rows();
+    }
+
+    async fn save_to_raindrop(&mut self) -> Result<()> {
         let tags: Vec<String> = self
             .tag_input
             .split(',')
             .map(|s| s.trim().to_string())
             .filter(|s| !s.is_empty())
             .collect();
+        self.bookmark_selected(tags).await
+    }
 
-        let article_id = article.id;
-        let url = article.url.clone();
-        let title = article.title.clone();
+    /// Save to Raindrop with a preset tag (no user input needed)
+    async fn save_to_raindrop_with_tag(&mut self, tag: &str) -> Result<()> {
+        self.bookmark_selected(vec![tag.to_string()]).await
+    }
 
-        // Get excerpt: first sentence of summary (cleaned), or first sentence of article content
-        let excerpt = self
-            .current_summary
-            .as_ref()
-            .map(|s| Self::clean_summary_for_excerpt(&s.content))
-            .filter(|s| !s.is_empty())
-            .or_else(|| {
-                article
-                    .content_text
-                    .as_ref()
-                    .or(article.content.as_ref())
-                    .map(|c| Self::get_first_sentence(c))
-            });
+    /// Bookmark the selected article (every source of a cluster) with its summary as the
+    /// note, unverified quotes handled as configured. A summary that has to be written
+    /// again first is regenerated in the background and sent by `poll_bookmark`.
+    async fn bookmark_selected(&mut self, tags: Vec<String>) -> Result<()> {
+        if self.raindrop.is_none() {
+            return Ok(());
+        }
+        if self.bookmark_task.is_some() {
+            self.bookmark_status = Some(("Still bookmarking...".to_string(), Instant::now()));
+            return Ok(());
+        }
+        let Some(article) = self.selected_article().cloned() else {
+            return Ok(());
+        };
+        let bookmark = PendingBookmark {
+            cluster_ids: self.selected_articles().iter().map(|a| a.id).collect(),
+            article,
+            tags,
+        };
 
-        // Get AI summary for note field (if available)
-        let note = self.current_summary.as_ref().map(|s| s.content.clone());
+        // AI summary for the note field (if available)
+        let Some(summary) = self.current_summary.clone() else {
+            return self.send_bookmark(bookmark, None).await;
+        };
+        let unverified = summary.unverified_quotes.clone().unwrap_or_default();
+        let note = match self.unverified_quotes {
+            _ if unverified.is_empty() => summary.content,
+            UnverifiedQuotes::Keep => summary.content,
+            UnverifiedQuotes::Regenerate if self.summarizer.is_some() => {
+                self.start_quote_regeneration(bookmark, summary);
+                return Ok(());
+            }
+            UnverifiedQuotes::Strip | UnverifiedQuotes::Regenerate => {
+                strip_unverified(&summary.content, &unverified)
+            }
+        };
+        self.send_bookmark(bookmark, Some(note)).await
+    }
 
-        match raindrop
-            .save_bookmark(
-                &url,
-                Some(&title),
-                excerpt.as_deref(),
-                note.as_deref(),
-                tags.clone(),
+    /// Write the summary of a bookmark's article again in the background, then send the
+    /// bookmark. Used when a summary quotes words the article doesn't contain.
+    fn start_quote_regeneration(&mut self, bookmark: PendingBookmark, old: Summary) {
+        let Some(summarizer) = self.summarizer.clone() else {
+            return;
+        };
+        let repository = self.repository.clone();
+        let content_fetcher = Arc::clone(&self.content_fetcher);
+
+        self.bookmark_status = Some((
+            "Rewriting summary before bookmarking...".to_string(),
+            Instant::now(),
+        ));
+        self.bookmark_task = Some(tokio::spawn(async move {
+            let result = Self::rewrite_summary(
+                &summarizer,
+                &repository,
+                &content_fetcher,
+                &bookmark.article,
+                &old,
             )
             .await
-        {
-            Ok(raindrop_id) => {
-                let tags_display = if tags.is_empty() {
-                    String::new()
-                } else {
-                    format!(" [{}]", tags.join(", "))
-                };
-                self.repository
-                    .mark_saved_to_raindrop(article_id, raindrop_id, tags)
-                    .await?;
-                self.is_saved_to_raindrop = true;
-                self.saved_count += 1;
-                self.bookmark_status =
-                    Some((format!("Bookmarked{}", tags_display), Instant::now()));
-                tracing::info!("Saved to Raindrop: {}", url);
-            }
-            Err(e) => {
-                self.bookmark_status = Some(("Bookmark failed".to_string(), Instant::now()));
-                tracing::error!("Failed to save to Raindrop: {}", e);
+            .map_err(|e| e.user_message());
+            RewrittenNote {
+                bookmark,
+                old,
+                result,
             }
-        }
-
-        // Don't reload - keep article visible in filtered list this session
-
-        Ok(())
+        }));
     }
 
-    /// Save to Raindrop with a preset tag (no user input needed)
-    async fn save_to_raindrop_with_tag(&mut self, tag: &str) -> Result<()> {
-        let Some(raindrop) = &self.raindrop else {
-            return Ok(());
+    /// Write an article's summary again with the same template and store it
+    async fn rewrite_summary(
+        summarizer: &Summarizer,
+        repository: &Repository,
+        content_fetcher: &ContentFetcher,
+        article: &Article,
+        old: &Summary,
+    ) -> Result<Summary> {
+        let template = old
+            .template
+            .clone()
+            .filter(|name| summarizer.template_names().contains(&name.as_str()))
+            .unwrap_or_else(|| summarizer.default_template().to_string());
+
+        let content = Self::fetch_source_text(content_fetcher, article).await;
+        let fields = PromptFields {
+            title: &article.title,
+            content: &content,
+            feed: article.feed_title.as_deref(),
+            author: article.author.as_deref(),
         };
+        let generated = summarizer.generate_summary(&fields, &template).await?;
+        repository
+            .save_summary(NewSummary {
+                article_id: article.id,
+                content: generated.text.clone(),
+                model_version: summarizer.model_version().to_string(),
+                template: template.clone(),
+                automatic: false,
+                input_tokens: generated.usage.map(|u| u.input_tokens),
+                output_tokens: generated.usage.map(|u| u.output_tokens),
+                cost_usd: generated.cost_usd,
+                unverified_quotes: generated.unverified_quotes.clone(),
+                fields: generated.fields.clone(),
+                content_hash: cluster::content_hash(&content),
+                canonical_url: Some(cluster::canonical_url(&article.url)),
+            })
+            .await?;
+
+        Ok(Summary {
+            id: old.id,
+            article_id: article.id,
+            content: generated.text,
+            model_version: summarizer.model_version().to_string(),
+            generated_at: chrono::Utc::now(),
+            template: Some(template),
+            unverified_quotes: Some(generated.unverified_quotes),
+            fields: generated.fields,
+            reused_from: None,
+        })
+    }
 
-        let Some(article) = self.selected_article() else {
+    /// Send a bookmark to Raindrop once its summary has been written again, and show
+    /// the new summary if its article is still selected
+    pub async fn poll_bookmark(&mut self) -> Result<()> {
+        if !self
+            .bookmark_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+        {
+            return Ok(());
+        }
+        let Some(task) = self.bookmark_task.take() else {
             return Ok(());
         };
+        let RewrittenNote {
+            bookmark,
+            old,
+            result,
+        } = match task.await {
+            Ok(done) => done,
+            Err(e) => {
+                tracing::debug!("Summary regeneration stopped: {}", e);
+                return Ok(());
+            }
+        };
 
-        let tags = vec![tag.to_string()];
+        let summary = match result {
+            Ok(summary) => {
+                if self.selected_article().map(|a| a.id) == Some(summary.article_id) {
+                    self.current_summary = Some(summary.clone());
+                    self.summary_status = SummaryStatus::Generated;
+                }
+                summary
+            }
+            Err(e) => {
+                tracing::warn!("Couldn't regenerate summary with unverified quotes: {}", e);
+                old
+            }
+        };
+        let unverified = summary.unverified_quotes.unwrap_or_default();
+        let note = strip_unverified(&summary.content, &unverified);
+        self.send_bookmark(bookmark, Some(note)).await
+    }
 
-        let article_id = article.id;
-        let url = article.url.clone();
-        let title = article.title.clone();
+    /// Save a bookmark to Raindrop with `note` as its note
+    async fn send_bookmark(
+        &mut self,
+        bookmark: PendingBookmark,
+        note: Option<String>,
+    ) -> Result<()> {
+        let Some(raindrop) = &self.raindrop else {
+            return Ok(());
+        };
+        let PendingBookmark {
+            article,
+            cluster_ids,
+            tags,
+        } = bookmark;
 
         // Get excerpt: first sentence of summary (cleaned), or first sentence of article content
-        let excerpt = self
-            .current_summary
-            .as_ref()
-            .map(|s| Self::clean_summary_for_excerpt(&s.content))
+        let excerpt = note
+            .as_deref()
+            .map(Self::clean_summary_for_excerpt)
             .filter(|s| !s.is_empty())
             .or_else(|| {
                 article
@@ -820,13 +2588,10 @@ impl App {
                     .map(|c| Self::get_first_sentence(c))
             });
 
-        // Get AI summary for note field (if available)
-        let note = self.current_summary.as_ref().map(|s| s.content.clone());
-
         match raindrop
             .save_bookmark(
-                &url,
-                Some(&title),
+                &article.url,
+                Some(&article.title),
                 excerpt.as_deref(),
                 note.as_deref(),
                 tags.clone(),
@@ -834,13 +2599,24 @@ impl App {
             .await
         {
             Ok(raindrop_id) => {
-                self.repository
-                    .mark_saved_to_raindrop(article_id, raindrop_id, tags)
-                    .await?;
-                self.is_saved_to_raindrop = true;
+                let tags_display = if tags.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{}]", tags.join(", "))
+                };
+                // Bookmarking a cluster covers all of its sources
+                for id in cluster_ids {
+                    self.repository
+                        .mark_saved_to_raindrop(id, raindrop_id, tags.clone())
+                        .await?;
+                }
+                if self.selected_article().map(|a| a.id) == Some(article.id) {
+                    self.is_saved_to_raindrop = true;
+                }
                 self.saved_count += 1;
-                self.bookmark_status = Some((format!("Bookmarked [{}]", tag), Instant::now()));
-                tracing::info!("Saved to Raindrop with tag '{}': {}", tag, url);
+                self.bookmark_status =
+                    Some((format!("Bookmarked{}", tags_display), Instant::now()));
+                tracing::info!("Saved to Raindrop: {}", article.url);
             }
             Err(e) => {
                 self.bookmark_status = Some(("Bookmark failed".to_string(), Instant::now()));
@@ -848,6 +2624,8 @@ impl App {
             }
         }
 
+        // Don't reload - keep article visible in filtered list this session
+
         Ok(())
     }
 
@@ -894,8 +2672,12 @@ impl App {
         let feeds = parse_opml_file(path)?;
 
         for feed in feeds {
-            // Silently ignore duplicates (UNIQUE constraint on url)
-            let _ = self.repository.insert_feed(feed).await;
+            let url = feed.url.clone();
+            let folder = feed.folder.clone();
+            // Duplicates fail the UNIQUE constraint on url; just pick up their folder
+            if self.repository.insert_feed(feed).await.is_err() && folder.is_some() {
+                let _ = self.repository.update_feed_folder(&url, folder).await;
+            }
         }
 
         self.feeds = self.repository.get_all_feeds().await?;
@@ -1019,3 +2801,33 @@ impl App {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::insert_feed_with_article;
+    use std::fs;
+
+    #[tokio::test]
+    async fn test_blocklist_edit_seen_by_screen_is_applied() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = Config {
+            db_path: dir.path().join("feeds.db").to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        let mut app = App::new(&config).await.unwrap();
+        insert_feed_with_article(&app.repository, "Crypto winter continues").await;
+
+        let path = dir.path().join("blocklist.txt");
+        app.blocklist = Blocklist::load_from(&path);
+        app.reload_articles().await.unwrap();
+        assert_eq!(app.articles.len(), 1);
+
+        // Edited outside the app, then noticed by opening the blocklist screen
+        fs::write(&path, "crypto\n").unwrap();
+        app.handle_action(AppAction::ShowBlocklist).await.unwrap();
+        assert!(app.articles.is_empty());
+        let blocked = app.repository.get_blocked_articles().await.unwrap();
+        assert_eq!(blocked[0].rule, "crypto");
+    }
+}
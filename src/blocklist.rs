@@ -1,33 +1,429 @@
-use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
-use regex::Regex;
+use aho_corasick::AhoCorasick;
+use caseless::Caseless;
+use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::error::{AppError, Result};
+
+/// Maximum length of a regex rule pattern (plain keywords are capped at 50)
+const MAX_REGEX_LEN: usize = 200;
+
+/// How many title keywords "block this" proposes
+const MAX_TITLE_SUGGESTIONS: usize = 5;
+
+/// Common English words never worth proposing as a blocklist keyword
+const STOPWORDS: &[&str] = &[
+    "about", "after", "again", "all", "also", "and", "any", "are", "back", "been", "before",
+    "being", "but", "can", "could", "did", "does", "for", "from", "get", "gets", "had", "has",
+    "have", "her", "here", "his", "how", "into", "its", "just", "like", "more", "most", "new",
+    "not", "now", "off", "one", "only", "our", "out", "over", "says", "she", "than", "that", "the",
+    "their", "them", "then", "there", "these", "they", "this", "those", "too", "two", "under",
+    "until", "was", "way", "were", "what", "when", "where", "which", "while", "who", "why", "will",
+    "with", "would", "you", "your",
+];
+
+/// Article fields a rule can be scoped to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleFields {
+    pub title: bool,
+    pub content: bool,
+    pub author: bool,
+    pub url: bool,
+}
+
+impl RuleFields {
+    /// Plain keyword lines keep the original behavior: title or content
+    const DEFAULT: Self = Self {
+        title: true,
+        content: true,
+        author: false,
+        url: false,
+    };
+
+    const NONE: Self = Self {
+        title: false,
+        content: false,
+        author: false,
+        url: false,
+    };
+}
+
+#[derive(Debug, Clone)]
+enum RulePattern {
+    /// Normalized whole-word keyword (case-insensitive)
+    Keyword(String),
+    /// User-supplied regular expression (case-insensitive)
+    Regex(Regex),
+}
+
+/// A single blocklist line parsed into a matcher plus where it applies.
+///
+/// Line syntax: `[fields:] pattern [| feed: Name] [| folder: Name] [| except feed: Name]`
+/// where `fields` is a comma-separated subset of `title,content,author,url` and
+/// `pattern` is either a plain keyword or a `/regex/`.
+#[derive(Debug, Clone)]
+pub struct BlockRule {
+    /// Original line from blocklist.txt, used when reporting matches
+    pub source: String,
+    pattern: RulePattern,
+    pub fields: RuleFields,
+    feeds: Vec<String>,
+    folders: Vec<String>,
+    except_feeds: Vec<String>,
+    except_folders: Vec<String>,
+}
+
+/// The parts of an entry a blocklist rule can look at
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlockTarget<'a> {
+    pub title: &'a str,
+    pub content: Option<&'a str>,
+    pub author: Option<&'a str>,
+    pub url: &'a str,
+    pub feed: Option<&'a str>,
+    pub folder: Option<&'a str>,
+}
+
+impl BlockRule {
+    /// Two lines are the same rule if they differ only in case and spacing
+    fn same_as(&self, other: &BlockRule) -> bool {
+        let key =
+            |source: &str| fold_case(&source.split_whitespace().collect::<Vec<_>>().join(" "));
+        key(&self.source) == key(&other.source)
+    }
+
+    /// Parse one blocklist line. Returns None for blank lines, comments and invalid rules.
+    fn parse(line: &str) -> Option<Self> {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            return None;
+        }
+
+        let (fields, rest) = Self::split_fields(trimmed);
+
+        // Regex patterns run to the closing unescaped '/', keywords to the first '|'
+        let (pattern, clauses) = if let Some(body) = rest.strip_prefix('/') {
+            let Some(close) = Self::find_regex_end(body) else {
+                tracing::warn!("Regex rule is missing closing '/', rejecting: {}", trimmed);
+                return None;
+            };
+            let source = &body[..close];
+            if source.is_empty() || source.len() > MAX_REGEX_LEN {
+                tracing::warn!("Regex rule is empty or too long, rejecting: {}", trimmed);
+                return None;
+            }
+            let regex = match RegexBuilder::new(source).case_insensitive(true).build() {
+                Ok(re) => re,
+                Err(e) => {
+                    tracing::warn!("Invalid regex rule {}: {}", trimmed, e);
+                    return None;
+                }
+            };
+            let after = body[close + 1..].trim_start();
+            if !after.is_empty() && !after.starts_with('|') {
+                tracing::warn!("Unexpected text after regex, rejecting: {}", trimmed);
+                return None;
+            }
+            (RulePattern::Regex(regex), after)
+        } else {
+            let (keyword, after) = rest.split_once('|').unwrap_or((rest, ""));
+            let keyword = Blocklist::normalize_keyword(keyword)?;
+            (RulePattern::Keyword(keyword), after)
+        };
+
+        let mut rule = Self {
+            source: trimmed.to_string(),
+            pattern,
+            fields,
+            feeds: Vec::new(),
+            folders: Vec::new(),
+            except_feeds: Vec::new(),
+            except_folders: Vec::new(),
+        };
+
+        for clause in clauses.split('|').map(str::trim).filter(|c| !c.is_empty()) {
+            let (negated, clause) = match clause.strip_prefix("except ") {
+                Some(rest) => (true, rest.trim_start()),
+                None => (false, clause),
+            };
+            let Some((key, value)) = clause.split_once(':') else {
+                tracing::warn!("Unknown blocklist clause '{}' in: {}", clause, trimmed);
+                return None;
+            };
+            let value = fold_case(value.trim());
+            if value.is_empty() {
+                tracing::warn!("Empty blocklist clause '{}' in: {}", clause, trimmed);
+                return None;
+            }
+            let target = match (key.trim().to_lowercase().as_str(), negated) {
+                ("feed", false) => &mut rule.feeds,
+                ("folder", false) => &mut rule.folders,
+                ("feed", true) => &mut rule.except_feeds,
+                ("folder", true) => &mut rule.except_folders,
+                _ => {
+                    tracing::warn!("Unknown blocklist clause '{}' in: {}", clause, trimmed);
+                    return None;
+                }
+            };
+            target.push(value);
+        }
+
+        Some(rule)
+    }
+
+    /// Split an optional `title,author:` style prefix from the rest of the line
+    fn split_fields(line: &str) -> (RuleFields, &str) {
+        let Some((prefix, rest)) = line.split_once(':') else {
+            return (RuleFields::DEFAULT, line);
+        };
+
+        let mut fields = RuleFields::NONE;
+        for name in prefix.split(',') {
+            match name.trim().to_lowercase().as_str() {
+                "title" => fields.title = true,
+                "content" => fields.content = true,
+                "author" => fields.author = true,
+                "url" => fields.url = true,
+                // Not a field list (e.g. a regex containing ':'), treat as unscoped
+                _ => return (RuleFields::DEFAULT, line),
+            }
+        }
+
+        (fields, rest.trim_start())
+    }
+
+    /// Find the index of the closing '/' of a regex body, skipping escaped slashes
+    fn find_regex_end(body: &str) -> Option<usize> {
+        let mut escaped = false;
+        for (i, c) in body.char_indices() {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '/' if !escaped => return Some(i),
+                _ => escaped = false,
+            }
+        }
+        None
+    }
+
+    /// Whether the rule is in effect for an entry from the given feed/folder
+    fn applies_to(&self, feed: Option<&str>, folder: Option<&str>) -> bool {
+        let feed = feed.map(|f| fold_case(f.trim()));
+        let folder = folder.map(|f| fold_case(f.trim()));
+        let listed = |names: &[String], value: &Option<String>| {
+            value.as_ref().is_some_and(|v| names.contains(v))
+        };
+
+        if listed(&self.except_feeds, &feed) || listed(&self.except_folders, &folder) {
+            return false;
+        }
+
+        // Rules without feed/folder restrictions are global
+        if self.feeds.is_empty() && self.folders.is_empty() {
+            return true;
+        }
+
+        listed(&self.feeds, &feed) || listed(&self.folders, &folder)
+    }
+
+    #[cfg(test)]
+    fn matches_text(&self, text: &str) -> bool {
+        match &self.pattern {
+            RulePattern::Keyword(keyword) => {
+                let folded = fold_case(text);
+                folded
+                    .match_indices(keyword.as_str())
+                    .any(|(start, _)| is_whole_word(&folded, start, start + keyword.len()))
+            }
+            RulePattern::Regex(re) => re.is_match(text),
+        }
+    }
+
+    /// Check the rule against an entry, honoring field scope and feed/folder applicability.
+    /// The reference the compiled matcher is tested against.
+    #[cfg(test)]
+    pub fn matches(&self, target: &BlockTarget) -> bool {
+        if !self.applies_to(target.feed, target.folder) {
+            return false;
+        }
+
+        (self.fields.title && self.matches_text(target.title))
+            || (self.fields.content && target.content.is_some_and(|c| self.matches_text(c)))
+            || (self.fields.author && target.author.is_some_and(|a| self.matches_text(a)))
+            || (self.fields.url && self.matches_text(target.url))
+    }
+}
+
+/// Case-fold text for caseless comparison: full Unicode case folding (so "STRASSE"
+/// and "Straße" compare equal) with NFC normalization so composed and decomposed
+/// accents match.
+fn fold_case(text: &str) -> String {
+    text.chars().nfd().default_case_fold().nfc().collect()
+}
+
+/// Whether `c` counts as part of a word, mirroring the regex `\b` definition
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Scripts written without spaces between words, where a keyword can't be expected
+/// to sit on a word boundary (e.g. "ビットコイン" inside "ビットコインが急落")
+fn is_unspaced_script(c: char) -> bool {
+    matches!(c as u32,
+        0x0E00..=0x0EFF     // Thai, Lao
+        | 0x1000..=0x109F   // Myanmar
+        | 0x1100..=0x11FF   // Hangul Jamo
+        | 0x1780..=0x17FF   // Khmer
+        | 0x3040..=0x30FF   // Hiragana, Katakana
+        | 0x3130..=0x318F   // Hangul Compatibility Jamo
+        | 0x31F0..=0x31FF   // Katakana Phonetic Extensions
+        | 0x3400..=0x4DBF   // CJK Extension A
+        | 0x4E00..=0x9FFF   // CJK Unified Ideographs
+        | 0xAC00..=0xD7AF   // Hangul Syllables
+        | 0xF900..=0xFAFF   // CJK Compatibility Ideographs
+        | 0xFF66..=0xFF9F   // Halfwidth Katakana
+        | 0x20000..=0x2FA1F // CJK Extensions B-F, Compatibility Supplement
+    )
+}
+
+/// Whether `text[start..end]` stands on its own as a keyword match.
+///
+/// Each end of the match needs a word boundary, like `\bkeyword\b`, except where
+/// boundaries don't make sense: the keyword ends in a non-word character (emoji,
+/// hyphen), or either side of the edge is in a script written without spaces.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let edge_ok = |inner: Option<char>, outer: Option<char>| match (inner, outer) {
+        (Some(inner), Some(outer)) => {
+            !is_word_char(inner)
+                || !is_word_char(outer)
+                || is_unspaced_script(inner)
+                || is_unspaced_script(outer)
+        }
+        _ => true,
+    };
+    let matched = &text[start..end];
+
+    edge_ok(matched.chars().next(), text[..start].chars().next_back())
+        && edge_ok(matched.chars().next_back(), text[end..].chars().next())
+}
+
+/// All rules compiled into two automatons, built once per load instead of per entry.
+///
+/// Keywords go into a single Aho-Corasick automaton (with word boundaries checked on
+/// each hit) and regex rules into a `RegexSet`, so each text field is scanned once
+/// no matter how many rules there are.
+struct CompiledRules {
+    keywords: Option<AhoCorasick>,
+    /// Rule indices for each keyword pattern (several rules can share a keyword)
+    keyword_rules: Vec<Vec<usize>>,
+    regexes: RegexSet,
+    /// Rule index for each regex pattern
+    regex_rules: Vec<usize>,
+}
+
+impl CompiledRules {
+    fn build(rules: &[BlockRule]) -> Self {
+        let mut keywords: Vec<&str> = Vec::new();
+        let mut keyword_rules: Vec<Vec<usize>> = Vec::new();
+        let mut regex_sources = Vec::new();
+        let mut regex_rules = Vec::new();
+
+        for (index, rule) in rules.iter().enumerate() {
+            match &rule.pattern {
+                RulePattern::Keyword(keyword) => match keywords.iter().position(|k| k == keyword) {
+                    Some(pos) => keyword_rules[pos].push(index),
+                    None => {
+                        keywords.push(keyword);
+                        keyword_rules.push(vec![index]);
+                    }
+                },
+                RulePattern::Regex(re) => {
+                    regex_sources.push(re.as_str());
+                    regex_rules.push(index);
+                }
+            }
+        }
+
+        let keyword_matcher = if keywords.is_empty() {
+            None
+        } else {
+            match AhoCorasick::new(&keywords) {
+                Ok(ac) => Some(ac),
+                Err(e) => {
+                    tracing::warn!("Failed to build blocklist keyword matcher: {}", e);
+                    None
+                }
+            }
+        };
+
+        // Every source already compiled on its own, so the set only fails on size limits
+        let regexes = RegexSetBuilder::new(&regex_sources)
+            .case_insensitive(true)
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to build blocklist regex set: {}", e);
+                RegexSet::empty()
+            });
+
+        Self {
+            keywords: keyword_matcher,
+            keyword_rules,
+            regexes,
+            regex_rules,
+        }
+    }
+
+    /// Mark every rule whose pattern occurs in `text`
+    fn mark_hits(&self, text: &str, hits: &mut [bool]) {
+        if let Some(ac) = &self.keywords {
+            let folded = fold_case(text);
+            for m in ac.find_overlapping_iter(&folded) {
+                if is_whole_word(&folded, m.start(), m.end()) {
+                    for &rule in &self.keyword_rules[m.pattern().as_usize()] {
+                        hits[rule] = true;
+                    }
+                }
+            }
+        }
+
+        if !self.regex_rules.is_empty() {
+            for pattern in self.regexes.matches(text).iter() {
+                hits[self.regex_rules[pattern]] = true;
+            }
+        }
+    }
+}
 
 pub struct Blocklist {
-    keywords: HashSet<String>,
+    rules: Vec<BlockRule>,
+    compiled: CompiledRules,
     last_modified: Option<SystemTime>,
+    /// File the rules came from; None for blocklists built from text
+    path: Option<PathBuf>,
 }
 
 impl Blocklist {
     pub fn load() -> Self {
-        let path = Self::blocklist_path();
-        let mut keywords = HashSet::new();
+        Self::load_from(&Self::blocklist_path())
+    }
+
+    /// Load rules from a specific blocklist file
+    pub fn load_from(path: &Path) -> Self {
+        let mut rules = Vec::new();
         let mut last_modified = None;
 
-        match fs::read_to_string(&path) {
+        match fs::read_to_string(path) {
             Ok(content) => {
                 // Capture file modification time
-                if let Ok(metadata) = fs::metadata(&path) {
+                if let Ok(metadata) = fs::metadata(path) {
                     last_modified = metadata.modified().ok();
                 }
 
-                for line in content.lines() {
-                    if let Some(normalized) = Self::normalize_keyword(line) {
-                        keywords.insert(normalized);
-                    }
-                }
+                rules = Self::parse_rules(&content);
             }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
                 // Missing file is fine, return empty blocklist
@@ -40,74 +436,219 @@ impl Blocklist {
             }
         }
 
+        let mut blocklist = Self::with_rules(rules, last_modified);
+        blocklist.path = Some(path.to_path_buf());
+        blocklist
+    }
+
+    /// Build a blocklist from blocklist.txt-formatted text (no file involved)
+    #[cfg(test)]
+    pub fn from_text(content: &str) -> Self {
+        Self::with_rules(Self::parse_rules(content), None)
+    }
+
+    fn with_rules(rules: Vec<BlockRule>, last_modified: Option<SystemTime>) -> Self {
+        let compiled = CompiledRules::build(&rules);
         Self {
-            keywords,
+            rules,
+            compiled,
             last_modified,
+            path: None,
         }
     }
 
-    pub fn reload(&mut self) {
+    fn parse_rules(content: &str) -> Vec<BlockRule> {
+        content.lines().filter_map(BlockRule::parse).collect()
+    }
+
+    /// Reload the blocklist if the file changed since it was last read.
+    /// Returns true when the rules were reloaded.
+    pub fn reload(&mut self) -> bool {
+        let Some(path) = self.path.clone() else {
+            return false;
+        };
+
         // Get file metadata to check modification time
-        let path = Self::blocklist_path();
         let current_mtime = fs::metadata(&path).ok().and_then(|m| m.modified().ok());
 
         // Only reload if file changed (or first load)
         if current_mtime != self.last_modified {
-            *self = Self::load();
+            *self = Self::load_from(&path);
+            return true;
         }
+        false
     }
 
-    #[allow(dead_code)]
-    pub fn keywords(&self) -> &HashSet<String> {
-        &self.keywords
-    }
+    /// Append a rule to the blocklist file. Comments and other lines are kept as they are.
+    pub fn add_rule(&mut self, line: &str) -> Result<()> {
+        let line = line.trim();
+        let Some(rule) = BlockRule::parse(line) else {
+            return Err(anyhow::anyhow!("Not a valid blocklist rule: {}", line).into());
+        };
 
-    #[allow(dead_code)]
-    pub fn is_empty(&self) -> bool {
-        self.keywords.is_empty()
+        let mut content = self.read_file()?;
+        // Check the file as it is now, in case it was edited since we loaded it
+        if Self::parse_rules(&content)
+            .iter()
+            .any(|existing| existing.same_as(&rule))
+        {
+            return Err(anyhow::anyhow!("Already in the blocklist: {}", line).into());
+        }
+
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(line);
+        content.push('\n');
+        self.write_file(&content)
     }
 
-    /// Check if any blocked keyword appears as a whole word in the title or content.
-    /// Returns true if a keyword is found (article should be filtered).
-    pub fn contains_blocked_keyword(&self, title: &str, content: Option<&str>) -> bool {
-        // Empty blocklist means no filtering
-        if self.keywords.is_empty() {
-            return false;
+    /// Remove a rule from the blocklist file by its source line
+    pub fn remove_rule(&mut self, source: &str) -> Result<()> {
+        let content = self.read_file()?;
+        let mut removed = false;
+        let kept: Vec<&str> = content
+            .lines()
+            .filter(|line| {
+                if !removed && line.trim() == source {
+                    removed = true;
+                    return false;
+                }
+                true
+            })
+            .collect();
+        if !removed {
+            return Err(anyhow::anyhow!("Not in the blocklist: {}", source).into());
         }
 
-        // Check title first (short-circuit on match)
-        if self.check_text_for_keywords(title) {
-            return true;
+        let mut content = kept.join("\n");
+        if !content.is_empty() {
+            content.push('\n');
         }
+        self.write_file(&content)
+    }
 
-        // Check content if available
-        if let Some(content_text) = content {
-            if self.check_text_for_keywords(content_text) {
-                return true;
+    /// Propose rules for blocking articles like this one: keywords from the title,
+    /// the author, and the top keyword limited to the article's feed.
+    /// Rules already in the blocklist are left out.
+    pub fn suggest_rules(
+        &self,
+        title: &str,
+        author: Option<&str>,
+        feed: Option<&str>,
+    ) -> Vec<String> {
+        let mut keywords: Vec<String> = Vec::new();
+        for word in title.split(|c: char| !(is_word_char(c) || c == '-' || c == '\'')) {
+            let word = word.trim_matches(|c| c == '-' || c == '\'');
+            let word = word
+                .strip_suffix("'s")
+                .or_else(|| word.strip_suffix("’s"))
+                .unwrap_or(word);
+            let folded = fold_case(word);
+            if word.chars().count() < 3
+                || word.chars().all(|c| c.is_numeric())
+                || STOPWORDS.contains(&folded.as_str())
+                || keywords.iter().any(|k| fold_case(k) == folded)
+            {
+                continue;
             }
+            keywords.push(word.to_string());
         }
+        keywords.retain(|k| !self.has_rule(k));
+        // Longer words tend to be the more specific ones
+        keywords.sort_by_key(|k| std::cmp::Reverse(k.chars().count()));
+        keywords.truncate(MAX_TITLE_SUGGESTIONS);
 
-        false
+        let mut suggestions = keywords.clone();
+        if let Some(author) = author.map(str::trim).filter(|a| !a.is_empty()) {
+            suggestions.push(format!("author: {}", author));
+        }
+        if let (Some(keyword), Some(feed)) = (keywords.first(), feed) {
+            suggestions.push(format!("{} | feed: {}", keyword, feed.trim()));
+        }
+
+        suggestions.retain(|s| !self.has_rule(s));
+        suggestions
+    }
+
+    /// Whether `line` is an invalid rule or one the blocklist already has
+    fn has_rule(&self, line: &str) -> bool {
+        match BlockRule::parse(line) {
+            Some(rule) => self.rules.iter().any(|r| r.same_as(&rule)),
+            None => true,
+        }
+    }
+
+    fn file_path(&self) -> Result<&Path> {
+        self.path
+            .as_deref()
+            .ok_or_else(|| AppError::Other(anyhow::anyhow!("Blocklist has no file")))
+    }
+
+    fn read_file(&self) -> Result<String> {
+        match fs::read_to_string(self.file_path()?) {
+            Ok(content) => Ok(content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+            Err(e) => Err(e.into()),
+        }
     }
 
-    /// Check if any keyword appears as a whole word in the given text.
-    fn check_text_for_keywords(&self, text: &str) -> bool {
-        // Convert text to lowercase for case-insensitive matching
-        let text_lower = text.to_lowercase();
+    /// Replace the blocklist file atomically (temp file + rename), then reload from it
+    fn write_file(&mut self, content: &str) -> Result<()> {
+        let path = self.file_path()?.to_path_buf();
+        // Write through a symlinked blocklist (e.g. from a dotfiles repo) instead of replacing it
+        let target = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let tmp = target.with_extension("txt.tmp");
+        {
+            let mut file = fs::File::create(&tmp)?;
+            file.write_all(content.as_bytes())?;
+            file.sync_all()?;
+        }
+        if let Err(e) = fs::rename(&tmp, &target) {
+            let _ = fs::remove_file(&tmp);
+            return Err(e.into());
+        }
 
-        for keyword in &self.keywords {
-            // Build regex with word boundaries: \bkeyword\b
-            // Escape the keyword to handle special regex characters
-            let pattern = format!(r"\b{}\b", regex::escape(keyword));
+        *self = Self::load_from(&path);
+        Ok(())
+    }
+
+    pub fn rules(&self) -> &[BlockRule] {
+        &self.rules
+    }
+
+    /// Return the first rule that blocks this entry, if any
+    pub fn find_match(&self, target: &BlockTarget) -> Option<&BlockRule> {
+        if self.rules.is_empty() {
+            return None;
+        }
 
-            if let Ok(re) = Regex::new(&pattern) {
-                if re.is_match(&text_lower) {
-                    return true;
+        // Only scan the fields some rule actually looks at
+        let scan = |enabled: fn(&RuleFields) -> bool, text: Option<&str>| {
+            let mut hits = vec![false; self.rules.len()];
+            if let Some(text) = text {
+                if self.rules.iter().any(|r| enabled(&r.fields)) {
+                    self.compiled.mark_hits(text, &mut hits);
                 }
             }
-        }
+            hits
+        };
+        let title_hits = scan(|f| f.title, Some(target.title));
+        let content_hits = scan(|f| f.content, target.content);
+        let author_hits = scan(|f| f.author, target.author);
+        let url_hits = scan(|f| f.url, Some(target.url));
 
-        false
+        self.rules.iter().enumerate().find_map(|(i, rule)| {
+            let hit = (rule.fields.title && title_hits[i])
+                || (rule.fields.content && content_hits[i])
+                || (rule.fields.author && author_hits[i])
+                || (rule.fields.url && url_hits[i]);
+            (hit && rule.applies_to(target.feed, target.folder)).then_some(rule)
+        })
     }
 
     fn blocklist_path() -> PathBuf {
@@ -117,6 +658,16 @@ impl Blocklist {
             .join("blocklist.txt")
     }
 
+    /// Characters allowed in a plain keyword: letters and digits in any script,
+    /// spaces, hyphens, apostrophes, and non-ASCII symbols such as emoji
+    fn is_keyword_char(c: char) -> bool {
+        if c.is_ascii() {
+            c.is_ascii_alphanumeric() || c == ' ' || c == '-' || c == '\''
+        } else {
+            !c.is_control()
+        }
+    }
+
     fn normalize_keyword(line: &str) -> Option<String> {
         // Trim whitespace
         let trimmed = line.trim();
@@ -126,29 +677,26 @@ impl Blocklist {
             return None;
         }
 
-        // Check length before processing
-        if trimmed.len() > 50 {
+        // Check length before processing (in characters, so CJK keywords aren't penalized)
+        if trimmed.chars().count() > 50 {
             tracing::warn!("Keyword exceeds 50 characters, rejecting: {}", trimmed);
             return None;
         }
 
-        // Validate characters (ASCII letters, numbers, spaces, hyphens only)
-        if !trimmed
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
-        {
+        // Validate characters (letters, numbers, spaces, hyphens, apostrophes, emoji)
+        if !trimmed.chars().all(Self::is_keyword_char) {
             tracing::warn!(
-                "Keyword contains invalid characters (only letters, numbers, spaces, hyphens allowed), rejecting: {}",
+                "Keyword contains invalid characters (only letters, numbers, spaces, hyphens, apostrophes, emoji allowed), rejecting: {}",
                 trimmed
             );
             return None;
         }
 
-        // Lowercase conversion
-        let lowercased = trimmed.to_lowercase();
+        // Case folding (Unicode-aware, e.g. "ß" and "ss" are equivalent)
+        let folded = fold_case(trimmed);
 
-        // Collapse multiple spaces to single space
-        let normalized = lowercased.split_whitespace().collect::<Vec<_>>().join(" ");
+        // Collapse multiple spaces (including ideographic spaces) to single space
+        let normalized = folded.split_whitespace().collect::<Vec<_>>().join(" ");
 
         // Re-validate after normalization
         if normalized.is_empty() {
@@ -156,10 +704,7 @@ impl Blocklist {
         }
 
         // Final character validation
-        if !normalized
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
-        {
+        if !normalized.chars().all(Self::is_keyword_char) {
             return None;
         }
 
@@ -171,6 +716,25 @@ impl Blocklist {
 mod tests {
     use super::*;
 
+    fn file_blocklist(content: &str) -> (tempfile::TempDir, PathBuf, Blocklist) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("blocklist.txt");
+        fs::write(&path, content).unwrap();
+        let blocklist = Blocklist::load_from(&path);
+        (dir, path, blocklist)
+    }
+
+    /// Whether a rule blocks an entry with only a title and content (no feed context)
+    fn blocks(blocklist: &Blocklist, title: &str, content: Option<&str>) -> bool {
+        blocklist
+            .find_match(&BlockTarget {
+                title,
+                content,
+                ..Default::default()
+            })
+            .is_some()
+    }
+
     #[test]
     fn test_normalize_keyword_valid() {
         assert_eq!(
@@ -189,13 +753,16 @@ mod tests {
             Blocklist::normalize_keyword("hyphen-word"),
             Some("hyphen-word".to_string())
         );
+        assert_eq!(
+            Blocklist::normalize_keyword("emoji😀"),
+            Some("emoji😀".to_string())
+        );
     }
 
     #[test]
     fn test_normalize_keyword_invalid() {
         assert_eq!(Blocklist::normalize_keyword(""), None);
         assert_eq!(Blocklist::normalize_keyword("   "), None);
-        assert_eq!(Blocklist::normalize_keyword("emoji😀"), None);
         assert_eq!(Blocklist::normalize_keyword("special@chars!"), None);
         // 51 character string - too long
         let long = "a".repeat(51);
@@ -216,7 +783,7 @@ mod tests {
         let blocklist = Blocklist::load();
         // This will pass if ~/.config/beatcheck/blocklist.txt doesn't exist
         // The test documents expected behavior
-        assert!(blocklist.keywords().is_empty() || !blocklist.keywords().is_empty());
+        assert!(blocklist.rules().is_empty() || !blocklist.rules().is_empty());
     }
 
     #[test]
@@ -231,84 +798,497 @@ mod tests {
     }
 
     #[test]
-    fn test_contains_blocked_keyword_in_title() {
+    fn test_keyword_in_title() {
         // Create blocklist with "bitcoin" keyword
-        let mut blocklist = Blocklist {
-            keywords: HashSet::new(),
-            last_modified: None,
-        };
-        blocklist.keywords.insert("bitcoin".to_string());
+        let blocklist = Blocklist::from_text("bitcoin");
 
         // Should match case-insensitively
-        assert!(blocklist.contains_blocked_keyword("Bitcoin news today", None));
-        assert!(blocklist.contains_blocked_keyword("BITCOIN price drops", None));
-        assert!(blocklist.contains_blocked_keyword("Why bitcoin matters", None));
+        assert!(blocks(&blocklist, "Bitcoin news today", None));
+        assert!(blocks(&blocklist, "BITCOIN price drops", None));
+        assert!(blocks(&blocklist, "Why bitcoin matters", None));
 
         // Should not match if keyword is not present
-        assert!(!blocklist.contains_blocked_keyword("Tech news today", None));
+        assert!(!blocks(&blocklist, "Tech news today", None));
     }
 
     #[test]
-    fn test_contains_blocked_keyword_in_content() {
-        let mut blocklist = Blocklist {
-            keywords: HashSet::new(),
-            last_modified: None,
-        };
-        blocklist.keywords.insert("crypto".to_string());
+    fn test_keyword_in_content() {
+        let blocklist = Blocklist::from_text("crypto");
 
         // Should match in content even if not in title
-        assert!(blocklist
-            .contains_blocked_keyword("Tech news", Some("This article discusses crypto markets")));
+        assert!(blocks(
+            &blocklist,
+            "Tech news",
+            Some("This article discusses crypto markets")
+        ));
 
         // Should match in title (short-circuit, doesn't check content)
-        assert!(blocklist.contains_blocked_keyword("Crypto news", Some("Some other content")));
+        assert!(blocks(
+            &blocklist,
+            "Crypto news",
+            Some("Some other content")
+        ));
     }
 
     #[test]
     fn test_word_boundary_respects_partial() {
-        let mut blocklist = Blocklist {
-            keywords: HashSet::new(),
-            last_modified: None,
-        };
-        blocklist.keywords.insert("crypto".to_string());
+        let blocklist = Blocklist::from_text("crypto");
 
         // Should NOT match when keyword is part of a larger word
-        assert!(!blocklist.contains_blocked_keyword("I love cryptocurrency", None));
-        assert!(!blocklist.contains_blocked_keyword("cryptocurrencies are rising", None));
+        assert!(!blocks(&blocklist, "I love cryptocurrency", None));
+        assert!(!blocks(&blocklist, "cryptocurrencies are rising", None));
 
         // Should NOT match in content either
-        assert!(!blocklist
-            .contains_blocked_keyword("Tech news", Some("The cryptocurrency market is volatile")));
+        assert!(!blocks(
+            &blocklist,
+            "Tech news",
+            Some("The cryptocurrency market is volatile")
+        ));
     }
 
     #[test]
     fn test_word_boundary_matches_whole() {
-        let mut blocklist = Blocklist {
-            keywords: HashSet::new(),
-            last_modified: None,
-        };
-        blocklist.keywords.insert("crypto".to_string());
+        let blocklist = Blocklist::from_text("crypto");
 
         // Should match when keyword appears as whole word
-        assert!(blocklist.contains_blocked_keyword("I love crypto", None));
-        assert!(blocklist.contains_blocked_keyword("Crypto is volatile", None));
-        assert!(blocklist.contains_blocked_keyword("Bitcoin and crypto news", None));
+        assert!(blocks(&blocklist, "I love crypto", None));
+        assert!(blocks(&blocklist, "Crypto is volatile", None));
+        assert!(blocks(&blocklist, "Bitcoin and crypto news", None));
 
         // With punctuation boundaries
-        assert!(blocklist.contains_blocked_keyword("What is crypto?", None));
-        assert!(blocklist.contains_blocked_keyword("crypto, bitcoin, and NFTs", None));
+        assert!(blocks(&blocklist, "What is crypto?", None));
+        assert!(blocks(&blocklist, "crypto, bitcoin, and NFTs", None));
     }
 
     #[test]
     fn test_empty_blocklist_matches_nothing() {
-        let blocklist = Blocklist {
-            keywords: HashSet::new(),
-            last_modified: None,
-        };
+        let blocklist = Blocklist::from_text("");
 
         // Empty blocklist should never match
-        assert!(!blocklist.contains_blocked_keyword("Bitcoin news", None));
-        assert!(!blocklist.contains_blocked_keyword("Crypto markets", Some("cryptocurrency")));
-        assert!(!blocklist.contains_blocked_keyword("Any content", Some("Any other content")));
+        assert!(!blocks(&blocklist, "Bitcoin news", None));
+        assert!(!blocks(
+            &blocklist,
+            "Crypto markets",
+            Some("cryptocurrency")
+        ));
+        assert!(!blocks(
+            &blocklist,
+            "Any content",
+            Some("Any other content")
+        ));
+    }
+
+    fn target<'a>(title: &'a str) -> BlockTarget<'a> {
+        BlockTarget {
+            title,
+            url: "https://example.com/story",
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_comments_and_blank_lines_ignored() {
+        let blocklist = Blocklist::from_text("# crypto stuff\n\nbitcoin\n   # indented comment");
+        assert_eq!(blocklist.rules().len(), 1);
+        assert_eq!(blocklist.rules()[0].source, "bitcoin");
+    }
+
+    #[test]
+    fn test_regex_rule() {
+        let blocklist = Blocklist::from_text("/crypto(currency|coin)s?/");
+
+        assert!(blocks(&blocklist, "Cryptocurrency crash", None));
+        assert!(blocks(&blocklist, "New CRYPTOCOINS launched", None));
+        assert!(!blocks(&blocklist, "Crypto news", None));
+    }
+
+    #[test]
+    fn test_regex_rule_with_escaped_slash_and_clause() {
+        let blocklist =
+            Blocklist::from_text(r"url: /\/sponsored\// | except feed: Daring Fireball");
+        assert_eq!(blocklist.rules().len(), 1);
+
+        let mut entry = target("Great deal");
+        entry.url = "https://example.com/sponsored/post";
+        assert!(blocklist.find_match(&entry).is_some());
+
+        entry.feed = Some("Daring Fireball");
+        assert!(blocklist.find_match(&entry).is_none());
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected() {
+        assert!(BlockRule::parse("/unclosed(/").is_none());
+        assert!(BlockRule::parse("/no closing slash").is_none());
+        assert!(BlockRule::parse("//").is_none());
+        assert!(BlockRule::parse("crypto | colour: red").is_none());
+        assert!(BlockRule::parse("crypto | feed:").is_none());
+        assert!(BlockRule::parse("/crypto/ trailing").is_none());
+    }
+
+    #[test]
+    fn test_field_scoping() {
+        let blocklist = Blocklist::from_text("author: jane doe\ntitle: sponsored");
+
+        let mut entry = target("Daily news");
+        entry.content = Some("Sponsored by nobody, written by Jane Doe");
+        assert!(blocklist.find_match(&entry).is_none());
+
+        entry.author = Some("Jane Doe");
+        assert_eq!(
+            blocklist.find_match(&entry).map(|r| r.source.as_str()),
+            Some("author: jane doe")
+        );
+
+        let entry = target("Sponsored: a new gadget");
+        assert!(blocklist.find_match(&entry).is_some());
+    }
+
+    #[test]
+    fn test_multiple_fields() {
+        let rule = BlockRule::parse("title, url: deals").unwrap();
+        assert_eq!(
+            rule.fields,
+            RuleFields {
+                title: true,
+                content: false,
+                author: false,
+                url: true,
+            }
+        );
+
+        let mut entry = target("Today");
+        entry.url = "https://example.com/deals/today";
+        assert!(rule.matches(&entry));
+    }
+
+    #[test]
+    fn test_unknown_field_prefix_is_plain_keyword() {
+        // A regex with ':' is not mistaken for a field list
+        let blocklist = Blocklist::from_text("/re:\\s*deal/");
+        assert!(blocks(&blocklist, "Re: deal of the day", None));
+    }
+
+    #[test]
+    fn test_feed_and_folder_scoping() {
+        let blocklist = Blocklist::from_text("rumor | feed: MacRumors | folder: Gossip");
+
+        let mut entry = target("Apple rumor roundup");
+        assert!(blocklist.find_match(&entry).is_none());
+
+        entry.feed = Some("macrumors");
+        assert!(blocklist.find_match(&entry).is_some());
+
+        entry.feed = Some("The Verge");
+        entry.folder = Some("Gossip");
+        assert!(blocklist.find_match(&entry).is_some());
+
+        entry.folder = Some("Tech");
+        assert!(blocklist.find_match(&entry).is_none());
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let blocklist =
+            Blocklist::from_text("crypto | except feed: CoinDesk | except folder: Finance");
+
+        let mut entry = target("Crypto winter continues");
+        entry.feed = Some("The Verge");
+        assert!(blocklist.find_match(&entry).is_some());
+
+        entry.feed = Some("CoinDesk");
+        assert!(blocklist.find_match(&entry).is_none());
+
+        entry.feed = Some("Bloomberg");
+        entry.folder = Some("finance");
+        assert!(blocklist.find_match(&entry).is_none());
+    }
+
+    #[test]
+    fn test_readme_examples_parse() {
+        let readme = include_str!("../README.md");
+        let section = &readme[readme.find("### Rules").unwrap()..];
+        let block = section.split("```").nth(1).unwrap();
+        let lines: Vec<&str> = block
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        assert!(!lines.is_empty());
+
+        for line in &lines {
+            assert!(
+                BlockRule::parse(line).is_some(),
+                "README rule rejected: {line}"
+            );
+        }
+
+        let rules = Blocklist::from_text(block);
+        assert_eq!(rules.rules().len(), lines.len());
+        let feed_rule = rules.rules().iter().find(|r| !r.feeds.is_empty()).unwrap();
+        assert_eq!(feed_rule.feeds, vec!["macrumors".to_string()]);
+    }
+
+    #[test]
+    fn test_find_match_reports_rule() {
+        let blocklist = Blocklist::from_text("bitcoin\ntitle: /nft(s)?/");
+
+        let matched = blocklist.find_match(&target("NFTs are back"));
+        assert_eq!(matched.map(|r| r.source.as_str()), Some("title: /nft(s)?/"));
+    }
+
+    #[test]
+    fn test_overlapping_keywords_respect_boundaries() {
+        // "crypto news" fails its boundary inside "newsletter" but "crypto" still matches
+        let blocklist = Blocklist::from_text("crypto news\ncrypto");
+        let matched = blocklist.find_match(&target("The crypto newsletter"));
+        assert_eq!(matched.map(|r| r.source.as_str()), Some("crypto"));
+
+        let blocklist = Blocklist::from_text("crypto news");
+        assert!(!blocks(&blocklist, "The crypto newsletter", None));
+    }
+
+    #[test]
+    fn test_shared_keyword_with_different_scopes() {
+        let blocklist = Blocklist::from_text("apple | feed: Daily Deals\nauthor: apple");
+
+        let mut entry = target("Apple deal");
+        entry.feed = Some("Daily Deals");
+        assert_eq!(
+            blocklist.find_match(&entry).map(|r| r.source.as_str()),
+            Some("apple | feed: Daily Deals")
+        );
+
+        let mut entry = target("Unrelated");
+        entry.author = Some("Apple PR");
+        assert_eq!(
+            blocklist.find_match(&entry).map(|r| r.source.as_str()),
+            Some("author: apple")
+        );
+    }
+
+    #[test]
+    fn test_compiled_matcher_agrees_with_single_rules() {
+        let blocklist = Blocklist::from_text(
+            "bitcoin\n/nft(s)?/\ntitle: crypto\nurl: /sponsored/\nhyphen-word\nmulti word",
+        );
+        let texts = [
+            "Bitcoin rally",
+            "cryptocurrency is not crypto-adjacent",
+            "NFTs return",
+            "sponsored content",
+            "a hyphen-word here",
+            "multi  word spacing",
+            "multi word",
+            "nothing to see",
+        ];
+
+        for title in texts {
+            for content in texts {
+                let mut entry = target(title);
+                entry.content = Some(content);
+                let expected = blocklist.rules().iter().find(|r| r.matches(&entry));
+                assert_eq!(
+                    blocklist.find_match(&entry).map(|r| &r.source),
+                    expected.map(|r| &r.source),
+                    "title={:?} content={:?}",
+                    title,
+                    content
+                );
+            }
+        }
+    }
+
+    // ==================== Unicode keywords ====================
+
+    #[test]
+    fn test_normalize_unicode_keywords() {
+        assert_eq!(
+            Blocklist::normalize_keyword("Kryptowährung"),
+            Some("kryptowährung".to_string())
+        );
+        assert_eq!(
+            Blocklist::normalize_keyword("Straße"),
+            Some("strasse".to_string())
+        );
+        assert_eq!(
+            Blocklist::normalize_keyword("L'Élysée"),
+            Some("l'élysée".to_string())
+        );
+        assert_eq!(
+            Blocklist::normalize_keyword("ビットコイン"),
+            Some("ビットコイン".to_string())
+        );
+        // Ideographic space collapses like an ASCII space
+        assert_eq!(
+            Blocklist::normalize_keyword("暗号\u{3000}\u{3000}資産"),
+            Some("暗号 資産".to_string())
+        );
+        // Length is counted in characters, not bytes
+        assert!(Blocklist::normalize_keyword(&"株".repeat(50)).is_some());
+        assert_eq!(Blocklist::normalize_keyword(&"株".repeat(51)), None);
+        // ASCII punctuation is still rejected
+        assert_eq!(Blocklist::normalize_keyword("ビット@コイン"), None);
+    }
+
+    #[test]
+    fn test_german_keywords() {
+        let blocklist = Blocklist::from_text("Kryptowährung\nFußball");
+
+        assert!(blocks(&blocklist, "Die KRYPTOWÄHRUNG stürzt ab", None));
+        // Decomposed umlaut (a + combining diaeresis) matches the composed keyword
+        assert!(blocks(&blocklist, "Neue Kryptowa\u{308}hrung", None));
+        // Full case folding: ß and SS are the same
+        assert!(blocks(&blocklist, "FUSSBALL heute", None));
+        // Word boundaries still apply to Latin script compounds
+        assert!(!blocks(&blocklist, "Kryptowährungsbörse öffnet", None));
+    }
+
+    #[test]
+    fn test_french_keywords() {
+        let blocklist = Blocklist::from_text("élysée\nl'état");
+
+        assert!(blocks(&blocklist, "Réunion à l'ÉLYSÉE", None));
+        assert!(blocks(&blocklist, "Réforme de L'État annoncée", None));
+        assert!(!blocks(&blocklist, "Les élyséens", None));
+    }
+
+    #[test]
+    fn test_japanese_keywords() {
+        let blocklist = Blocklist::from_text("ビットコイン\napple");
+
+        // No spaces between words in Japanese, so match inside the sentence
+        assert!(blocks(&blocklist, "ビットコインが急落した", None));
+        assert!(blocks(&blocklist, "今日のビットコイン価格", None));
+        // Latin keyword directly adjacent to kana/kanji
+        assert!(blocks(&blocklist, "Appleが新製品を発表", None));
+        assert!(!blocks(&blocklist, "イーサリアムの動向", None));
+        // Latin boundaries are unaffected
+        assert!(!blocks(&blocklist, "Pineapple発表", None));
+    }
+
+    #[test]
+    fn test_emoji_keywords() {
+        let blocklist = Blocklist::from_text("🚀\nmoon 🌕");
+
+        assert!(blocks(&blocklist, "To the moon 🚀", None));
+        assert!(blocks(&blocklist, "Launch🚀🚀 today", None));
+        assert!(blocks(&blocklist, "Going to the MOON 🌕 soon", None));
+        assert!(!blocks(&blocklist, "Rocket launch today", None));
+    }
+
+    #[test]
+    fn test_unicode_feed_names() {
+        let blocklist = Blocklist::from_text("bundesliga | except feed: SÜDDEUTSCHE");
+
+        let mut entry = target("Bundesliga Ergebnisse");
+        entry.feed = Some("Süddeutsche");
+        assert!(blocklist.find_match(&entry).is_none());
+
+        entry.feed = Some("Kicker");
+        assert!(blocklist.find_match(&entry).is_some());
+    }
+
+    #[test]
+    fn test_add_rule_appends_and_keeps_comments() {
+        let (_dir, path, mut blocklist) = file_blocklist("# my rules\nbitcoin");
+
+        blocklist.add_rule("  author: Jane Doe ").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# my rules\nbitcoin\nauthor: Jane Doe\n"
+        );
+        assert_eq!(blocklist.rules().len(), 2);
+        assert_eq!(blocklist.rules()[1].source, "author: Jane Doe");
+        assert!(!path.with_extension("txt.tmp").exists());
+        // Our own write doesn't count as an outside change
+        assert!(!blocklist.reload());
+    }
+
+    #[test]
+    fn test_add_rule_rejects_invalid_and_duplicates() {
+        let (_dir, path, mut blocklist) = file_blocklist("bitcoin\n");
+
+        assert!(blocklist.add_rule("/unclosed").is_err());
+        assert!(blocklist.add_rule("bitcoin").is_err());
+        assert!(blocklist.add_rule("BitCoin").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "bitcoin\n");
+    }
+
+    #[test]
+    fn test_add_rule_creates_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("beatcheck").join("blocklist.txt");
+        let mut blocklist = Blocklist::load_from(&path);
+        assert!(blocklist.rules().is_empty());
+
+        blocklist.add_rule("crypto").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "crypto\n");
+        assert!(blocks(&blocklist, "crypto news", None));
+    }
+
+    #[test]
+    fn test_remove_rule() {
+        let (_dir, path, mut blocklist) =
+            file_blocklist("# comment\nbitcoin\n/nfts?/ | feed: Verge\ncrypto\n");
+
+        blocklist.remove_rule("/nfts?/ | feed: Verge").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# comment\nbitcoin\ncrypto\n"
+        );
+        assert_eq!(blocklist.rules().len(), 2);
+        assert!(blocklist.remove_rule("dogecoin").is_err());
+    }
+
+    #[test]
+    fn test_remove_rule_writes_through_symlink() {
+        let dir = tempfile::tempdir().unwrap();
+        let real = dir.path().join("dotfiles-blocklist.txt");
+        let link = dir.path().join("blocklist.txt");
+        fs::write(&real, "bitcoin\ncrypto\n").unwrap();
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&real, &link).unwrap();
+            let mut blocklist = Blocklist::load_from(&link);
+            blocklist.remove_rule("bitcoin").unwrap();
+            assert!(fs::symlink_metadata(&link)
+                .unwrap()
+                .file_type()
+                .is_symlink());
+            assert_eq!(fs::read_to_string(&real).unwrap(), "crypto\n");
+        }
+    }
+
+    #[test]
+    fn test_suggest_rules() {
+        let blocklist = Blocklist::from_text("bitcoin\n");
+        let suggestions = blocklist.suggest_rules(
+            "Why Bitcoin's crash has Elon Musk worried",
+            Some("Jane Doe"),
+            Some("The Verge"),
+        );
+        assert_eq!(
+            suggestions,
+            vec![
+                "worried",
+                "crash",
+                "Elon",
+                "Musk",
+                "author: Jane Doe",
+                "worried | feed: The Verge",
+            ]
+        );
+        // Every suggestion is a rule the blocklist accepts
+        for suggestion in &suggestions {
+            assert!(BlockRule::parse(suggestion).is_some());
+        }
+    }
+
+    #[test]
+    fn test_suggest_rules_non_latin_title() {
+        let blocklist = Blocklist::from_text("");
+        let suggestions = blocklist.suggest_rules("Kryptowährung im Höhenflug", None, None);
+        assert_eq!(suggestions, vec!["Kryptowährung", "Höhenflug"]);
     }
 }
@@ -0,0 +1,483 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration, Local, NaiveDate, Utc};
+use pulldown_cmark::{html, Event, Options, Parser};
+use regex::Regex;
+
+use crate::ai::Summarizer;
+use crate::config::BriefingConfig;
+use crate::db::Repository;
+use crate::error::Result;
+use crate::models::Article;
+
+/// Article text sent per story when it has no summary, in bytes
+const EXCERPT_BYTES: usize = 700;
+
+/// The briefing prompt. Stories are numbered; the model cites them as `Sources: [n]`
+/// and the links are filled in afterwards, so it never has to copy a URL.
+const BRIEFING_PROMPT: &str = r#"You are writing today's news briefing for a team that prepares a daily tech news rundown. Write it in the Smart Brevity style: short, scannable, no filler.
+
+RULES:
+1. Use ONLY the stories below - no external knowledge
+2. Start with one line: **The big picture:** followed by one sentence on the main theme of the day
+3. Then cover the most important stories first. Combine stories that are about the same news.
+4. Skip minor stories (deals, listicles, minor updates) if there are many stories
+5. Do not write any URLs or links yourself
+
+For each story, use exactly this format:
+
+### A short, punchy headline
+
+**Why it matters:** One sentence.
+
+- Two or three bullets with the key facts, figures, names and dates
+
+Sources: [n]
+
+where n is the story number. For combined stories list every number, like: Sources: [2] [7]
+
+STORIES:
+
+{stories}"#;
+
+/// One source of a story
+#[derive(Debug, Clone, PartialEq)]
+pub struct Source {
+    pub feed: String,
+    pub url: String,
+}
+
+/// Articles about the same story (one duplicate cluster)
+#[derive(Debug, Clone)]
+pub struct Story {
+    pub title: String,
+    /// The article's summary when it has one, else the start of its text
+    pub excerpt: String,
+    pub sources: Vec<Source>,
+}
+
+/// A generated briefing, ready to write
+#[derive(Debug, Clone)]
+pub struct Briefing {
+    pub date: NaiveDate,
+    pub markdown: String,
+    pub html: String,
+    pub stories: usize,
+    pub articles: usize,
+}
+
+/// Group articles (newest first) into stories by duplicate cluster. Stories carried by
+/// more feeds come first; at most `max_stories` are kept.
+pub fn group_stories(
+    articles: &[Article],
+    summaries: &HashMap<i64, String>,
+    max_stories: usize,
+) -> Vec<Story> {
+    let mut order: Vec<i64> = Vec::new();
+    let mut members: HashMap<i64, Vec<&Article>> = HashMap::new();
+    for article in articles {
+        let key = article.cluster_id.unwrap_or(article.id);
+        let entry = members.entry(key).or_default();
+        if entry.is_empty() {
+            order.push(key);
+        }
+        entry.push(article);
+    }
+
+    let mut stories: Vec<Story> = order
+        .iter()
+        .map(|key| {
+            let articles = &members[key];
+            let excerpt = articles
+                .iter()
+                .find_map(|a| summaries.get(&a.id).cloned())
+                .or_else(|| {
+                    articles
+                        .iter()
+                        .find_map(|a| a.content_text.as_deref())
+                        .map(|text| excerpt(text, EXCERPT_BYTES))
+                })
+                .unwrap_or_default();
+            let mut sources: Vec<Source> = Vec::new();
+            for article in articles {
+                if sources.iter().all(|s| s.url != article.url) {
+                    sources.push(Source {
+                        feed: article
+                            .feed_title
+                            .clone()
+                            .unwrap_or_else(|| "Unknown feed".to_string()),
+                        url: article.url.clone(),
+                    });
+                }
+            }
+            Story {
+                title: articles[0].title.clone(),
+                excerpt,
+                sources,
+            }
+        })
+        .collect();
+
+    // Stable, so equally covered stories stay newest first
+    stories.sort_by_key(|story| std::cmp::Reverse(story.sources.len()));
+    stories.truncate(max_stories);
+    stories
+}
+
+/// The start of `text`, cut at a word boundary
+fn excerpt(text: &str, max_bytes: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let cut = text[..end].rfind(' ').unwrap_or(end);
+    format!("{}…", &text[..cut])
+}
+
+/// The prompt listing every story by number
+pub fn build_prompt(stories: &[Story]) -> String {
+    let list = stories
+        .iter()
+        .enumerate()
+        .map(|(i, story)| {
+            let feeds = story
+                .sources
+                .iter()
+                .map(|s| s.feed.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "[{}] {}\nFeeds: {}\n{}",
+                i + 1,
+                story.title,
+                feeds,
+                story.excerpt
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    BRIEFING_PROMPT.replace("{stories}", &list)
+}
+
+/// Replace the story numbers on `Sources:` lines with links to the stories' articles.
+/// Numbers that aren't a story are dropped, and so is a line left with no links.
+pub fn expand_sources(markdown: &str, stories: &[Story]) -> String {
+    let sources_line =
+        Regex::new(r"(?i)^\s*(?:[-*]\s*)?\**\s*sources?\s*:\s*\**\s*((?:\[\d+\][\s,]*)+)$")
+            .expect("valid regex");
+    let number = Regex::new(r"\[(\d+)\]").expect("valid regex");
+
+    let mut lines = Vec::new();
+    for line in markdown.lines() {
+        let Some(numbers) = sources_line.captures(line) else {
+            lines.push(line.to_string());
+            continue;
+        };
+        let mut links: Vec<String> = Vec::new();
+        for n in number.captures_iter(&numbers[1]) {
+            let Some(story) = n[1]
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|i| stories.get(i))
+            else {
+                continue;
+            };
+            for source in &story.sources {
+                let link = format!("[{}](<{}>)", link_text(&source.feed), source.url);
+                if !links.contains(&link) {
+                    links.push(link);
+                }
+            }
+        }
+        if !links.is_empty() {
+            lines.push(format!("Sources: {}", links.join(", ")));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Feed name safe to use as Markdown link text
+fn link_text(feed: &str) -> String {
+    feed.replace('[', "(").replace(']', ")")
+}
+
+/// The full Markdown document: heading, what it covers, and the model's briefing
+pub fn render_markdown(
+    body: &str,
+    date: NaiveDate,
+    settings: &BriefingConfig,
+    stories: usize,
+    articles: usize,
+) -> String {
+    let mut scope = format!(
+        "{} stories from {} articles in the last {} hours",
+        stories, articles, settings.hours
+    );
+    if settings.unread_only {
+        scope.push_str(", unread only");
+    }
+    if let Some(folder) = &settings.folder {
+        scope.push_str(&format!(", folder {}", folder));
+    }
+    format!(
+        "# Daily Briefing: {}\n\n_{}_\n\n{}\n",
+        date.format("%A, %B %-d, %Y"),
+        scope,
+        body.trim()
+    )
+}
+
+/// A standalone HTML page for the Markdown. HTML in the model's reply is shown as text.
+pub fn render_html(markdown: &str, date: NaiveDate) -> String {
+    html_page(
+        markdown,
+        &format!("Daily Briefing: {}", date.format("%Y-%m-%d")),
+    )
+}
+
+/// A standalone HTML page with `title` for the Markdown; HTML in it is shown as text
+pub fn html_page(markdown: &str, title: &str) -> String {
+    let parser =
+        Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH).map(|event| match event {
+            Event::Html(text) | Event::InlineHtml(text) => Event::Text(text),
+            other => other,
+        });
+    let mut body = String::new();
+    html::push_html(&mut body, parser);
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{}</title>
+<style>
+body {{ font-family: -apple-system, system-ui, sans-serif; max-width: 44rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; color: #222; }}
+h3 {{ margin-top: 2rem; }}
+a {{ color: #0b57d0; }}
+</style>
+</head>
+<body>
+{}</body>
+</html>
+"#,
+        title
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;"),
+        body
+    )
+}
+
+/// Collect the stories and have the summarizer write the briefing. Its token usage is
+/// logged with the summaries'.
+pub async fn generate(
+    repository: &Repository,
+    summarizer: &Summarizer,
+    settings: &BriefingConfig,
+) -> Result<Briefing> {
+    let since = Utc::now() - Duration::hours(i64::from(settings.hours));
+    let articles = repository
+        .get_articles_since(since, settings.unread_only, settings.folder.clone())
+        .await?;
+    if articles.is_empty() {
+        return Err(anyhow::anyhow!("No articles in the last {} hours", settings.hours).into());
+    }
+
+    let mut summaries = HashMap::new();
+    for article in &articles {
+        if let Some(summary) = repository.get_summary(article.id).await? {
+            summaries.insert(article.id, summary.content);
+        }
+    }
+    let stories = group_stories(&articles, &summaries, settings.max_stories);
+
+    let reply = summarizer
+        .complete_prompt(&build_prompt(&stories), settings.max_tokens)
+        .await?;
+    if let Some(usage) = reply.usage {
+        repository
+            .log_usage(
+                summarizer.model_version().to_string(),
+                usage.input_tokens,
+                usage.output_tokens,
+                reply.cost_usd,
+            )
+            .await?;
+    }
+
+    let date = Local::now().date_naive();
+    let markdown = render_markdown(
+        &expand_sources(&reply.text, &stories),
+        date,
+        settings,
+        stories.len(),
+        articles.len(),
+    );
+    let html = render_html(&markdown, date);
+    Ok(Briefing {
+        date,
+        markdown,
+        html,
+        stories: stories.len(),
+        articles: articles.len(),
+    })
+}
+
+/// Write `briefing-YYYY-MM-DD.md` and `.html` to `dir`, replacing an earlier briefing
+/// from the same day. Returns the Markdown file's path.
+pub fn write(briefing: &Briefing, dir: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let stem = format!("briefing-{}", briefing.date.format("%Y-%m-%d"));
+    let markdown_path = dir.join(format!("{}.md", stem));
+    std::fs::write(&markdown_path, &briefing.markdown)?;
+    std::fs::write(dir.join(format!("{}.html", stem)), &briefing.html)?;
+    Ok(markdown_path)
+}
+
+/// Generate a briefing and write it to the configured directory
+pub async fn run(
+    repository: &Repository,
+    summarizer: &Summarizer,
+    settings: &BriefingConfig,
+) -> Result<PathBuf> {
+    let briefing = generate(repository, summarizer, settings).await?;
+    let path = write(&briefing, &settings.output_dir())?;
+    tracing::info!(
+        "Wrote briefing of {} stories from {} articles to {}",
+        briefing.stories,
+        briefing.articles,
+        path.display()
+    );
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(id: i64, feed: &str, cluster_id: Option<i64>) -> Article {
+        Article {
+            id,
+            feed_id: 1,
+            guid: id.to_string(),
+            title: format!("Story {}", id),
+            url: format!("https://example.com/{}", id),
+            author: None,
+            content: None,
+            content_text: Some(format!("Text of   article {}.\n\nMore text.", id)),
+            published_at: None,
+            fetched_at: Utc::now(),
+            feed_title: Some(feed.to_string()),
+            cluster_id,
+        }
+    }
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()
+    }
+
+    #[test]
+    fn test_stories_group_clusters_and_rank_by_sources() {
+        let articles = vec![
+            article(1, "The Verge", None),
+            article(2, "MacRumors", Some(2)),
+            article(3, "9to5Mac", Some(2)),
+            article(4, "Ars [Technica]", None),
+        ];
+        let summaries = HashMap::from([(3, "Apple shipped a phone.".to_string())]);
+        let stories = group_stories(&articles, &summaries, 10);
+
+        assert_eq!(stories.len(), 3);
+        assert_eq!(stories[0].title, "Story 2");
+        assert_eq!(stories[0].sources.len(), 2);
+        assert_eq!(stories[0].excerpt, "Apple shipped a phone.");
+        // The rest keep their order, with the article text as the excerpt
+        assert_eq!(stories[1].title, "Story 1");
+        assert_eq!(stories[1].excerpt, "Text of article 1. More text.");
+        assert_eq!(stories[2].title, "Story 4");
+
+        assert_eq!(group_stories(&articles, &summaries, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_excerpt_cuts_at_word() {
+        assert_eq!(excerpt("one two three", 100), "one two three");
+        assert_eq!(excerpt("one two three", 9), "one two…");
+    }
+
+    #[test]
+    fn test_prompt_numbers_stories() {
+        let stories = group_stories(&[article(1, "The Verge", None)], &HashMap::new(), 10);
+        let prompt = build_prompt(&stories);
+        assert!(prompt.contains("[1] Story 1\nFeeds: The Verge\nText of article 1."));
+        assert!(!prompt.contains("{stories}"));
+    }
+
+    #[test]
+    fn test_sources_become_links() {
+        let articles = vec![
+            article(1, "The Verge", None),
+            article(2, "Ars [Technica]", Some(2)),
+            article(3, "9to5Mac", Some(2)),
+        ];
+        let stories = group_stories(&articles, &HashMap::new(), 10);
+        let markdown = "### Headline\n\n- Sources say [1]\n\n**Sources:** [1] [2]\n\nSources: [9]";
+        let expanded = expand_sources(markdown, &stories);
+        assert_eq!(
+            expanded,
+            "### Headline\n\n- Sources say [1]\n\nSources: \
+             [Ars (Technica)](<https://example.com/2>), [9to5Mac](<https://example.com/3>), \
+             [The Verge](<https://example.com/1>)\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_and_html_documents() {
+        let settings = BriefingConfig {
+            unread_only: true,
+            folder: Some("Tech".to_string()),
+            ..Default::default()
+        };
+        let markdown = render_markdown(
+            "**The big picture:** Phones.\n\nSources: [MacRumors](<https://example.com/2>)\n\n<script>alert(1)</script>",
+            date(),
+            &settings,
+            3,
+            5,
+        );
+        assert!(markdown.starts_with("# Daily Briefing: Sunday, October 18, 2026\n\n"));
+        assert!(markdown.contains(
+            "_3 stories from 5 articles in the last 24 hours, unread only, folder Tech_"
+        ));
+
+        let html = render_html(&markdown, date());
+        assert!(html.contains("<title>Daily Briefing: 2026-10-18</title>"));
+        assert!(html.contains(r#"<a href="https://example.com/2">MacRumors</a>"#));
+        assert!(html.contains("<strong>The big picture:</strong>"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_write_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let briefing = Briefing {
+            date: date(),
+            markdown: "# Briefing\n".to_string(),
+            html: "<h1>Briefing</h1>".to_string(),
+            stories: 1,
+            articles: 1,
+        };
+        let path = write(&briefing, &dir.path().join("out")).unwrap();
+        assert_eq!(path, dir.path().join("out/briefing-2026-10-18.md"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# Briefing\n");
+        assert!(dir.path().join("out/briefing-2026-10-18.html").exists());
+    }
+}
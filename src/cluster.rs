@@ -0,0 +1,459 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use caseless::Caseless;
+use url::Url;
+
+/// Number of hash functions in a MinHash signature
+const NUM_HASHES: usize = 64;
+
+/// LSH bands; NUM_HASHES / BANDS rows each. 16 bands of 4 rows puts the
+/// "likely duplicate" threshold around 50% similarity.
+const BANDS: usize = 16;
+const ROWS: usize = NUM_HASHES / BANDS;
+
+/// Words per shingle
+const SHINGLE_WORDS: usize = 3;
+
+/// Only the start of the content is compared; syndicated copies diverge in footers
+const MAX_WORDS: usize = 400;
+
+/// Texts with fewer shingles than this are too short to compare reliably
+const MIN_SHINGLES: usize = 8;
+
+/// Estimated Jaccard similarity at which two stories count as the same
+const SIMILARITY_THRESHOLD: f64 = 0.6;
+
+/// Titles shorter than this many words are too generic to cluster on alone
+const MIN_TITLE_WORDS: usize = 4;
+
+/// Texts with fewer words than this aren't hashed: teasers like "Read more" are shared
+/// by unrelated stories
+const MIN_HASH_WORDS: usize = 50;
+
+/// Query parameters that only track where a click came from
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "mc_cid", "mc_eid", "ref", "ref_src", "cmpid",
+];
+
+/// The parts of an article used to find duplicates
+#[derive(Debug, Clone, Copy)]
+pub struct StoryText<'a> {
+    /// Feed the story came from; a feed repeating a headline isn't a duplicate
+    pub feed_id: i64,
+    pub url: &'a str,
+    pub title: &'a str,
+    pub content: Option<&'a str>,
+}
+
+/// Normalize a URL so links to the same page compare equal: no scheme, "www.",
+/// fragment, trailing slash or tracking parameters (utm_* and friends).
+pub fn canonical_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url.trim()) else {
+        return url.trim().to_lowercase();
+    };
+
+    let params: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| {
+            let key = key.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if params.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(params);
+    }
+    parsed.set_fragment(None);
+
+    let host = parsed.host_str().unwrap_or_default().to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let path = parsed.path().trim_end_matches('/');
+    match parsed.query() {
+        Some(query) => format!("{}{}?{}", host, path, query),
+        None => format!("{}{}", host, path),
+    }
+}
+
+/// Hash of a text's words (case, punctuation and spacing ignored), as 16 hex digits, so
+/// copies of the same story hash alike. None for texts too short to tell stories apart.
+/// FNV-1a, which unlike `DefaultHasher` is the same in every build, as stored hashes must be.
+pub fn content_hash(text: &str) -> Option<String> {
+    let words = words(text);
+    if words.len() < MIN_HASH_WORDS {
+        return None;
+    }
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in words.join(" ").bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    Some(format!("{:016x}", hash))
+}
+
+/// Lowercased words of a text, punctuation removed
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.chars().default_case_fold().collect())
+        .collect()
+}
+
+/// Title reduced to its words, or None if it's too short to identify a story
+fn title_key(title: &str) -> Option<String> {
+    let words = words(title);
+    (words.len() >= MIN_TITLE_WORDS).then(|| words.join(" "))
+}
+
+/// Hashes of every run of SHINGLE_WORDS consecutive words
+fn shingles(story: &StoryText) -> HashSet<u64> {
+    let mut text = words(story.title);
+    if let Some(content) = story.content {
+        text.extend(words(content).into_iter().take(MAX_WORDS));
+    }
+
+    text.windows(SHINGLE_WORDS)
+        .map(|window| {
+            let mut hasher = DefaultHasher::new();
+            window.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect()
+}
+
+/// splitmix64, used to derive NUM_HASHES independent hash functions from one shingle hash
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// MinHash signature of a shingle set, or None if the text is too short
+fn signature(shingles: &HashSet<u64>) -> Option<[u64; NUM_HASHES]> {
+    if shingles.len() < MIN_SHINGLES {
+        return None;
+    }
+
+    let mut sig = [u64::MAX; NUM_HASHES];
+    for &shingle in shingles {
+        for (i, slot) in sig.iter_mut().enumerate() {
+            let h = mix(shingle ^ mix(i as u64));
+            if h < *slot {
+                *slot = h;
+            }
+        }
+    }
+    Some(sig)
+}
+
+/// Fraction of matching signature slots, an estimate of Jaccard similarity
+fn similarity(a: &[u64; NUM_HASHES], b: &[u64; NUM_HASHES]) -> f64 {
+    let same = a.iter().zip(b).filter(|(x, y)| x == y).count();
+    same as f64 / NUM_HASHES as f64
+}
+
+/// Union-find over story indices; the root of a group is its smallest index
+struct Groups {
+    parent: Vec<usize>,
+}
+
+impl Groups {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Path compression
+        let mut node = i;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            self.parent[a.max(b)] = a.min(b);
+        }
+    }
+
+    /// Join every story that shares a key
+    fn union_by_key<K: Hash + Eq>(&mut self, keys: impl Iterator<Item = (usize, K)>) {
+        let mut first: HashMap<K, usize> = HashMap::new();
+        for (i, key) in keys {
+            match first.get(&key) {
+                Some(&j) => self.union(i, j),
+                None => {
+                    first.insert(key, i);
+                }
+            }
+        }
+    }
+}
+
+/// Group duplicate stories: same canonical URL, same title, or near-identical text.
+/// Near-duplicates are found with MinHash signatures over word shingles, bucketed with
+/// locality-sensitive hashing so only likely pairs are compared.
+/// Returns, for each story, the index of the first story in its group
+/// (a story on its own maps to itself).
+pub fn find_clusters(stories: &[StoryText]) -> Vec<usize> {
+    let mut groups = Groups::new(stories.len());
+
+    // Same page, possibly linked with different tracking parameters
+    groups.union_by_key(
+        stories
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (i, canonical_url(s.url)))
+            .filter(|(_, url)| !url.is_empty()),
+    );
+
+    // Same headline from different feeds. A headline one feed uses more than once is a
+    // recurring column title ("Morning briefing", "Deals of the day"), not one story.
+    let titles: Vec<Option<String>> = stories.iter().map(|s| title_key(s.title)).collect();
+    let mut per_feed: HashMap<(&str, i64), usize> = HashMap::new();
+    for (title, story) in titles.iter().zip(stories) {
+        if let Some(title) = title {
+            *per_feed.entry((title, story.feed_id)).or_default() += 1;
+        }
+    }
+    groups.union_by_key(titles.iter().enumerate().filter_map(|(i, title)| {
+        let title = title.as_deref()?;
+        (per_feed[&(title, stories[i].feed_id)] == 1).then_some((i, title))
+    }));
+
+    // Near-identical text: stories from different feeds sharing any LSH band are
+    // candidates, confirmed by comparing full signatures
+    let signatures: Vec<Option<[u64; NUM_HASHES]>> =
+        stories.iter().map(|s| signature(&shingles(s))).collect();
+    let mut buckets: HashMap<(usize, &[u64]), Vec<usize>> = HashMap::new();
+    for (i, sig) in signatures.iter().enumerate() {
+        if let Some(sig) = sig {
+            for band in 0..BANDS {
+                let rows = &sig[band * ROWS..(band + 1) * ROWS];
+                buckets.entry((band, rows)).or_default().push(i);
+            }
+        }
+    }
+    for members in buckets.values() {
+        for (n, &i) in members.iter().enumerate() {
+            for &j in &members[n + 1..] {
+                if stories[i].feed_id == stories[j].feed_id || groups.find(i) == groups.find(j) {
+                    continue;
+                }
+                if let (Some(a), Some(b)) = (&signatures[i], &signatures[j]) {
+                    if similarity(a, b) >= SIMILARITY_THRESHOLD {
+                        groups.union(i, j);
+                    }
+                }
+            }
+        }
+    }
+
+    (0..stories.len()).map(|i| groups.find(i)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn story<'a>(
+        feed_id: i64,
+        url: &'a str,
+        title: &'a str,
+        content: Option<&'a str>,
+    ) -> StoryText<'a> {
+        StoryText {
+            feed_id,
+            url,
+            title,
+            content,
+        }
+    }
+
+    const APPLE_STORY: &str =
+        "Apple today announced the iPhone 17 lineup at its September event in \
+        Cupertino, with a thinner design, a faster A19 chip, an improved camera system and \
+        longer battery life. Preorders begin Friday and the phones ship the following week, \
+        starting at $799 for the base model.";
+
+    #[test]
+    fn test_content_hash_ignores_formatting() {
+        let story = "Apple announced a new phone on Tuesday. ".repeat(10);
+        let reformatted = story.to_uppercase().replace(". ", "!\n\n");
+        assert_eq!(content_hash(&story), content_hash(&reformatted));
+        assert!(content_hash(&story).is_some());
+
+        let other = "Google announced a new phone on Tuesday. ".repeat(10);
+        assert_ne!(content_hash(&story), content_hash(&other));
+
+        assert_eq!(content_hash("Read more on our site"), None);
+    }
+
+    #[test]
+    fn test_canonical_url() {
+        assert_eq!(
+            canonical_url("https://www.Example.com/news/story/?utm_source=rss&utm_medium=feed#top"),
+            "example.com/news/story"
+        );
+        assert_eq!(
+            canonical_url("http://example.com/news/story"),
+            "example.com/news/story"
+        );
+        // Parameters that identify the page are kept
+        assert_eq!(
+            canonical_url("https://example.com/article?id=42&fbclid=abc"),
+            "example.com/article?id=42"
+        );
+        // Parameters that look like tracking but can pick the page are kept too
+        assert_eq!(
+            canonical_url("https://example.com/search?source=rss"),
+            "example.com/search?source=rss"
+        );
+        assert_eq!(canonical_url("not a url"), "not a url");
+    }
+
+    #[test]
+    fn test_same_canonical_url_clusters() {
+        let stories = [
+            story(
+                1,
+                "https://www.example.com/a?utm_source=x",
+                "One headline",
+                None,
+            ),
+            story(1, "https://example.com/a/", "Different headline", None),
+            story(1, "https://example.com/b", "Another story", None),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 0, 2]);
+
+        // Articles without a link aren't all the same story
+        let stories = [story(1, "", "One", None), story(1, "", "Two", None)];
+        assert_eq!(find_clusters(&stories), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_same_title_clusters() {
+        let stories = [
+            story(1, "https://a.com/1", "Apple Announces the iPhone 17", None),
+            story(2, "https://b.com/2", "Apple announces the iPhone 17!", None),
+            // Short, generic titles don't cluster on their own
+            story(3, "https://c.com/3", "Podcast", None),
+            story(4, "https://d.com/4", "Podcast", None),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 0, 2, 3]);
+    }
+
+    #[test]
+    fn test_same_title_in_one_feed_does_not_cluster() {
+        let stories = [
+            story(
+                1,
+                "https://a.com/1",
+                "The Morning Briefing: Top Tech News",
+                None,
+            ),
+            story(
+                1,
+                "https://a.com/2",
+                "The Morning Briefing: Top Tech News",
+                None,
+            ),
+            story(
+                2,
+                "https://b.com/3",
+                "The Morning Briefing: Top Tech News",
+                None,
+            ),
+            // A headline a feed only used once still clusters across feeds
+            story(2, "https://b.com/4", "Apple announces the iPhone 17", None),
+            story(3, "https://c.com/5", "Apple announces the iPhone 17", None),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_near_duplicate_content_clusters() {
+        let syndicated = format!("{} Reporting by the Associated Press.", APPLE_STORY);
+        let stories = [
+            story(
+                1,
+                "https://a.com/1",
+                "Apple unveils iPhone 17",
+                Some(APPLE_STORY),
+            ),
+            story(
+                2,
+                "https://b.com/2",
+                "iPhone 17 is here: everything Apple announced",
+                Some(&syndicated),
+            ),
+            story(
+                3,
+                "https://c.com/3",
+                "Microsoft ships a Windows update",
+                Some(
+                    "Microsoft released a cumulative update for Windows 11 on Tuesday that \
+                     fixes a number of security vulnerabilities, including two that were being \
+                     actively exploited, and improves the reliability of the Start menu.",
+                ),
+            ),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 0, 2]);
+    }
+
+    #[test]
+    fn test_same_content_in_one_feed_does_not_cluster() {
+        // A feed's boilerplate (a newsletter footer, a recurring intro) isn't one story
+        let stories = [
+            story(1, "https://a.com/1", "Monday roundup", Some(APPLE_STORY)),
+            story(1, "https://a.com/2", "Tuesday roundup", Some(APPLE_STORY)),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_short_content_does_not_cluster() {
+        let stories = [
+            story(1, "https://a.com/1", "First", Some("Read more")),
+            story(2, "https://b.com/2", "Second", Some("Read more")),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_clusters_are_transitive() {
+        let stories = [
+            story(1, "https://a.com/1", "Apple announces the iPhone 17", None),
+            story(2, "https://b.com/2", "Apple announces the iPhone 17", None),
+            story(
+                2,
+                "https://b.com/2?utm_campaign=x",
+                "A different title",
+                None,
+            ),
+        ];
+        assert_eq!(find_clusters(&stories), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_similarity_estimate() {
+        let a = shingles(&story(1, "", "", Some(APPLE_STORY)));
+        let sig = signature(&a).unwrap();
+        assert_eq!(similarity(&sig, &sig), 1.0);
+        assert!(signature(&shingles(&story(1, "", "Too short", None))).is_none());
+    }
+}
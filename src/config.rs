@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -18,6 +19,334 @@ pub struct Config {
 
     #[serde(default)]
     pub default_tags: Vec<String>,
+
+    #[serde(default)]
+    pub summarizer: SummarizerConfig,
+
+    #[serde(default)]
+    pub presummarize: PresummarizeConfig,
+
+    #[serde(default)]
+    pub usage: UsageConfig,
+
+    #[serde(default)]
+    pub briefing: BriefingConfig,
+
+    #[serde(default)]
+    pub rundown: RundownConfig,
+
+    #[serde(default)]
+    pub cookies: CookiesConfig,
+}
+
+/// Which model service writes summaries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SummarizerBackend {
+    /// Anthropic Messages API (Claude)
+    #[default]
+    Anthropic,
+    /// OpenAI or any OpenAI-compatible chat completions endpoint
+    #[serde(rename = "openai")]
+    OpenAi,
+    /// Local Ollama server
+    Ollama,
+    /// Local llama.cpp server (llama-server)
+    #[serde(rename = "llamacpp")]
+    LlamaCpp,
+}
+
+/// The `[summarizer]` table. Every field is optional; each backend has its own
+/// default URL and model.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SummarizerConfig {
+    #[serde(default)]
+    pub backend: SummarizerBackend,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Longest summary the model may write, in tokens (default 1024)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// Prompt template used by default (default "nutgraph", the built-in one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Named prompt templates with {title}, {content}, {feed} and {author} placeholders
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, String>,
+    /// Article text sent in one request, in bytes (default 10000). Longer articles are
+    /// summarized in parts, then the notes on the parts are summarized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunk_size: Option<usize>,
+    /// Most parts of a long article that are summarized (default 8)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_chunks: Option<usize>,
+    /// What to do with quotes that aren't in the article before a summary goes to Raindrop
+    #[serde(default)]
+    pub unverified_quotes: UnverifiedQuotes,
+    /// Pre-fill the Raindrop tag prompt with tags the model picks from the ones already
+    /// in use (default true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggest_tags: Option<bool>,
+    /// Language summaries are written in and articles are translated into, by its English
+    /// name ("English", "German"). Unset, translations go into English and summaries
+    /// follow the prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// Handling of summary quotes that weren't found word for word in the article, when the
+/// summary is saved to Raindrop. The summary pane marks them either way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnverifiedQuotes {
+    /// Save the summary as it is
+    #[default]
+    Keep,
+    /// Take the unverified quotes out
+    Strip,
+    /// Write the summary again, then take out any quotes still unverified
+    Regenerate,
+}
+
+/// The `[presummarize]` table: summarize new articles in the background after a
+/// refresh so summaries are ready when they're opened
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PresummarizeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Summaries generated at the same time
+    #[serde(default = "default_presummarize_concurrency")]
+    pub concurrency: usize,
+    /// Most summaries generated after one refresh
+    #[serde(default = "default_presummarize_max_per_run")]
+    pub max_per_run: usize,
+    /// Most background summaries generated per day (local time)
+    #[serde(default = "default_presummarize_max_per_day")]
+    pub max_per_day: usize,
+    /// Only summarize articles from these feeds (all feeds when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include_feeds: Vec<String>,
+    /// Never summarize articles from these feeds
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_feeds: Vec<String>,
+}
+
+impl Default for PresummarizeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            concurrency: default_presummarize_concurrency(),
+            max_per_run: default_presummarize_max_per_run(),
+            max_per_day: default_presummarize_max_per_day(),
+            include_feeds: Vec::new(),
+            exclude_feeds: Vec::new(),
+        }
+    }
+}
+
+/// The `[usage]` table: token prices and spending limits
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageConfig {
+    /// Background summaries stop for the rest of the month once this much (USD) is spent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_budget: Option<f64>,
+    /// Prices by model name (or name prefix), added to and overriding the built-in table
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub prices: BTreeMap<String, ModelPrice>,
+}
+
+/// The `[briefing]` table: the daily briefing written by `--briefing` and `n`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BriefingConfig {
+    /// Where briefing files are written (default ~/.local/share/beatcheck/briefings)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_dir: Option<String>,
+    /// Articles published in the last this many hours are included
+    #[serde(default = "default_briefing_hours")]
+    pub hours: u32,
+    /// Leave out articles already read
+    #[serde(default)]
+    pub unread_only: bool,
+    /// Only articles from feeds in this OPML folder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+    /// Most stories sent to the model; stories with more sources go first
+    #[serde(default = "default_briefing_max_stories")]
+    pub max_stories: usize,
+    /// Longest briefing the model may write, in tokens
+    #[serde(default = "default_briefing_max_tokens")]
+    pub max_tokens: u32,
+}
+
+impl Default for BriefingConfig {
+    fn default() -> Self {
+        Self {
+            output_dir: None,
+            hours: default_briefing_hours(),
+            unread_only: false,
+            folder: None,
+            max_stories: default_briefing_max_stories(),
+            max_tokens: default_briefing_max_tokens(),
+        }
+    }
+}
+
+impl BriefingConfig {
+    /// `output_dir` with `~` expanded, or the default
+    pub fn output_dir(&self) -> PathBuf {
+        output_dir(self.output_dir.as_deref(), "briefings")
+    }
+}
+
+/// The `[rundown]` table: show rundowns exported with `--rundown` and from the `R` screen
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RundownConfig {
+    /// Where rundown files are written (default ~/.local/share/beatcheck/rundowns)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_dir: Option<String>,
+}
+
+impl RundownConfig {
+    /// `output_dir` with `~` expanded, or the default
+    pub fn output_dir(&self) -> PathBuf {
+        output_dir(self.output_dir.as_deref(), "rundowns")
+    }
+}
+
+/// Browsers whose cookies can be sent when fetching full articles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Browser {
+    Chrome,
+    Chromium,
+    Brave,
+    Vivaldi,
+    Edge,
+    Opera,
+    Firefox,
+}
+
+impl Browser {
+    /// In the order they're tried when no browser is configured
+    pub const ALL: [Browser; 7] = [
+        Browser::Chrome,
+        Browser::Chromium,
+        Browser::Brave,
+        Browser::Vivaldi,
+        Browser::Edge,
+        Browser::Opera,
+        Browser::Firefox,
+    ];
+}
+
+impl fmt::Display for Browser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Browser::Chrome => "Chrome",
+            Browser::Chromium => "Chromium",
+            Browser::Brave => "Brave",
+            Browser::Vivaldi => "Vivaldi",
+            Browser::Edge => "Edge",
+            Browser::Opera => "Opera",
+            Browser::Firefox => "Firefox",
+        })
+    }
+}
+
+/// A browser, and optionally one of its profiles, to take cookies from
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CookieSource {
+    /// Every browser is tried when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser: Option<Browser>,
+    /// Profile directory ("Default", "Profile 1") or the name the browser shows for it.
+    /// Every profile is tried when unset, the default one first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+}
+
+/// The `[cookies]` table: which browser profile's cookies are sent when fetching full
+/// articles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CookiesConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub browser: Option<Browser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Sources for particular sites, by domain; an entry also covers its subdomains
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub domains: BTreeMap<String, CookieSource>,
+}
+
+impl CookiesConfig {
+    /// The source for `host`: the entry for its longest configured domain, or the
+    /// table's own `browser` and `profile`
+    pub fn source_for(&self, host: &str) -> CookieSource {
+        let host = host.trim_end_matches('.').to_lowercase();
+        self.domains
+            .iter()
+            .filter(|(domain, _)| {
+                let domain = domain.trim_start_matches('.').to_lowercase();
+                host == domain || host.ends_with(&format!(".{}", domain))
+            })
+            .max_by_key(|(domain, _)| domain.trim_start_matches('.').len())
+            .map(|(_, source)| source.clone())
+            .unwrap_or_else(|| CookieSource {
+                browser: self.browser,
+                profile: self.profile.clone(),
+            })
+    }
+}
+
+/// A configured directory with `~` expanded, or `name` in the data directory
+fn output_dir(dir: Option<&str>, name: &str) -> PathBuf {
+    match dir {
+        Some(dir) => match dir.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join(rest),
+            None => PathBuf::from(dir),
+        },
+        None => dirs::data_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("beatcheck")
+            .join(name),
+    }
+}
+
+/// USD per million tokens
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub input: f64,
+    pub output: f64,
+}
+
+fn default_presummarize_concurrency() -> usize {
+    2
+}
+
+fn default_presummarize_max_per_run() -> usize {
+    20
+}
+
+fn default_presummarize_max_per_day() -> usize {
+    100
+}
+
+fn default_briefing_hours() -> u32 {
+    24
+}
+
+fn default_briefing_max_stories() -> usize {
+    40
+}
+
+fn default_briefing_max_tokens() -> u32 {
+    4096
 }
 
 fn default_db_path() -> String {
@@ -40,6 +369,12 @@ impl Default for Config {
             raindrop_token: None,
             refresh_interval_minutes: default_refresh_interval(),
             default_tags: vec!["rss".to_string()],
+            summarizer: SummarizerConfig::default(),
+            presummarize: PresummarizeConfig::default(),
+            usage: UsageConfig::default(),
+            briefing: BriefingConfig::default(),
+            rundown: RundownConfig::default(),
+            cookies: CookiesConfig::default(),
         }
     }
 }
@@ -80,6 +415,11 @@ impl Config {
         if let Ok(token) = std::env::var("RAINDROP_TOKEN") {
             config.raindrop_token = Some(token);
         }
+        if config.summarizer.backend == SummarizerBackend::OpenAi {
+            if let Ok(key) = std::env::var("OPENAI_API_KEY") {
+                config.summarizer.api_key = Some(key);
+            }
+        }
 
         Ok(config)
     }
@@ -213,6 +553,203 @@ refresh_interval_minutes = "not a number"
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_summarizer_section() {
+        let toml = r#"
+[summarizer]
+backend = "openai"
+base_url = "http://localhost:1234/v1"
+model = "qwen2.5-7b-instruct"
+"#;
+
+        let config = toml.parse::<Config>().unwrap();
+        assert_eq!(config.summarizer.backend, SummarizerBackend::OpenAi);
+        assert_eq!(
+            config.summarizer.base_url.as_deref(),
+            Some("http://localhost:1234/v1")
+        );
+        assert_eq!(
+            config.summarizer.model.as_deref(),
+            Some("qwen2.5-7b-instruct")
+        );
+        assert_eq!(config.summarizer.api_key, None);
+
+        for (name, backend) in [
+            ("anthropic", SummarizerBackend::Anthropic),
+            ("ollama", SummarizerBackend::Ollama),
+            ("llamacpp", SummarizerBackend::LlamaCpp),
+        ] {
+            let toml = format!("[summarizer]\nbackend = \"{}\"\n", name);
+            assert_eq!(toml.parse::<Config>().unwrap().summarizer.backend, backend);
+        }
+        assert!("[summarizer]\nbackend = \"gpt\"\n"
+            .parse::<Config>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_prompt_templates() {
+        let toml = r#"
+[summarizer]
+model = "claude-sonnet-4-5"
+max_tokens = 400
+template = "brief"
+chunk_size = 8000
+max_chunks = 12
+unverified_quotes = "regenerate"
+
+[summarizer.templates]
+brief = "Summarize {title} from {feed} in three bullets:\n\n{content}"
+byline = """
+Who wrote this ({author}) and what is it about?
+{content}
+"""
+"#;
+
+        let config = toml.parse::<Config>().unwrap();
+        assert_eq!(
+            config.summarizer.model.as_deref(),
+            Some("claude-sonnet-4-5")
+        );
+        assert_eq!(config.summarizer.max_tokens, Some(400));
+        assert_eq!(config.summarizer.template.as_deref(), Some("brief"));
+        assert_eq!(config.summarizer.chunk_size, Some(8000));
+        assert_eq!(config.summarizer.max_chunks, Some(12));
+        assert_eq!(
+            config.summarizer.unverified_quotes,
+            UnverifiedQuotes::Regenerate
+        );
+        assert_eq!(config.summarizer.templates.len(), 2);
+        assert!(config.summarizer.templates["brief"].starts_with("Summarize {title}"));
+        assert!(config.summarizer.templates["byline"].contains("{author}"));
+    }
+
+    #[test]
+    fn test_parse_presummarize_section() {
+        let config = "".parse::<Config>().unwrap();
+        assert!(!config.presummarize.enabled);
+        assert_eq!(config.presummarize.concurrency, 2);
+        assert_eq!(config.presummarize.max_per_run, 20);
+        assert_eq!(config.presummarize.max_per_day, 100);
+
+        let toml = r#"
+[presummarize]
+enabled = true
+max_per_day = 40
+exclude_feeds = ["Hacker News", "Slashdot"]
+"#;
+        let config = toml.parse::<Config>().unwrap();
+        assert!(config.presummarize.enabled);
+        assert_eq!(config.presummarize.concurrency, 2);
+        assert_eq!(config.presummarize.max_per_day, 40);
+        assert!(config.presummarize.include_feeds.is_empty());
+        assert_eq!(
+            config.presummarize.exclude_feeds,
+            vec!["Hacker News", "Slashdot"]
+        );
+    }
+
+    #[test]
+    fn test_parse_usage_section() {
+        let toml = r#"
+[usage]
+monthly_budget = 5.0
+
+[usage.prices]
+"claude-haiku-4-5" = { input = 1.0, output = 5.0 }
+my-model = { input = 0.5, output = 1.5 }
+"#;
+        let config = toml.parse::<Config>().unwrap();
+        assert_eq!(config.usage.monthly_budget, Some(5.0));
+        assert_eq!(
+            config.usage.prices["my-model"],
+            ModelPrice {
+                input: 0.5,
+                output: 1.5
+            }
+        );
+        assert_eq!(config.usage.prices.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_briefing_settings() {
+        let config = "".parse::<Config>().unwrap();
+        assert_eq!(config.briefing, BriefingConfig::default());
+        assert_eq!(config.briefing.hours, 24);
+        assert!(config
+            .briefing
+            .output_dir()
+            .ends_with("beatcheck/briefings"));
+
+        let toml = r#"
+[briefing]
+output_dir = "~/Rundowns"
+hours = 8
+unread_only = true
+folder = "Tech"
+"#;
+        let config = toml.parse::<Config>().unwrap();
+        assert_eq!(config.briefing.hours, 8);
+        assert!(config.briefing.unread_only);
+        assert_eq!(config.briefing.folder.as_deref(), Some("Tech"));
+        assert_eq!(config.briefing.max_stories, 40);
+        let dir = config.briefing.output_dir();
+        assert!(dir.ends_with("Rundowns") && dir.is_absolute());
+    }
+
+    #[test]
+    fn test_parse_rundown_settings() {
+        let config = "".parse::<Config>().unwrap();
+        assert!(config.rundown.output_dir().ends_with("beatcheck/rundowns"));
+
+        let config = "[rundown]\noutput_dir = \"/srv/shows\"\n"
+            .parse::<Config>()
+            .unwrap();
+        assert_eq!(config.rundown.output_dir(), PathBuf::from("/srv/shows"));
+    }
+
+    #[test]
+    fn test_parse_cookie_sources() {
+        let toml = r#"
+[cookies]
+browser = "brave"
+
+[cookies.domains]
+"nytimes.com" = { browser = "firefox", profile = "Work" }
+"cooking.nytimes.com" = { browser = "chrome" }
+"#;
+        let cookies = toml.parse::<Config>().unwrap().cookies;
+        assert_eq!(
+            cookies.source_for("www.nytimes.com"),
+            CookieSource {
+                browser: Some(Browser::Firefox),
+                profile: Some("Work".to_string()),
+            }
+        );
+        assert_eq!(
+            cookies.source_for("Cooking.NYTimes.com").browser,
+            Some(Browser::Chrome)
+        );
+        assert_eq!(
+            cookies.source_for("notnytimes.com"),
+            CookieSource {
+                browser: Some(Browser::Brave),
+                profile: None,
+            }
+        );
+
+        assert!("[cookies]\nbrowser = \"netscape\"\n"
+            .parse::<Config>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_summarizer_defaults_to_anthropic() {
+        let config = "".parse::<Config>().unwrap();
+        assert_eq!(config.summarizer, SummarizerConfig::default());
+        assert_eq!(config.summarizer.backend, SummarizerBackend::Anthropic);
+    }
+
     // ==================== Serialization ====================
 
     #[test]
@@ -223,6 +760,12 @@ refresh_interval_minutes = "not a number"
             raindrop_token: None,
             refresh_interval_minutes: 45,
             default_tags: vec!["a".to_string(), "b".to_string()],
+            summarizer: SummarizerConfig::default(),
+            presummarize: PresummarizeConfig::default(),
+            usage: UsageConfig::default(),
+            briefing: BriefingConfig::default(),
+            rundown: RundownConfig::default(),
+            cookies: CookiesConfig::default(),
         };
 
         let toml = config.to_string();
@@ -241,6 +784,59 @@ refresh_interval_minutes = "not a number"
             raindrop_token: Some("token456".to_string()),
             refresh_interval_minutes: 120,
             default_tags: vec!["tag1".to_string(), "tag2".to_string(), "tag3".to_string()],
+            summarizer: SummarizerConfig {
+                backend: SummarizerBackend::Ollama,
+                base_url: Some("http://gpu-box:11434".to_string()),
+                api_key: None,
+                model: Some("mistral".to_string()),
+                max_tokens: Some(512),
+                template: Some("brief".to_string()),
+                templates: BTreeMap::from([(
+                    "brief".to_string(),
+                    "Summarize {title} in one line:\n\n{content}".to_string(),
+                )]),
+                chunk_size: Some(6000),
+                max_chunks: Some(4),
+                unverified_quotes: UnverifiedQuotes::Strip,
+                suggest_tags: Some(false),
+                language: Some("German".to_string()),
+            },
+            presummarize: PresummarizeConfig {
+                enabled: true,
+                include_feeds: vec!["The Verge".to_string()],
+                ..Default::default()
+            },
+            usage: UsageConfig {
+                monthly_budget: Some(2.5),
+                prices: BTreeMap::from([(
+                    "mistral".to_string(),
+                    ModelPrice {
+                        input: 0.0,
+                        output: 0.0,
+                    },
+                )]),
+            },
+            briefing: BriefingConfig {
+                output_dir: Some("~/Rundowns".to_string()),
+                hours: 12,
+                unread_only: true,
+                folder: Some("Tech".to_string()),
+                ..Default::default()
+            },
+            rundown: RundownConfig {
+                output_dir: Some("/srv/shows".to_string()),
+            },
+            cookies: CookiesConfig {
+                browser: Some(Browser::Vivaldi),
+                profile: None,
+                domains: BTreeMap::from([(
+                    "wsj.com".to_string(),
+                    CookieSource {
+                        browser: Some(Browser::Chrome),
+                        profile: Some("Profile 1".to_string()),
+                    },
+                )]),
+            },
         };
 
         let toml = original.to_string();
@@ -254,6 +850,12 @@ refresh_interval_minutes = "not a number"
             original.refresh_interval_minutes
         );
         assert_eq!(parsed.default_tags, original.default_tags);
+        assert_eq!(parsed.summarizer, original.summarizer);
+        assert_eq!(parsed.presummarize, original.presummarize);
+        assert_eq!(parsed.usage, original.usage);
+        assert_eq!(parsed.briefing, original.briefing);
+        assert_eq!(parsed.rundown, original.rundown);
+        assert_eq!(parsed.cookies, original.cookies);
     }
 
     // ==================== Edge cases ====================
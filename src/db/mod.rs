@@ -1,4 +1,6 @@
 mod repository;
 mod schema;
 
+#[cfg(test)]
+pub(crate) use repository::test_support;
 pub use repository::Repository;
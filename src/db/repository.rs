@@ -1,12 +1,26 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use rusqlite::{params, OptionalExtension, Row};
 use tokio_rusqlite::Connection;
 
 use crate::error::Result;
-use crate::models::{Article, Feed, NewArticle, NewFeed, Summary};
+use crate::models::{
+    Article, BlockedArticle, ChatMessage, ChatRole, Feed, NewArticle, NewFeed, NewSummary, Rundown,
+    RundownItem, Summary, SummaryFields, SummaryKind, UsageTotals,
+};
+
+use super::schema::{COLUMN_MIGRATIONS, SCHEMA};
 
-use super::schema::SCHEMA;
+/// Ids of articles older than `?1` days (by publish date, else fetch date). Bookmarks
+/// are kept for that long after they're saved, so a show's rundown still has them.
+const OLD_ARTICLES: &str = r#"SELECT id FROM articles
+    WHERE (published_at < datetime('now', '-' || ?1 || ' days')
+       OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days')))
+      AND id NOT IN (SELECT article_id FROM saved_to_raindrop
+                     WHERE saved_at >= datetime('now', '-' || ?1 || ' days'))"#;
 
+#[derive(Clone)]
 pub struct Repository {
     conn: Connection,
 }
@@ -21,6 +35,20 @@ impl Repository {
             // Enable WAL mode for better concurrency
             conn.execute_batch("PRAGMA journal_mode=WAL;")?;
             conn.execute_batch(SCHEMA)?;
+            // Add columns introduced after a database was first created
+            for (table, column, definition) in COLUMN_MIGRATIONS {
+                let exists: bool = conn.query_row(
+                    "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+                    params![table, column],
+                    |row| row.get::<_, i64>(0).map(|count| count > 0),
+                )?;
+                if !exists {
+                    conn.execute_batch(&format!(
+                        "ALTER TABLE {} ADD COLUMN {} {}",
+                        table, column, definition
+                    ))?;
+                }
+            }
             Ok(())
         })
         .await?;
@@ -35,8 +63,8 @@ impl Repository {
             .conn
             .call(move |conn| {
                 conn.execute(
-                    "INSERT INTO feeds (title, url, site_url, description) VALUES (?1, ?2, ?3, ?4)",
-                    params![feed.title, feed.url, feed.site_url, feed.description],
+                    "INSERT INTO feeds (title, url, site_url, description, folder) VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![feed.title, feed.url, feed.site_url, feed.description, feed.folder],
                 )?;
                 Ok(conn.last_insert_rowid())
             })
@@ -49,7 +77,7 @@ impl Repository {
             .conn
             .call(|conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT id, title, url, site_url, description, last_fetched, created_at, updated_at FROM feeds ORDER BY title",
+                    "SELECT id, title, url, site_url, description, last_fetched, created_at, updated_at, folder FROM feeds ORDER BY title",
                 )?;
                 let feeds = stmt
                     .query_map([], feed_from_row)?
@@ -73,6 +101,20 @@ impl Repository {
         Ok(())
     }
 
+    pub async fn update_feed_folder(&self, url: &str, folder: Option<String>) -> Result<()> {
+        let url = url.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "UPDATE feeds SET folder = ?1, updated_at = datetime('now') WHERE url = ?2",
+                    params![folder, url],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
     pub async fn delete_feed(&self, id: i64) -> Result<()> {
         self.conn
             .call(move |conn| {
@@ -134,9 +176,10 @@ impl Repository {
                 let mut stmt = conn.prepare(
                     r#"SELECT a.id, a.feed_id, a.guid, a.title, a.url, a.author, a.content,
                               a.content_text, a.published_at, a.fetched_at,
-                              f.title as feed_title
+                              f.title as feed_title, a.cluster_id
                        FROM articles a
                        JOIN feeds f ON a.feed_id = f.id
+                       WHERE (a.filtered_rule IS NULL OR a.rescued = 1) AND a.deleted = 0
                        ORDER BY a.published_at DESC NULLS LAST, a.fetched_at DESC"#,
                 )?;
                 let articles = stmt
@@ -148,23 +191,83 @@ impl Repository {
         Ok(articles)
     }
 
-    pub async fn delete_article(&self, id: i64) -> Result<()> {
+    /// Delete several articles at once (e.g. a whole duplicate cluster) in one transaction.
+    /// Bookmarked articles are only hidden, so a show's rundown still lists them.
+    pub async fn delete_articles(&self, ids: Vec<i64>) -> Result<()> {
         self.conn
             .call(move |conn| {
-                // Record the article's feed_id and guid before deleting (to prevent re-adding)
-                conn.execute(
-                    r#"INSERT OR IGNORE INTO deleted_articles (feed_id, guid)
-                       SELECT feed_id, guid FROM articles WHERE id = ?1"#,
-                    params![id],
-                )?;
-                // Delete related data first
-                conn.execute("DELETE FROM summaries WHERE article_id = ?1", params![id])?;
-                conn.execute(
-                    "DELETE FROM saved_to_raindrop WHERE article_id = ?1",
-                    params![id],
-                )?;
-                // Delete the article
-                conn.execute("DELETE FROM articles WHERE id = ?1", params![id])?;
+                let tx = conn.transaction()?;
+                for id in ids {
+                    // Record the article's feed_id and guid before deleting (to prevent re-adding)
+                    tx.execute(
+                        r#"INSERT OR IGNORE INTO deleted_articles (feed_id, guid)
+                           SELECT feed_id, guid FROM articles WHERE id = ?1"#,
+                        params![id],
+                    )?;
+                    // A bookmarked article stays, hidden, until its show's rundown is finished
+                    let bookmarked: bool = tx.query_row(
+                        "SELECT COUNT(*) > 0 FROM saved_to_raindrop WHERE article_id = ?1",
+                        params![id],
+                        |row| row.get(0),
+                    )?;
+                    if bookmarked {
+                        tx.execute("UPDATE articles SET deleted = 1 WHERE id = ?1", params![id])?;
+                        continue;
+                    }
+                    // Delete related data first
+                    tx.execute("DELETE FROM summaries WHERE article_id = ?1", params![id])?;
+                    tx.execute(
+                        "DELETE FROM saved_to_raindrop WHERE article_id = ?1",
+                        params![id],
+                    )?;
+                    tx.execute(
+                        "DELETE FROM chat_messages WHERE article_id = ?1",
+                        params![id],
+                    )?;
+                    tx.execute(
+                        "DELETE FROM rundown_items WHERE article_id = ?1",
+                        params![id],
+                    )?;
+                    // Delete the article
+                    tx.execute("DELETE FROM articles WHERE id = ?1", params![id])?;
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Set the duplicate cluster of each article in one transaction
+    pub async fn set_article_clusters(&self, clusters: Vec<(i64, Option<i64>)>) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                {
+                    let mut stmt =
+                        tx.prepare("UPDATE articles SET cluster_id = ?2 WHERE id = ?1")?;
+                    for (id, cluster_id) in &clusters {
+                        stmt.execute(params![id, cluster_id])?;
+                    }
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn mark_articles_read(&self, ids: Vec<i64>) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                {
+                    let mut stmt = tx.prepare("UPDATE articles SET is_read = 1 WHERE id = ?1")?;
+                    for id in &ids {
+                        stmt.execute(params![id])?;
+                    }
+                }
+                tx.commit()?;
                 Ok(())
             })
             .await?;
@@ -179,6 +282,11 @@ impl Repository {
                     "DELETE FROM deleted_articles WHERE feed_id = ?1 AND guid = ?2",
                     params![feed_id, guid],
                 )?;
+                // A bookmarked article that was only hidden comes straight back
+                conn.execute(
+                    "UPDATE articles SET deleted = 0 WHERE feed_id = ?1 AND guid = ?2",
+                    params![feed_id, guid],
+                )?;
                 Ok(())
             })
             .await?;
@@ -188,33 +296,7 @@ impl Repository {
     pub async fn delete_old_articles(&self, days: i64) -> Result<usize> {
         let deleted = self
             .conn
-            .call(move |conn| {
-                // Delete summaries and raindrop entries for old articles first
-                conn.execute(
-                    r#"DELETE FROM summaries WHERE article_id IN (
-                        SELECT id FROM articles
-                        WHERE published_at < datetime('now', '-' || ?1 || ' days')
-                           OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days'))
-                    )"#,
-                    params![days],
-                )?;
-                conn.execute(
-                    r#"DELETE FROM saved_to_raindrop WHERE article_id IN (
-                        SELECT id FROM articles
-                        WHERE published_at < datetime('now', '-' || ?1 || ' days')
-                           OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days'))
-                    )"#,
-                    params![days],
-                )?;
-                // Delete old articles (using published_at, fallback to fetched_at if null)
-                let deleted = conn.execute(
-                    r#"DELETE FROM articles
-                       WHERE published_at < datetime('now', '-' || ?1 || ' days')
-                          OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days'))"#,
-                    params![days],
-                )?;
-                Ok(deleted)
-            })
+            .call(move |conn| Ok(delete_old_article_rows(conn, days)?))
             .await?;
         Ok(deleted)
     }
@@ -224,28 +306,7 @@ impl Repository {
             .conn
             .call(move |conn| {
                 // Delete old articles first
-                conn.execute(
-                    r#"DELETE FROM summaries WHERE article_id IN (
-                        SELECT id FROM articles
-                        WHERE published_at < datetime('now', '-' || ?1 || ' days')
-                           OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days'))
-                    )"#,
-                    params![days],
-                )?;
-                conn.execute(
-                    r#"DELETE FROM saved_to_raindrop WHERE article_id IN (
-                        SELECT id FROM articles
-                        WHERE published_at < datetime('now', '-' || ?1 || ' days')
-                           OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days'))
-                    )"#,
-                    params![days],
-                )?;
-                let old_deleted = conn.execute(
-                    r#"DELETE FROM articles
-                       WHERE published_at < datetime('now', '-' || ?1 || ' days')
-                          OR (published_at IS NULL AND fetched_at < datetime('now', '-' || ?1 || ' days'))"#,
-                    params![days],
-                )?;
+                let old_deleted = delete_old_article_rows(conn, days)?;
 
                 // Clean up old deleted_articles tracking entries
                 conn.execute(
@@ -262,6 +323,161 @@ impl Repository {
         Ok(result)
     }
 
+    // Blocklist filtering
+
+    /// Store an entry the blocklist matched, hidden from the article list and tagged
+    /// with the rule. Entries the user rescued earlier stay visible.
+    pub async fn upsert_blocked_article(&self, article: NewArticle, rule: String) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                let was_deleted: bool = conn
+                    .query_row(
+                        "SELECT 1 FROM deleted_articles WHERE feed_id = ?1 AND guid = ?2",
+                        params![article.feed_id, article.guid],
+                        |_| Ok(true),
+                    )
+                    .unwrap_or(false);
+
+                if was_deleted {
+                    return Ok(());
+                }
+
+                conn.execute(
+                    r#"INSERT INTO articles (feed_id, guid, title, url, author, content, content_text, published_at, filtered_rule, filtered_at)
+                       VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, datetime('now'))
+                       ON CONFLICT(feed_id, guid) DO UPDATE SET
+                           title = excluded.title,
+                           url = excluded.url,
+                           author = excluded.author,
+                           content = excluded.content,
+                           content_text = excluded.content_text,
+                           published_at = excluded.published_at,
+                           filtered_rule = excluded.filtered_rule,
+                           filtered_at = COALESCE(articles.filtered_at, excluded.filtered_at)"#,
+                    params![
+                        article.feed_id,
+                        article.guid,
+                        article.title,
+                        article.url,
+                        article.author,
+                        article.content,
+                        article.content_text,
+                        article.published_at.map(|dt| dt.to_rfc3339()),
+                        rule,
+                    ],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Every stored article (visible or hidden) with its current blocklist rule,
+    /// for re-evaluating the blocklist after it changes. Rescued articles are left out.
+    pub async fn get_articles_for_blocklist(&self) -> Result<Vec<(Article, Option<String>)>> {
+        let articles = self
+            .conn
+            .call(|conn| {
+                let mut stmt = conn.prepare(
+                    r#"SELECT a.id, a.feed_id, a.guid, a.title, a.url, a.author, a.content,
+                              a.content_text, a.published_at, a.fetched_at,
+                              f.title as feed_title, a.cluster_id, a.filtered_rule
+                       FROM articles a
+                       JOIN feeds f ON a.feed_id = f.id
+                       WHERE a.rescued = 0 AND a.deleted = 0"#,
+                )?;
+                let articles = stmt
+                    .query_map([], |row| Ok((article_from_row(row)?, row.get(12)?)))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(articles)
+            })
+            .await?;
+        Ok(articles)
+    }
+
+    /// Set or clear the blocklist rule on articles in one transaction.
+    /// A rule hides the article (unless rescued); None makes it visible again.
+    pub async fn set_blocked_rules(&self, changes: Vec<(i64, Option<String>)>) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                {
+                    let mut stmt = tx.prepare(
+                        r#"UPDATE articles SET
+                               filtered_rule = ?2,
+                               filtered_at = CASE WHEN ?2 IS NULL THEN NULL
+                                                  ELSE COALESCE(filtered_at, datetime('now')) END
+                           WHERE id = ?1"#,
+                    )?;
+                    for (id, rule) in &changes {
+                        stmt.execute(params![id, rule])?;
+                    }
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Hidden articles that haven't been rescued, newest first
+    pub async fn get_blocked_articles(&self) -> Result<Vec<BlockedArticle>> {
+        let blocked = self
+            .conn
+            .call(|conn| {
+                let mut stmt = conn.prepare(
+                    r#"SELECT a.id, a.title, a.url, a.published_at,
+                              a.filtered_at, a.filtered_rule, f.title as feed_title
+                       FROM articles a
+                       JOIN feeds f ON a.feed_id = f.id
+                       WHERE a.filtered_rule IS NOT NULL AND a.rescued = 0 AND a.deleted = 0
+                       ORDER BY a.published_at DESC NULLS LAST, a.filtered_at DESC"#,
+                )?;
+                let blocked = stmt
+                    .query_map([], blocked_article_from_row)?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(blocked)
+            })
+            .await?;
+        Ok(blocked)
+    }
+
+    /// Per-rule counts of blocked articles: (rule, hits, rescued)
+    pub async fn get_blocked_rule_counts(&self) -> Result<Vec<(String, usize, usize)>> {
+        let counts = self
+            .conn
+            .call(|conn| {
+                let mut stmt = conn.prepare(
+                    r#"SELECT filtered_rule, COUNT(*), SUM(rescued) FROM articles
+                       WHERE filtered_rule IS NOT NULL
+                       GROUP BY filtered_rule"#,
+                )?;
+                let counts = stmt
+                    .query_map([], |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            row.get::<_, i64>(1)? as usize,
+                            row.get::<_, i64>(2)? as usize,
+                        ))
+                    })?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(counts)
+            })
+            .await?;
+        Ok(counts)
+    }
+
+    /// Show a blocked article in the list and keep the blocklist from hiding it again
+    pub async fn rescue_blocked_article(&self, id: i64) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                conn.execute("UPDATE articles SET rescued = 1 WHERE id = ?1", params![id])?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
     // Summary operations
 
     pub async fn get_summary(&self, article_id: i64) -> Result<Option<Summary>> {
@@ -269,7 +485,10 @@ impl Repository {
             .conn
             .call(move |conn| {
                 let mut stmt = conn.prepare(
-                    "SELECT id, article_id, content, model_version, generated_at FROM summaries WHERE article_id = ?1",
+                    r#"SELECT id, article_id, content, model_version, generated_at, template, unverified_quotes,
+                              summary_type, lede, why_it_matters, price, availability, platforms, quote, speaker,
+                              reused_from
+                       FROM summaries WHERE article_id = ?1"#,
                 )?;
                 let summary = stmt
                     .query_row(params![article_id], summary_from_row)
@@ -280,43 +499,267 @@ impl Repository {
         Ok(summary)
     }
 
-    pub async fn save_summary(
+    /// Give `article_id` a copy of the summary of an identical story, if there is one:
+    /// another article's summary written with `template` for the same canonical URL or,
+    /// when `content_hash` is given, the same text. Nothing is logged as usage. Returns
+    /// whether a summary was copied.
+    pub async fn reuse_summary(
         &self,
         article_id: i64,
-        content: String,
-        model: String,
-    ) -> Result<()> {
+        template: String,
+        canonical_url: String,
+        content_hash: Option<String>,
+        automatic: bool,
+    ) -> Result<bool> {
+        let copied = self
+            .conn
+            .call(move |conn| {
+                let copied = conn.execute(
+                    r#"INSERT INTO summaries (article_id, content, model_version, template, automatic,
+                                              unverified_quotes, summary_type, lede, why_it_matters,
+                                              price, availability, platforms, quote, speaker,
+                                              content_hash, canonical_url, reused_from)
+                       SELECT ?1, s.content, s.model_version, s.template, ?5,
+                              s.unverified_quotes, s.summary_type, s.lede, s.why_it_matters,
+                              s.price, s.availability, s.platforms, s.quote, s.speaker,
+                              s.content_hash, ?3, COALESCE(s.reused_from, f.title, a.title)
+                       FROM summaries s
+                       JOIN articles a ON a.id = s.article_id
+                       LEFT JOIN feeds f ON f.id = a.feed_id
+                       WHERE s.article_id != ?1
+                         AND s.template = ?2
+                         AND (s.canonical_url = ?3 OR (?4 IS NOT NULL AND s.content_hash = ?4))
+                       ORDER BY s.generated_at DESC
+                       LIMIT 1
+                       ON CONFLICT(article_id) DO UPDATE SET
+                           content = excluded.content,
+                           model_version = excluded.model_version,
+                           template = excluded.template,
+                           automatic = excluded.automatic,
+                           input_tokens = NULL,
+                           output_tokens = NULL,
+                           cost_usd = NULL,
+                           unverified_quotes = excluded.unverified_quotes,
+                           summary_type = excluded.summary_type,
+                           lede = excluded.lede,
+                           why_it_matters = excluded.why_it_matters,
+                           price = excluded.price,
+                           availability = excluded.availability,
+                           platforms = excluded.platforms,
+                           quote = excluded.quote,
+                           speaker = excluded.speaker,
+                           content_hash = excluded.content_hash,
+                           canonical_url = excluded.canonical_url,
+                           reused_from = excluded.reused_from,
+                           generated_at = datetime('now')"#,
+                    params![article_id, template, canonical_url, content_hash, automatic],
+                )?;
+                Ok(copied > 0)
+            })
+            .await?;
+        Ok(copied)
+    }
+
+    /// Store a summary, replacing any earlier one, and log its token usage
+    pub async fn save_summary(&self, summary: NewSummary) -> Result<()> {
+        let unverified_quotes = serde_json::to_string(&summary.unverified_quotes)?;
+        let fields = summary.fields.clone().unwrap_or_default();
+        let summary_type = summary.fields.as_ref().map(|f| f.kind.as_str());
         self.conn
             .call(move |conn| {
-                conn.execute(
-                    r#"INSERT INTO summaries (article_id, content, model_version)
-                       VALUES (?1, ?2, ?3)
+                let tx = conn.transaction()?;
+                tx.execute(
+                    r#"INSERT INTO summaries (article_id, content, model_version, template, automatic,
+                                              input_tokens, output_tokens, cost_usd, unverified_quotes,
+                                              summary_type, lede, why_it_matters, price, availability,
+                                              platforms, quote, speaker, content_hash, canonical_url)
+                       VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                               ?18, ?19)
                        ON CONFLICT(article_id) DO UPDATE SET
                            content = excluded.content,
                            model_version = excluded.model_version,
+                           template = excluded.template,
+                           automatic = excluded.automatic,
+                           input_tokens = excluded.input_tokens,
+                           output_tokens = excluded.output_tokens,
+                           cost_usd = excluded.cost_usd,
+                           unverified_quotes = excluded.unverified_quotes,
+                           summary_type = excluded.summary_type,
+                           lede = excluded.lede,
+                           why_it_matters = excluded.why_it_matters,
+                           price = excluded.price,
+                           availability = excluded.availability,
+                           platforms = excluded.platforms,
+                           quote = excluded.quote,
+                           speaker = excluded.speaker,
+                           content_hash = excluded.content_hash,
+                           canonical_url = excluded.canonical_url,
+                           reused_from = NULL,
                            generated_at = datetime('now')"#,
-                    params![article_id, content, model],
+                    params![
+                        summary.article_id,
+                        summary.content,
+                        summary.model_version,
+                        summary.template,
+                        summary.automatic,
+                        summary.input_tokens,
+                        summary.output_tokens,
+                        summary.cost_usd,
+                        unverified_quotes,
+                        summary_type,
+                        summary.fields.as_ref().map(|f| f.lede.as_str()),
+                        fields.why_it_matters,
+                        fields.price,
+                        fields.availability,
+                        fields.platforms,
+                        fields.quote,
+                        fields.speaker,
+                        summary.content_hash,
+                        summary.canonical_url,
+                    ],
+                )?;
+                tx.execute(
+                    r#"INSERT INTO usage_log (article_id, model, input_tokens, output_tokens, cost_usd, automatic)
+                       VALUES (?1, ?2, ?3, ?4, ?5, ?6)"#,
+                    params![
+                        summary.article_id,
+                        summary.model_version,
+                        summary.input_tokens.unwrap_or_default(),
+                        summary.output_tokens.unwrap_or_default(),
+                        summary.cost_usd,
+                        summary.automatic,
+                    ],
                 )?;
+                tx.commit()?;
                 Ok(())
             })
             .await?;
         Ok(())
     }
 
-    // Raindrop tracking
+    /// Total usage of summaries generated since `since`
+    pub async fn get_usage_since(&self, since: DateTime<Utc>) -> Result<UsageTotals> {
+        let since = since.format("%Y-%m-%d %H:%M:%S").to_string();
+        let totals = self
+            .conn
+            .call(move |conn| {
+                let totals = conn.query_row(
+                    r#"SELECT COUNT(*), COALESCE(SUM(input_tokens), 0),
+                              COALESCE(SUM(output_tokens), 0), COALESCE(SUM(cost_usd), 0)
+                       FROM usage_log WHERE created_at >= ?1"#,
+                    params![since],
+                    usage_totals_from_row,
+                )?;
+                Ok(totals)
+            })
+            .await?;
+        Ok(totals)
+    }
 
-    pub async fn mark_saved_to_raindrop(
+    /// Usage per local calendar day since `since`, newest day first
+    pub async fn get_daily_usage(
         &self,
-        article_id: i64,
-        raindrop_id: i64,
-        tags: Vec<String>,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(String, UsageTotals)>> {
+        let since = since.format("%Y-%m-%d %H:%M:%S").to_string();
+        let days = self
+            .conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    r#"SELECT COUNT(*), COALESCE(SUM(input_tokens), 0),
+                              COALESCE(SUM(output_tokens), 0), COALESCE(SUM(cost_usd), 0),
+                              date(created_at, 'localtime') AS day
+                       FROM usage_log WHERE created_at >= ?1
+                       GROUP BY day ORDER BY day DESC"#,
+                )?;
+                let days = stmt
+                    .query_map(params![since], |row| {
+                        Ok((row.get::<_, String>(4)?, usage_totals_from_row(row)?))
+                    })?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(days)
+            })
+            .await?;
+        Ok(days)
+    }
+
+    /// Unread, visible articles fetched since `since` that have no summary yet, newest first
+    pub async fn get_unsummarized_articles(&self, since: DateTime<Utc>) -> Result<Vec<Article>> {
+        let since = since.format("%Y-%m-%d %H:%M:%S").to_string();
+        let articles = self
+            .conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    r#"SELECT a.id, a.feed_id, a.guid, a.title, a.url, a.author, a.content,
+                              a.content_text, a.published_at, a.fetched_at,
+                              f.title as feed_title, a.cluster_id
+                       FROM articles a
+                       JOIN feeds f ON a.feed_id = f.id
+                       LEFT JOIN summaries s ON s.article_id = a.id
+                       WHERE (a.filtered_rule IS NULL OR a.rescued = 1)
+                         AND a.deleted = 0
+                         AND a.is_read = 0
+                         AND s.id IS NULL
+                         AND datetime(a.fetched_at) >= ?1
+                       ORDER BY a.published_at DESC NULLS LAST, a.fetched_at DESC"#,
+                )?;
+                let articles = stmt
+                    .query_map(params![since], article_from_row)?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(articles)
+            })
+            .await?;
+        Ok(articles)
+    }
+
+    /// Visible articles published (or, without a date, fetched) since `since`, newest
+    /// first. Optionally only unread ones, or only feeds in `folder` (case-insensitive).
+    pub async fn get_articles_since(
+        &self,
+        since: DateTime<Utc>,
+        unread_only: bool,
+        folder: Option<String>,
+    ) -> Result<Vec<Article>> {
+        let since = since.format("%Y-%m-%d %H:%M:%S").to_string();
+        let articles = self
+            .conn
+            .call(move |conn| {
+                let mut stmt = conn.prepare(
+                    r#"SELECT a.id, a.feed_id, a.guid, a.title, a.url, a.author, a.content,
+                              a.content_text, a.published_at, a.fetched_at,
+                              f.title as feed_title, a.cluster_id
+                       FROM articles a
+                       JOIN feeds f ON a.feed_id = f.id
+                       WHERE (a.filtered_rule IS NULL OR a.rescued = 1)
+                         AND a.deleted = 0
+                         AND COALESCE(datetime(a.published_at), datetime(a.fetched_at)) >= ?1
+                         AND (?2 = 0 OR a.is_read = 0)
+                         AND (?3 IS NULL OR f.folder = ?3 COLLATE NOCASE)
+                       ORDER BY a.published_at DESC NULLS LAST, a.fetched_at DESC"#,
+                )?;
+                let articles = stmt
+                    .query_map(params![since, unread_only, folder], article_from_row)?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(articles)
+            })
+            .await?;
+        Ok(articles)
+    }
+
+    /// Log tokens used by a request that isn't an article summary (e.g. a briefing)
+    pub async fn log_usage(
+        &self,
+        model: String,
+        input_tokens: u32,
+        output_tokens: u32,
+        cost_usd: Option<f64>,
     ) -> Result<()> {
-        let tags_json = serde_json::to_string(&tags)?;
         self.conn
             .call(move |conn| {
                 conn.execute(
-                    "INSERT OR REPLACE INTO saved_to_raindrop (article_id, raindrop_id, tags) VALUES (?1, ?2, ?3)",
-                    params![article_id, raindrop_id, tags_json],
+                    r#"INSERT INTO usage_log (article_id, model, input_tokens, output_tokens, cost_usd)
+                       VALUES (NULL, ?1, ?2, ?3, ?4)"#,
+                    params![model, input_tokens, output_tokens, cost_usd],
                 )?;
                 Ok(())
             })
@@ -324,30 +767,329 @@ impl Repository {
         Ok(())
     }
 
-    pub async fn is_saved_to_raindrop(&self, article_id: i64) -> Result<bool> {
-        let exists = self
+    // Chat operations
+
+    /// An article's follow-up chat, oldest message first
+    pub async fn get_chat(&self, article_id: i64) -> Result<Vec<ChatMessage>> {
+        let messages = self
             .conn
             .call(move |conn| {
-                let count: i64 = conn.query_row(
-                    "SELECT COUNT(*) FROM saved_to_raindrop WHERE article_id = ?1",
-                    params![article_id],
-                    |row| row.get(0),
+                let mut stmt = conn.prepare(
+                    "SELECT role, content FROM chat_messages WHERE article_id = ?1 ORDER BY id",
                 )?;
-                Ok(count > 0)
+                let rows = stmt.query_map(params![article_id], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?;
+                let mut messages = Vec::new();
+                for row in rows {
+                    let (role, content) = row?;
+                    if let Some(role) = ChatRole::parse(&role) {
+                        messages.push(ChatMessage { role, content });
+                    }
+                }
+                Ok(messages)
             })
             .await?;
-        Ok(exists)
+        Ok(messages)
     }
-}
 
-fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
-    // Try RFC3339 first (e.g., "2026-01-11T12:34:56+00:00")
-    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-        return Some(dt.with_timezone(&Utc));
-    }
-    // Try SQLite datetime format (e.g., "2026-01-11 12:34:56")
-    if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
-        return Some(naive.and_utc());
+    pub async fn add_chat_message(&self, article_id: i64, message: ChatMessage) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT INTO chat_messages (article_id, role, content) VALUES (?1, ?2, ?3)",
+                    params![article_id, message.role.as_str(), message.content],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn clear_chat(&self, article_id: i64) -> Result<()> {
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "DELETE FROM chat_messages WHERE article_id = ?1",
+                    params![article_id],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Number of background summaries generated since `since`
+    pub async fn count_automatic_summaries_since(&self, since: DateTime<Utc>) -> Result<usize> {
+        let since = since.format("%Y-%m-%d %H:%M:%S").to_string();
+        let count = self
+            .conn
+            .call(move |conn| {
+                let count: i64 = conn.query_row(
+                    // Copies of another story's summary cost nothing
+                    "SELECT COUNT(*) FROM summaries WHERE automatic = 1 AND reused_from IS NULL AND generated_at >= ?1",
+                    params![since],
+                    |row| row.get(0),
+                )?;
+                Ok(count as usize)
+            })
+            .await?;
+        Ok(count)
+    }
+
+    // Raindrop tracking
+
+    pub async fn mark_saved_to_raindrop(
+        &self,
+        article_id: i64,
+        raindrop_id: i64,
+        tags: Vec<String>,
+    ) -> Result<()> {
+        let tags_json = serde_json::to_string(&tags)?;
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    "INSERT OR REPLACE INTO saved_to_raindrop (article_id, raindrop_id, tags) VALUES (?1, ?2, ?3)",
+                    params![article_id, raindrop_id, tags_json],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Every tag used on a Raindrop bookmark, most used first
+    pub async fn get_raindrop_tags(&self) -> Result<Vec<String>> {
+        let tag_lists = self
+            .conn
+            .call(|conn| {
+                let mut stmt =
+                    conn.prepare("SELECT tags FROM saved_to_raindrop WHERE tags IS NOT NULL")?;
+                let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+                let mut lists = Vec::new();
+                for row in rows {
+                    lists.push(row?);
+                }
+                Ok(lists)
+            })
+            .await?;
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for json in tag_lists {
+            let tags: Vec<String> = serde_json::from_str(&json).unwrap_or_default();
+            for tag in tags {
+                *counts.entry(tag).or_default() += 1;
+            }
+        }
+        let mut tags: Vec<(String, usize)> = counts.into_iter().collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(tags.into_iter().map(|(tag, _)| tag).collect())
+    }
+
+    pub async fn is_saved_to_raindrop(&self, article_id: i64) -> Result<bool> {
+        let exists = self
+            .conn
+            .call(move |conn| {
+                let count: i64 = conn.query_row(
+                    "SELECT COUNT(*) FROM saved_to_raindrop WHERE article_id = ?1",
+                    params![article_id],
+                    |row| row.get(0),
+                )?;
+                Ok(count > 0)
+            })
+            .await?;
+        Ok(exists)
+    }
+
+    // Show rundowns
+
+    /// Articles bookmarked with the `show` tag since its last rundown was finished, in
+    /// running order; ones never moved follow in the order they were saved
+    pub async fn get_rundown(&self, show: &str) -> Result<Rundown> {
+        let show = show.to_string();
+        let (since, rows) = self
+            .conn
+            .call({
+                let show = show.clone();
+                move |conn| {
+                    let since: Option<String> = conn
+                        .query_row(
+                            "SELECT last_show_at FROM shows WHERE tag = ?1",
+                            params![show],
+                            |row| row.get(0),
+                        )
+                        .optional()?;
+                    let mut stmt = conn.prepare(
+                        r#"SELECT a.id, a.title, a.url, f.title, r.tags, r.saved_at,
+                                  s.lede, s.content, ri.note, r.raindrop_id,
+                                  ri.article_id IS NOT NULL
+                           FROM saved_to_raindrop r
+                           JOIN articles a ON a.id = r.article_id
+                           LEFT JOIN feeds f ON f.id = a.feed_id
+                           LEFT JOIN summaries s ON s.article_id = a.id
+                           LEFT JOIN rundown_items ri ON ri.article_id = a.id AND ri.show = ?1
+                           WHERE r.saved_at > COALESCE(?2, '')
+                           ORDER BY ri.position IS NULL, ri.position, r.saved_at, r.id"#,
+                    )?;
+                    let rows = stmt.query_map(params![show, since], |row| {
+                        let tags: Option<String> = row.get(4)?;
+                        let saved_at: String = row.get(5)?;
+                        let lede: Option<String> = row.get(6)?;
+                        let summary: Option<String> = row.get(7)?;
+                        Ok((
+                            tags,
+                            row.get::<_, i64>(9)?,
+                            row.get::<_, bool>(10)?,
+                            RundownItem {
+                                article_id: row.get(0)?,
+                                title: row.get(1)?,
+                                url: row.get(2)?,
+                                feed_title: row.get(3)?,
+                                lede: lede.or_else(|| summary.as_deref().and_then(first_paragraph)),
+                                note: row.get(8)?,
+                                saved_at: parse_datetime(&saved_at).unwrap_or_else(Utc::now),
+                            },
+                        ))
+                    })?;
+                    let mut items = Vec::new();
+                    for row in rows {
+                        items.push(row?);
+                    }
+                    Ok((since, items))
+                }
+            })
+            .await?;
+
+        let rows: Vec<_> = rows
+            .into_iter()
+            .filter(|(tags, ..)| {
+                let tags: Vec<String> = tags
+                    .as_deref()
+                    .and_then(|json| serde_json::from_str(json).ok())
+                    .unwrap_or_default();
+                tags.iter().any(|tag| tag.eq_ignore_ascii_case(&show))
+            })
+            .map(|(_, raindrop_id, in_rundown, item)| (raindrop_id, in_rundown, item))
+            .collect();
+
+        // Bookmarking a cluster saves every source under one raindrop. Keep one article
+        // per bookmark: the one the running order or a note is attached to, else the
+        // first saved. It takes a lede from another source if it has none.
+        let mut kept: HashMap<i64, usize> = HashMap::new();
+        let mut ledes: HashMap<i64, String> = HashMap::new();
+        for (i, (raindrop_id, in_rundown, item)) in rows.iter().enumerate() {
+            let first = *kept.entry(*raindrop_id).or_insert(i);
+            if *in_rundown && !rows[first].1 {
+                kept.insert(*raindrop_id, i);
+            }
+            if let Some(lede) = &item.lede {
+                ledes.entry(*raindrop_id).or_insert_with(|| lede.clone());
+            }
+        }
+        let items = rows
+            .into_iter()
+            .enumerate()
+            .filter(|(i, (raindrop_id, ..))| kept[raindrop_id] == *i)
+            .map(|(_, (raindrop_id, _, mut item))| {
+                if item.lede.is_none() {
+                    item.lede = ledes.remove(&raindrop_id);
+                }
+                item
+            })
+            .collect();
+        Ok(Rundown {
+            show,
+            since: since.as_deref().and_then(parse_datetime),
+            items,
+        })
+    }
+
+    /// Store the running order of a show's rundown
+    pub async fn set_rundown_order(&self, show: &str, article_ids: Vec<i64>) -> Result<()> {
+        let show = show.to_string();
+        self.conn
+            .call(move |conn| {
+                let tx = conn.transaction()?;
+                {
+                    let mut stmt = tx.prepare(
+                        r#"INSERT INTO rundown_items (show, article_id, position) VALUES (?1, ?2, ?3)
+                           ON CONFLICT(show, article_id) DO UPDATE SET position = excluded.position"#,
+                    )?;
+                    for (position, article_id) in article_ids.iter().enumerate() {
+                        stmt.execute(params![show, article_id, position as i64])?;
+                    }
+                }
+                tx.commit()?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Set or clear (with None) the note on an article in a show's rundown
+    pub async fn set_rundown_note(
+        &self,
+        show: &str,
+        article_id: i64,
+        note: Option<String>,
+    ) -> Result<()> {
+        let show = show.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    r#"INSERT INTO rundown_items (show, article_id, note) VALUES (?1, ?2, ?3)
+                       ON CONFLICT(show, article_id) DO UPDATE SET note = excluded.note"#,
+                    params![show, article_id, note],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Mark the show as aired: its rundown starts over with the next bookmark
+    pub async fn finish_rundown(&self, show: &str) -> Result<()> {
+        let show = show.to_string();
+        self.conn
+            .call(move |conn| {
+                conn.execute(
+                    r#"INSERT INTO shows (tag, last_show_at) VALUES (?1, datetime('now'))
+                       ON CONFLICT(tag) DO UPDATE SET last_show_at = excluded.last_show_at"#,
+                    params![show],
+                )?;
+                conn.execute("DELETE FROM rundown_items WHERE show = ?1", params![show])?;
+                // Deleted articles kept for this show's rundown can go now (their
+                // bookmarks and summaries cascade)
+                conn.execute(
+                    r#"DELETE FROM articles
+                       WHERE deleted = 1
+                         AND id IN (SELECT article_id FROM saved_to_raindrop
+                                    WHERE EXISTS (SELECT 1 FROM json_each(tags)
+                                                  WHERE value = ?1 COLLATE NOCASE))"#,
+                    params![show],
+                )?;
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+/// The first paragraph of a plain-text summary, on one line
+fn first_paragraph(text: &str) -> Option<String> {
+    text.split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+        .find(|paragraph| !paragraph.is_empty())
+}
+
+fn parse_datetime(s: &str) -> Option<DateTime<Utc>> {
+    // Try RFC3339 first (e.g., "2026-01-11T12:34:56+00:00")
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Try SQLite datetime format (e.g., "2026-01-11 12:34:56")
+    if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+        return Some(naive.and_utc());
     }
     None
 }
@@ -372,6 +1114,7 @@ fn feed_from_row(row: &Row) -> rusqlite::Result<Feed> {
             .ok()
             .and_then(|s| parse_datetime(&s))
             .unwrap_or_else(Utc::now),
+        folder: row.get(8)?,
     })
 }
 
@@ -394,6 +1137,57 @@ fn article_from_row(row: &Row) -> rusqlite::Result<Article> {
             .and_then(|s| parse_datetime(&s))
             .unwrap_or_else(Utc::now),
         feed_title: row.get(10)?,
+        cluster_id: row.get(11)?,
+    })
+}
+
+/// Delete articles older than `days` with their summaries, bookmarks, chats and rundown
+/// entries; returns the number of articles deleted
+fn delete_old_article_rows(conn: &rusqlite::Connection, days: i64) -> rusqlite::Result<usize> {
+    for table in [
+        "summaries",
+        "saved_to_raindrop",
+        "chat_messages",
+        "rundown_items",
+    ] {
+        conn.execute(
+            &format!(
+                "DELETE FROM {} WHERE article_id IN ({})",
+                table, OLD_ARTICLES
+            ),
+            params![days],
+        )?;
+    }
+    conn.execute(
+        &format!("DELETE FROM articles WHERE id IN ({})", OLD_ARTICLES),
+        params![days],
+    )
+}
+
+fn blocked_article_from_row(row: &Row) -> rusqlite::Result<BlockedArticle> {
+    Ok(BlockedArticle {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        url: row.get(2)?,
+        published_at: row
+            .get::<_, Option<String>>(3)?
+            .and_then(|s| parse_datetime(&s)),
+        filtered_at: row
+            .get::<_, String>(4)
+            .ok()
+            .and_then(|s| parse_datetime(&s))
+            .unwrap_or_else(Utc::now),
+        rule: row.get(5)?,
+        feed_title: row.get(6)?,
+    })
+}
+
+fn usage_totals_from_row(row: &Row) -> rusqlite::Result<UsageTotals> {
+    Ok(UsageTotals {
+        requests: row.get::<_, i64>(0)? as u64,
+        input_tokens: row.get::<_, i64>(1)? as u64,
+        output_tokens: row.get::<_, i64>(2)? as u64,
+        cost_usd: row.get(3)?,
     })
 }
 
@@ -408,11 +1202,96 @@ fn summary_from_row(row: &Row) -> rusqlite::Result<Summary> {
             .ok()
             .and_then(|s| parse_datetime(&s))
             .unwrap_or_else(Utc::now),
+        template: row.get(5)?,
+        unverified_quotes: row
+            .get::<_, Option<String>>(6)?
+            .and_then(|json| serde_json::from_str(&json).ok()),
+        fields: summary_fields_from_row(row)?,
+        reused_from: row.get(15)?,
     })
 }
 
+/// The structured parts of a summary row (columns 7 to 14); None for plain-text summaries
+fn summary_fields_from_row(row: &Row) -> rusqlite::Result<Option<SummaryFields>> {
+    let Some(lede) = row.get::<_, Option<String>>(8)? else {
+        return Ok(None);
+    };
+    Ok(Some(SummaryFields {
+        kind: row
+            .get::<_, Option<String>>(7)?
+            .and_then(|kind| SummaryKind::parse(&kind))
+            .unwrap_or_default(),
+        lede,
+        why_it_matters: row.get(9)?,
+        price: row.get(10)?,
+        availability: row.get(11)?,
+        platforms: row.get(12)?,
+        quote: row.get(13)?,
+        speaker: row.get(14)?,
+    }))
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+
+    /// A feed for test articles, named `title`
+    pub(crate) async fn insert_feed(repo: &Repository, title: &str) -> i64 {
+        repo.insert_feed(NewFeed {
+            title: title.into(),
+            url: format!(
+                "https://example.com/{}/rss",
+                title.to_lowercase().replace(' ', "-")
+            ),
+            site_url: None,
+            description: None,
+            folder: None,
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Article `n` of a feed: guid "guid-n", linked at https://example.com/n, no content
+    pub(crate) fn new_article(feed_id: i64, n: usize, title: &str) -> NewArticle {
+        NewArticle {
+            feed_id,
+            guid: format!("guid-{}", n),
+            title: title.into(),
+            url: format!("https://example.com/{}", n),
+            author: None,
+            content: None,
+            content_text: None,
+            published_at: None,
+        }
+    }
+
+    /// A feed named "Feed" with one article per title; returns the feed id and article ids
+    pub(crate) async fn insert_feed_with_articles(
+        repo: &Repository,
+        titles: &[&str],
+    ) -> (i64, Vec<i64>) {
+        let feed_id = insert_feed(repo, "Feed").await;
+        let mut ids = Vec::new();
+        for (n, title) in titles.iter().enumerate() {
+            ids.push(
+                repo.upsert_article(new_article(feed_id, n, title))
+                    .await
+                    .unwrap(),
+            );
+        }
+        (feed_id, ids)
+    }
+
+    /// A feed named "Feed" with a single article; returns the feed id and article id
+    pub(crate) async fn insert_feed_with_article(repo: &Repository, title: &str) -> (i64, i64) {
+        let (feed_id, ids) = insert_feed_with_articles(repo, &[title]).await;
+        (feed_id, ids[0])
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::test_support::*;
     use super::*;
     use chrono::Duration;
     use tempfile::TempDir;
@@ -434,29 +1313,34 @@ mod tests {
         }
     }
 
+    fn new_summary(article_id: i64, template: &str, automatic: bool) -> NewSummary {
+        NewSummary {
+            article_id,
+            content: "summary".into(),
+            model_version: "claude-test".into(),
+            template: template.into(),
+            automatic,
+            input_tokens: None,
+            output_tokens: None,
+            cost_usd: None,
+            unverified_quotes: Vec::new(),
+            fields: None,
+            content_hash: None,
+            canonical_url: None,
+        }
+    }
+
     #[tokio::test]
     async fn insert_and_read_article_with_summary() {
         let test = test_repo().await;
         let repo = &test.repo;
-        let feed_id = repo
-            .insert_feed(NewFeed {
-                title: "Feed".into(),
-                url: "https://example.com/rss".into(),
-                site_url: Some("https://example.com".into()),
-                description: Some("Example feed".into()),
-            })
-            .await
-            .unwrap();
-
+        let feed_id = insert_feed(repo, "Feed").await;
         repo.upsert_article(NewArticle {
-            feed_id,
-            guid: "guid-1".into(),
-            title: "Hello".into(),
-            url: "https://example.com/post".into(),
             author: Some("leo".into()),
             content: Some("<p>Hello</p>".into()),
             content_text: Some("Hello".into()),
             published_at: Some(Utc::now()),
+            ..new_article(feed_id, 0, "Hello")
         })
         .await
         .unwrap();
@@ -466,86 +1350,286 @@ mod tests {
         assert_eq!(articles[0].title, "Hello");
         assert_eq!(articles[0].feed_title.as_deref(), Some("Feed"));
 
-        repo.save_summary(articles[0].id, "summary".into(), "claude-test".into())
+        repo.save_summary(new_summary(articles[0].id, "nutgraph", false))
             .await
             .unwrap();
         let summary = repo.get_summary(articles[0].id).await.unwrap().unwrap();
         assert_eq!(summary.content, "summary");
+        assert_eq!(summary.unverified_quotes, Some(Vec::new()));
+
+        repo.save_summary(NewSummary {
+            unverified_quotes: vec!["We made the best phone ever".into()],
+            ..new_summary(articles[0].id, "nutgraph", false)
+        })
+        .await
+        .unwrap();
+        let summary = repo.get_summary(articles[0].id).await.unwrap().unwrap();
+        assert_eq!(
+            summary.unverified_quotes,
+            Some(vec!["We made the best phone ever".to_string()])
+        );
+        assert_eq!(summary.fields, None);
+
+        let fields = SummaryFields {
+            kind: SummaryKind::Product,
+            lede: "A phone.".into(),
+            price: Some("$799".into()),
+            quote: Some("It's our best phone".into()),
+            speaker: Some("Tim Cook".into()),
+            ..Default::default()
+        };
+        repo.save_summary(NewSummary {
+            fields: Some(fields.clone()),
+            ..new_summary(articles[0].id, "nutgraph", false)
+        })
+        .await
+        .unwrap();
+        let summary = repo.get_summary(articles[0].id).await.unwrap().unwrap();
+        assert_eq!(summary.fields, Some(fields));
     }
 
     #[tokio::test]
     async fn deleted_article_is_not_reinserted() {
         let test = test_repo().await;
         let repo = &test.repo;
-        let feed_id = repo
-            .insert_feed(NewFeed {
-                title: "Feed".into(),
-                url: "https://example.com/rss".into(),
-                site_url: None,
-                description: None,
-            })
+        let (feed_id, inserted) = insert_feed_with_article(repo, "First").await;
+        repo.delete_articles(vec![inserted]).await.unwrap();
+
+        let skipped = repo
+            .upsert_article(new_article(feed_id, 0, "Re-added"))
             .await
             .unwrap();
+        assert_eq!(skipped, 0);
+        assert!(repo.get_all_articles_sorted().await.unwrap().is_empty());
+    }
 
-        let inserted = repo
-            .upsert_article(NewArticle {
-                feed_id,
-                guid: "guid-1".into(),
-                title: "First".into(),
-                url: "https://example.com/1".into(),
-                author: None,
-                content: None,
-                content_text: None,
-                published_at: None,
-            })
+    #[tokio::test]
+    async fn rundown_order_notes_and_finish() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let feed_id = insert_feed(repo, "The Verge").await;
+        let month_ago = Utc::now() - Duration::days(30);
+        let mut ids = Vec::new();
+        for n in 0..4 {
+            let id = repo
+                .upsert_article(NewArticle {
+                    published_at: Some(month_ago),
+                    ..new_article(feed_id, n, &format!("Article {}", n))
+                })
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+        repo.mark_saved_to_raindrop(ids[0], 10, vec!["TWiT".into(), "apple".into()])
+            .await
+            .unwrap();
+        repo.mark_saved_to_raindrop(ids[1], 11, vec!["mbw".into()])
+            .await
+            .unwrap();
+        repo.mark_saved_to_raindrop(ids[2], 12, vec!["twit".into()])
             .await
             .unwrap();
-        repo.delete_article(inserted).await.unwrap();
+        repo.save_summary(NewSummary {
+            content: "First paragraph\nof it.\n\nSecond paragraph.".into(),
+            ..new_summary(ids[2], "nutgraph", false)
+        })
+        .await
+        .unwrap();
 
-        let skipped = repo
-            .upsert_article(NewArticle {
-                feed_id,
-                guid: "guid-1".into(),
-                title: "Re-added".into(),
-                url: "https://example.com/1".into(),
-                author: None,
-                content: None,
-                content_text: None,
-                published_at: None,
-            })
+        // Old articles bookmarked recently survive the retention cleanup
+        assert_eq!(repo.delete_old_articles(7).await.unwrap(), 1);
+
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        assert_eq!(rundown.since, None);
+        let order: Vec<i64> = rundown.items.iter().map(|i| i.article_id).collect();
+        assert_eq!(order, vec![ids[0], ids[2]]);
+        assert_eq!(rundown.items[0].feed_title.as_deref(), Some("The Verge"));
+        assert_eq!(rundown.items[0].lede, None);
+        assert_eq!(
+            rundown.items[1].lede.as_deref(),
+            Some("First paragraph of it.")
+        );
+
+        repo.set_rundown_order("twit", vec![ids[2], ids[0]])
             .await
             .unwrap();
-        assert_eq!(skipped, 0);
+        repo.set_rundown_note("twit", ids[0], Some("Ask Leo".into()))
+            .await
+            .unwrap();
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        let order: Vec<i64> = rundown.items.iter().map(|i| i.article_id).collect();
+        assert_eq!(order, vec![ids[2], ids[0]]);
+        assert_eq!(rundown.items[1].note.as_deref(), Some("Ask Leo"));
+        // Other shows keep their own rundown
+        assert_eq!(repo.get_rundown("mbw").await.unwrap().items.len(), 1);
+
+        repo.finish_rundown("twit").await.unwrap();
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        assert!(rundown.since.is_some());
+        assert!(rundown.items.is_empty());
+        assert_eq!(repo.get_rundown("mbw").await.unwrap().items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rundown_lists_a_bookmarked_cluster_once() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let mut ids = Vec::new();
+        for (n, feed) in ["The Verge", "MacRumors", "9to5Mac"]
+            .into_iter()
+            .enumerate()
+        {
+            let feed_id = insert_feed(repo, feed).await;
+            let title = format!("Apple announces the iPhone 17 ({})", feed);
+            let id = repo
+                .upsert_article(new_article(feed_id, n, &title))
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+
+        // Two sources of one cluster, bookmarked together, then another story
+        for &id in &ids[..2] {
+            repo.mark_saved_to_raindrop(id, 10, vec!["twit".into()])
+                .await
+                .unwrap();
+        }
+        repo.mark_saved_to_raindrop(ids[2], 11, vec!["twit".into()])
+            .await
+            .unwrap();
+        repo.save_summary(NewSummary {
+            content: "Summary of the second source.".into(),
+            ..new_summary(ids[1], "nutgraph", false)
+        })
+        .await
+        .unwrap();
+
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        let order: Vec<i64> = rundown.items.iter().map(|i| i.article_id).collect();
+        assert_eq!(order, vec![ids[0], ids[2]]);
+        assert_eq!(
+            rundown.items[0].lede.as_deref(),
+            Some("Summary of the second source.")
+        );
+
+        // Order and notes stay with the article they were set on
+        repo.set_rundown_note("twit", ids[1], Some("Lead story".into()))
+            .await
+            .unwrap();
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        let order: Vec<i64> = rundown.items.iter().map(|i| i.article_id).collect();
+        assert_eq!(order, vec![ids[1], ids[2]]);
+        assert_eq!(rundown.items[0].note.as_deref(), Some("Lead story"));
+
+        repo.set_rundown_order("twit", vec![ids[2], ids[1]])
+            .await
+            .unwrap();
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        let order: Vec<i64> = rundown.items.iter().map(|i| i.article_id).collect();
+        assert_eq!(order, vec![ids[2], ids[1]]);
+    }
+
+    #[tokio::test]
+    async fn rundown_keeps_a_bookmarked_article_after_delete() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (feed_id, ids) =
+            insert_feed_with_articles(repo, &["Apple announces the iPhone 17", "Unsaved"]).await;
+        repo.save_summary(NewSummary {
+            content: "Apple showed its new phones.".into(),
+            ..new_summary(ids[0], "nutgraph", false)
+        })
+        .await
+        .unwrap();
+        repo.mark_saved_to_raindrop(ids[0], 10, vec!["twit".into()])
+            .await
+            .unwrap();
+        repo.set_rundown_note("twit", ids[0], Some("Lead story".into()))
+            .await
+            .unwrap();
+
+        repo.delete_articles(ids.clone()).await.unwrap();
         assert!(repo.get_all_articles_sorted().await.unwrap().is_empty());
+
+        let rundown = repo.get_rundown("twit").await.unwrap();
+        assert_eq!(rundown.items.len(), 1);
+        let item = &rundown.items[0];
+        assert_eq!(item.article_id, ids[0]);
+        assert_eq!(item.title, "Apple announces the iPhone 17");
+        assert_eq!(item.lede.as_deref(), Some("Apple showed its new phones."));
+        assert_eq!(item.note.as_deref(), Some("Lead story"));
+
+        // A refresh doesn't bring it back, but undo does
+        let readded = repo
+            .upsert_article(new_article(feed_id, 0, "Apple announces the iPhone 17"))
+            .await
+            .unwrap();
+        assert_eq!(readded, 0);
+        repo.undelete_article(feed_id, "guid-0").await.unwrap();
+        assert_eq!(repo.get_all_articles_sorted().await.unwrap().len(), 1);
+        repo.delete_articles(vec![ids[0]]).await.unwrap();
+
+        // Finishing the show removes it for good
+        repo.finish_rundown("twit").await.unwrap();
+        assert!(repo.get_rundown("twit").await.unwrap().items.is_empty());
+        assert!(!repo.is_saved_to_raindrop(ids[0]).await.unwrap());
     }
 
     #[tokio::test]
-    async fn invalid_datetime_in_row_falls_back_to_now() {
+    async fn raindrop_tags_most_used_first() {
         let test = test_repo().await;
         let repo = &test.repo;
-        let feed_id = repo
-            .insert_feed(NewFeed {
-                title: "Feed".into(),
-                url: "https://example.com/rss".into(),
-                site_url: None,
-                description: None,
-            })
+        assert!(repo.get_raindrop_tags().await.unwrap().is_empty());
+
+        let (_, ids) = insert_feed_with_articles(repo, &["One", "Two", "Three"]).await;
+
+        repo.mark_saved_to_raindrop(ids[0], 10, vec!["twit".into(), "apple".into()])
+            .await
+            .unwrap();
+        repo.mark_saved_to_raindrop(ids[1], 11, vec!["apple".into()])
+            .await
+            .unwrap();
+        repo.mark_saved_to_raindrop(ids[2], 12, vec!["ai".into()])
             .await
             .unwrap();
+        assert_eq!(
+            repo.get_raindrop_tags().await.unwrap(),
+            vec!["apple", "ai", "twit"]
+        );
+    }
 
-        let article_id = repo
-            .upsert_article(NewArticle {
-                feed_id,
-                guid: "guid-2".into(),
-                title: "Date test".into(),
-                url: "https://example.com/date".into(),
-                author: None,
-                content: None,
-                content_text: None,
-                published_at: None,
-            })
+    #[tokio::test]
+    async fn chat_is_kept_in_order_and_deleted_with_article() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (_, article_id) = insert_feed_with_article(repo, "Phone").await;
+
+        let question = ChatMessage::user("What's the price in Europe?");
+        let answer = ChatMessage::assistant("The article doesn't say.");
+        repo.add_chat_message(article_id, question.clone())
             .await
             .unwrap();
+        repo.add_chat_message(article_id, answer.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.get_chat(article_id).await.unwrap(),
+            vec![question.clone(), answer]
+        );
+
+        repo.clear_chat(article_id).await.unwrap();
+        assert!(repo.get_chat(article_id).await.unwrap().is_empty());
+
+        repo.add_chat_message(article_id, question).await.unwrap();
+        repo.delete_articles(vec![article_id]).await.unwrap();
+        assert!(repo.get_chat(article_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn invalid_datetime_in_row_falls_back_to_now() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (_, article_id) = insert_feed_with_article(repo, "Date test").await;
 
         repo.conn
             .call(move |conn| {
@@ -561,4 +1645,366 @@ mod tests {
         let article = repo.get_all_articles_sorted().await.unwrap().remove(0);
         assert!(article.fetched_at > Utc::now() - Duration::minutes(1));
     }
+
+    #[tokio::test]
+    async fn legacy_database_gains_folder_column() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let db_path = tmpdir.path().join("legacy.db");
+
+        // A feeds table from before the folder column existed
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE feeds (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                title TEXT NOT NULL,
+                url TEXT NOT NULL UNIQUE,
+                site_url TEXT,
+                description TEXT,
+                last_fetched TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            INSERT INTO feeds (title, url) VALUES ('Old', 'https://old.example.com/rss');",
+        )
+        .unwrap();
+        drop(conn);
+
+        let repo = Repository::new(db_path.to_string_lossy().as_ref())
+            .await
+            .unwrap();
+        repo.update_feed_folder("https://old.example.com/rss", Some("Tech".into()))
+            .await
+            .unwrap();
+
+        let feeds = repo.get_all_feeds().await.unwrap();
+        assert_eq!(feeds.len(), 1);
+        assert_eq!(feeds[0].folder.as_deref(), Some("Tech"));
+    }
+
+    #[tokio::test]
+    async fn blocked_articles_are_hidden_until_rescued() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let feed_id = insert_feed(repo, "Feed").await;
+
+        let entry = |guid: &str, title: &str| NewArticle {
+            feed_id,
+            guid: guid.into(),
+            title: title.into(),
+            url: format!("https://example.com/{}", guid),
+            author: None,
+            content: Some("<p>Body</p>".into()),
+            content_text: Some("Body".into()),
+            published_at: Some(Utc::now()),
+        };
+
+        repo.upsert_blocked_article(entry("a", "Crypto news"), "crypto".into())
+            .await
+            .unwrap();
+        repo.upsert_blocked_article(entry("b", "More crypto"), "crypto".into())
+            .await
+            .unwrap();
+        repo.upsert_blocked_article(entry("c", "NFT drop"), "/nfts?/".into())
+            .await
+            .unwrap();
+        // Seeing the same entry again on the next refresh doesn't double count
+        repo.upsert_blocked_article(entry("a", "Crypto news"), "crypto".into())
+            .await
+            .unwrap();
+
+        let blocked = repo.get_blocked_articles().await.unwrap();
+        assert_eq!(blocked.len(), 3);
+        assert_eq!(blocked[0].feed_title.as_deref(), Some("Feed"));
+        assert!(repo.get_all_articles_sorted().await.unwrap().is_empty());
+
+        let rescued = blocked
+            .iter()
+            .find(|b| b.title == "Crypto news")
+            .unwrap()
+            .id;
+        repo.rescue_blocked_article(rescued).await.unwrap();
+
+        let articles = repo.get_all_articles_sorted().await.unwrap();
+        assert_eq!(articles.len(), 1);
+        assert_eq!(articles[0].id, rescued);
+        assert_eq!(articles[0].content_text.as_deref(), Some("Body"));
+        assert_eq!(repo.get_blocked_articles().await.unwrap().len(), 2);
+
+        // A later refresh that still matches the rule leaves the rescued article visible
+        repo.upsert_blocked_article(entry("a", "Crypto news"), "crypto".into())
+            .await
+            .unwrap();
+        assert_eq!(repo.get_all_articles_sorted().await.unwrap().len(), 1);
+
+        let mut counts = repo.get_blocked_rule_counts().await.unwrap();
+        counts.sort();
+        assert_eq!(
+            counts,
+            vec![("/nfts?/".to_string(), 1, 0), ("crypto".to_string(), 2, 1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn set_blocked_rules_hides_and_unhides() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (_, id) = insert_feed_with_article(repo, "Stored before the rule existed").await;
+        repo.save_summary(new_summary(id, "brief", false))
+            .await
+            .unwrap();
+
+        repo.set_blocked_rules(vec![(id, Some("rule".into()))])
+            .await
+            .unwrap();
+        assert!(repo.get_all_articles_sorted().await.unwrap().is_empty());
+        let stored = repo.get_articles_for_blocklist().await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].1.as_deref(), Some("rule"));
+
+        repo.set_blocked_rules(vec![(id, None)]).await.unwrap();
+        let articles = repo.get_all_articles_sorted().await.unwrap();
+        assert_eq!(articles.len(), 1);
+        // Hiding doesn't throw away the summary
+        let summary = repo.get_summary(id).await.unwrap().unwrap();
+        assert_eq!(summary.template.as_deref(), Some("brief"));
+    }
+
+    #[tokio::test]
+    async fn article_clusters_persist() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (feed_id, ids) =
+            insert_feed_with_articles(repo, &["Same story", "Same story", "Same story"]).await;
+
+        repo.set_article_clusters(vec![(ids[0], Some(ids[0])), (ids[1], Some(ids[0]))])
+            .await
+            .unwrap();
+        let mut articles = repo.get_all_articles_sorted().await.unwrap();
+        articles.sort_by_key(|a| a.id);
+        let clusters: Vec<Option<i64>> = articles.iter().map(|a| a.cluster_id).collect();
+        assert_eq!(clusters, vec![Some(ids[0]), Some(ids[0]), None]);
+
+        // Deleting a cluster deletes every source and keeps them from coming back
+        repo.delete_articles(vec![ids[0], ids[1]]).await.unwrap();
+        assert_eq!(repo.get_all_articles_sorted().await.unwrap().len(), 1);
+        let readded = repo
+            .upsert_article(new_article(feed_id, 0, "Same story"))
+            .await
+            .unwrap();
+        assert_eq!(readded, 0);
+    }
+
+    #[tokio::test]
+    async fn unsummarized_articles_and_automatic_count() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (_, ids) = insert_feed_with_articles(repo, &["Read", "Summarized", "New"]).await;
+        repo.mark_articles_read(vec![ids[0]]).await.unwrap();
+        repo.save_summary(new_summary(ids[1], "nutgraph", true))
+            .await
+            .unwrap();
+
+        let hour_ago = Utc::now() - chrono::Duration::hours(1);
+        let pending = repo.get_unsummarized_articles(hour_ago).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, ids[2]);
+        // Articles already stored before the refresh started are left alone
+        let in_an_hour = Utc::now() + chrono::Duration::hours(1);
+        assert!(repo
+            .get_unsummarized_articles(in_an_hour)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(
+            repo.count_automatic_summaries_since(hour_ago)
+                .await
+                .unwrap(),
+            1
+        );
+        // Summaries asked for by hand don't count
+        repo.save_summary(new_summary(ids[2], "nutgraph", false))
+            .await
+            .unwrap();
+        assert_eq!(
+            repo.count_automatic_summaries_since(hour_ago)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            repo.count_automatic_summaries_since(in_an_hour)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn reuse_summary_by_url_or_content_hash() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let feed_id = insert_feed(repo, "The Verge").await;
+        let mut ids = Vec::new();
+        for n in 0..4 {
+            let id = repo
+                .upsert_article(new_article(feed_id, n, &format!("Article {}", n)))
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+        repo.save_summary(NewSummary {
+            content_hash: Some("abc".into()),
+            canonical_url: Some("example.com/story".into()),
+            ..new_summary(ids[0], "nutgraph", false)
+        })
+        .await
+        .unwrap();
+
+        // Nothing matches: a different template, or neither URL nor text
+        let reuse = |id: i64, template: &str, url: &str, hash: Option<&str>| {
+            repo.reuse_summary(
+                id,
+                template.into(),
+                url.into(),
+                hash.map(String::from),
+                true,
+            )
+        };
+        assert!(!reuse(ids[1], "brief", "example.com/story", None)
+            .await
+            .unwrap());
+        assert!(!reuse(ids[1], "nutgraph", "example.com/other", Some("xyz"))
+            .await
+            .unwrap());
+        assert!(repo.get_summary(ids[1]).await.unwrap().is_none());
+
+        assert!(reuse(ids[1], "nutgraph", "example.com/story", None)
+            .await
+            .unwrap());
+        assert!(reuse(ids[2], "nutgraph", "example.com/other", Some("abc"))
+            .await
+            .unwrap());
+        let copy = repo.get_summary(ids[2]).await.unwrap().unwrap();
+        assert_eq!(copy.content, "summary");
+        assert_eq!(copy.reused_from.as_deref(), Some("The Verge"));
+        assert!(repo
+            .get_summary(ids[0])
+            .await
+            .unwrap()
+            .unwrap()
+            .reused_from
+            .is_none());
+
+        // Copies are free: no usage and no room taken from the daily cap
+        let hour_ago = Utc::now() - chrono::Duration::hours(1);
+        assert_eq!(
+            repo.count_automatic_summaries_since(hour_ago)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(repo.get_usage_since(hour_ago).await.unwrap().requests, 1);
+    }
+
+    #[tokio::test]
+    async fn articles_since_by_window_read_state_and_folder() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let mut feeds = Vec::new();
+        for (n, folder) in [Some("Tech"), None].into_iter().enumerate() {
+            let id = repo
+                .insert_feed(NewFeed {
+                    title: format!("Feed {}", n),
+                    url: format!("https://example.com/feed{}.xml", n),
+                    site_url: None,
+                    description: None,
+                    folder: folder.map(String::from),
+                })
+                .await
+                .unwrap();
+            feeds.push(id);
+        }
+
+        let now = Utc::now();
+        let mut ids = Vec::new();
+        for (n, (feed_id, published_at)) in [
+            (feeds[0], Some(now - Duration::hours(2))),
+            (feeds[1], Some(now - Duration::hours(3))),
+            (feeds[0], Some(now - Duration::days(2))),
+            // No date: fetched just now
+            (feeds[0], None),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let id = repo
+                .upsert_article(NewArticle {
+                    published_at,
+                    ..new_article(feed_id, n, &format!("Article {}", n))
+                })
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+        repo.mark_articles_read(vec![ids[0]]).await.unwrap();
+
+        let since = now - Duration::hours(24);
+        let ids_of = |articles: Vec<Article>| articles.iter().map(|a| a.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids_of(repo.get_articles_since(since, false, None).await.unwrap()),
+            vec![ids[0], ids[1], ids[3]]
+        );
+        assert_eq!(
+            ids_of(repo.get_articles_since(since, true, None).await.unwrap()),
+            vec![ids[1], ids[3]]
+        );
+        assert_eq!(
+            ids_of(
+                repo.get_articles_since(since, false, Some("tech".into()))
+                    .await
+                    .unwrap()
+            ),
+            vec![ids[0], ids[3]]
+        );
+    }
+
+    #[tokio::test]
+    async fn usage_totals_outlive_deleted_articles() {
+        let test = test_repo().await;
+        let repo = &test.repo;
+        let (_, id) = insert_feed_with_article(repo, "Article").await;
+
+        let priced = NewSummary {
+            input_tokens: Some(2000),
+            output_tokens: Some(300),
+            cost_usd: Some(0.0035),
+            ..new_summary(id, "nutgraph", false)
+        };
+        repo.save_summary(priced.clone()).await.unwrap();
+        // Regenerating replaces the summary but both requests cost money
+        repo.save_summary(priced).await.unwrap();
+        repo.delete_articles(vec![id]).await.unwrap();
+
+        let since = Utc::now() - Duration::days(1);
+        let totals = repo.get_usage_since(since).await.unwrap();
+        assert_eq!(totals.requests, 2);
+        assert_eq!(totals.input_tokens, 4000);
+        assert_eq!(totals.output_tokens, 600);
+        assert!((totals.cost_usd - 0.007).abs() < 1e-9);
+
+        let days = repo.get_daily_usage(since).await.unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(
+            days[0].0,
+            chrono::Local::now().format("%Y-%m-%d").to_string()
+        );
+        assert_eq!(days[0].1, totals);
+
+        let later = Utc::now() + Duration::hours(1);
+        assert_eq!(
+            repo.get_usage_since(later).await.unwrap(),
+            UsageTotals::default()
+        );
+    }
 }
@@ -6,6 +6,7 @@ CREATE TABLE IF NOT EXISTS feeds (
     url TEXT NOT NULL UNIQUE,
     site_url TEXT,
     description TEXT,
+    folder TEXT,
     last_fetched TEXT,
     created_at TEXT NOT NULL DEFAULT (datetime('now')),
     updated_at TEXT NOT NULL DEFAULT (datetime('now'))
@@ -27,6 +28,11 @@ CREATE TABLE IF NOT EXISTS articles (
     fetched_at TEXT NOT NULL DEFAULT (datetime('now')),
     is_read INTEGER NOT NULL DEFAULT 0,
     is_starred INTEGER NOT NULL DEFAULT 0,
+    filtered_rule TEXT,
+    filtered_at TEXT,
+    rescued INTEGER NOT NULL DEFAULT 0,
+    cluster_id INTEGER,
+    deleted INTEGER NOT NULL DEFAULT 0,
     UNIQUE(feed_id, guid)
 );
 
@@ -40,11 +46,54 @@ CREATE TABLE IF NOT EXISTS summaries (
     article_id INTEGER NOT NULL UNIQUE REFERENCES articles(id) ON DELETE CASCADE,
     content TEXT NOT NULL,
     model_version TEXT NOT NULL,
-    generated_at TEXT NOT NULL DEFAULT (datetime('now'))
+    generated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    template TEXT,
+    automatic INTEGER NOT NULL DEFAULT 0,
+    input_tokens INTEGER,
+    output_tokens INTEGER,
+    cost_usd REAL,
+    unverified_quotes TEXT,
+    summary_type TEXT,
+    lede TEXT,
+    why_it_matters TEXT,
+    price TEXT,
+    availability TEXT,
+    platforms TEXT,
+    quote TEXT,
+    speaker TEXT,
+    content_hash TEXT,
+    canonical_url TEXT,
+    reused_from TEXT
 );
 
 CREATE INDEX IF NOT EXISTS idx_summaries_article_id ON summaries(article_id);
 
+-- usage_log table (one row per generated summary; kept when the article is deleted
+-- so monthly totals stay right)
+CREATE TABLE IF NOT EXISTS usage_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    article_id INTEGER,
+    model TEXT NOT NULL,
+    input_tokens INTEGER NOT NULL DEFAULT 0,
+    output_tokens INTEGER NOT NULL DEFAULT 0,
+    cost_usd REAL,
+    automatic INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_usage_log_created_at ON usage_log(created_at);
+
+-- chat_messages table (follow-up questions about an article and their answers)
+CREATE TABLE IF NOT EXISTS chat_messages (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    article_id INTEGER NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    role TEXT NOT NULL,
+    content TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_chat_messages_article_id ON chat_messages(article_id);
+
 -- saved_to_raindrop table
 CREATE TABLE IF NOT EXISTS saved_to_raindrop (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -54,6 +103,21 @@ CREATE TABLE IF NOT EXISTS saved_to_raindrop (
     saved_at TEXT NOT NULL DEFAULT (datetime('now'))
 );
 
+-- shows table (when each show's rundown was last finished)
+CREATE TABLE IF NOT EXISTS shows (
+    tag TEXT PRIMARY KEY,
+    last_show_at TEXT NOT NULL
+);
+
+-- rundown_items table (running order and notes of articles bookmarked for a show)
+CREATE TABLE IF NOT EXISTS rundown_items (
+    show TEXT NOT NULL,
+    article_id INTEGER NOT NULL REFERENCES articles(id) ON DELETE CASCADE,
+    position INTEGER,
+    note TEXT,
+    PRIMARY KEY (show, article_id)
+);
+
 -- deleted_articles table (prevents re-adding deleted articles on refresh)
 CREATE TABLE IF NOT EXISTS deleted_articles (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -65,3 +129,31 @@ CREATE TABLE IF NOT EXISTS deleted_articles (
 
 CREATE INDEX IF NOT EXISTS idx_deleted_articles_feed_guid ON deleted_articles(feed_id, guid);
 "#;
+
+/// Columns added after the initial schema, applied with `ALTER TABLE` when missing
+/// so existing databases pick them up: (table, column, definition).
+pub const COLUMN_MIGRATIONS: &[(&str, &str, &str)] = &[
+    ("feeds", "folder", "TEXT"),
+    ("articles", "filtered_rule", "TEXT"),
+    ("articles", "filtered_at", "TEXT"),
+    ("articles", "rescued", "INTEGER NOT NULL DEFAULT 0"),
+    ("articles", "cluster_id", "INTEGER"),
+    ("articles", "deleted", "INTEGER NOT NULL DEFAULT 0"),
+    ("summaries", "template", "TEXT"),
+    ("summaries", "automatic", "INTEGER NOT NULL DEFAULT 0"),
+    ("summaries", "input_tokens", "INTEGER"),
+    ("summaries", "output_tokens", "INTEGER"),
+    ("summaries", "cost_usd", "REAL"),
+    ("summaries", "unverified_quotes", "TEXT"),
+    ("summaries", "summary_type", "TEXT"),
+    ("summaries", "lede", "TEXT"),
+    ("summaries", "why_it_matters", "TEXT"),
+    ("summaries", "price", "TEXT"),
+    ("summaries", "availability", "TEXT"),
+    ("summaries", "platforms", "TEXT"),
+    ("summaries", "quote", "TEXT"),
+    ("summaries", "speaker", "TEXT"),
+    ("summaries", "content_hash", "TEXT"),
+    ("summaries", "canonical_url", "TEXT"),
+    ("summaries", "reused_from", "TEXT"),
+];
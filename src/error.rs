@@ -1,3 +1,6 @@
+use std::fmt;
+use std::time::Duration;
+
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -27,7 +30,10 @@ pub enum AppError {
     Io(#[from] std::io::Error),
 
     #[error("Claude API error: {0}")]
-    ClaudeApi(String),
+    ClaudeApi(ClaudeApiError),
+
+    #[error("Summary API error: {0}")]
+    SummaryApi(String),
 
     #[error("Raindrop API error: {0}")]
     RaindropApi(String),
@@ -37,3 +43,205 @@ pub enum AppError {
 }
 
 pub type Result<T> = std::result::Result<T, AppError>;
+
+impl AppError {
+    /// Message for the user: what went wrong and what to do about it where we know
+    pub fn user_message(&self) -> String {
+        match self {
+            AppError::ClaudeApi(e) => e.advice(),
+            AppError::Http(e) if e.is_timeout() => {
+                "The request timed out. Check your connection and press 'g' to retry.".to_string()
+            }
+            AppError::Http(e) if e.is_connect() => {
+                "Couldn't reach the summarizer. Check your connection and press 'g' to retry."
+                    .to_string()
+            }
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Kind of Claude API failure, from the HTTP status and the `error.type` in the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaudeErrorKind {
+    /// Missing or invalid API key (401)
+    Authentication,
+    /// Out of credits
+    Billing,
+    /// The key may not use this model or feature (403)
+    Permission,
+    /// Unknown model (404)
+    NotFound,
+    /// Too many requests or tokens per minute (429)
+    RateLimit,
+    /// The API is overloaded (529)
+    Overloaded,
+    /// The prompt doesn't fit the model's context window (400/413)
+    ContextTooLong,
+    /// Anything else the API rejected (400)
+    InvalidRequest,
+    /// Internal API error (500 and other 5xx)
+    Server,
+}
+
+/// A failed Claude API request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaudeApiError {
+    pub kind: ClaudeErrorKind,
+    /// Message from the API (or the raw body when it wasn't JSON)
+    pub message: String,
+    /// How long the API asked us to wait (`retry-after` header)
+    pub retry_after: Option<Duration>,
+}
+
+impl ClaudeApiError {
+    /// Classify an error response. `error_type` is the body's `error.type`, if any.
+    pub fn new(
+        status: u16,
+        error_type: Option<&str>,
+        message: String,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        let lower = message.to_lowercase();
+        let kind = match (error_type, status) {
+            (Some("authentication_error"), _) | (_, 401) => ClaudeErrorKind::Authentication,
+            (Some("permission_error"), _) | (_, 403) => ClaudeErrorKind::Permission,
+            (Some("not_found_error"), _) | (_, 404) => ClaudeErrorKind::NotFound,
+            (Some("rate_limit_error"), _) | (_, 429) => ClaudeErrorKind::RateLimit,
+            (Some("overloaded_error"), _) | (_, 529) => ClaudeErrorKind::Overloaded,
+            (Some("request_too_large"), _) | (_, 413) => ClaudeErrorKind::ContextTooLong,
+            _ if lower.contains("credit balance") => ClaudeErrorKind::Billing,
+            _ if lower.contains("too long") || lower.contains("context window") => {
+                ClaudeErrorKind::ContextTooLong
+            }
+            (Some("api_error"), _) | (_, 500..=599) => ClaudeErrorKind::Server,
+            _ => ClaudeErrorKind::InvalidRequest,
+        };
+        Self {
+            kind,
+            message,
+            retry_after,
+        }
+    }
+
+    /// Worth retrying after a pause
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self.kind,
+            ClaudeErrorKind::RateLimit | ClaudeErrorKind::Overloaded | ClaudeErrorKind::Server
+        )
+    }
+
+    /// What went wrong and what to do about it, for the summary pane
+    pub fn advice(&self) -> String {
+        match self.kind {
+            ClaudeErrorKind::Authentication => {
+                "Claude rejected the API key. Check claude_api_key in \
+                 ~/.config/beatcheck/config.toml (or CLAUDE_API_KEY)."
+                    .to_string()
+            }
+            ClaudeErrorKind::Billing => "Your Anthropic credit balance is too low. Add credits at \
+                 console.anthropic.com, then press 'g' to retry."
+                .to_string(),
+            ClaudeErrorKind::Permission => "This API key can't use the configured model. Check \
+                 model under [summarizer] in config.toml."
+                .to_string(),
+            ClaudeErrorKind::NotFound => "Claude doesn't know the configured model. Check model \
+                 under [summarizer] in config.toml."
+                .to_string(),
+            ClaudeErrorKind::RateLimit => match self.retry_after {
+                Some(wait) => format!(
+                    "Rate limited by the Claude API. Press 'g' to retry in {} seconds.",
+                    wait.as_secs().max(1)
+                ),
+                None => "Rate limited by the Claude API. Wait a minute, then press 'g' to retry."
+                    .to_string(),
+            },
+            ClaudeErrorKind::Overloaded => "Claude is overloaded right now. Press 'g' to retry \
+                 in a few minutes."
+                .to_string(),
+            ClaudeErrorKind::ContextTooLong => "The article is too long for the model's context \
+                 window. Try a model with a larger context window."
+                .to_string(),
+            ClaudeErrorKind::InvalidRequest => {
+                format!("Claude rejected the request: {}", self.message)
+            }
+            ClaudeErrorKind::Server => {
+                "The Claude API had an internal error. Press 'g' to retry.".to_string()
+            }
+        }
+    }
+}
+
+impl fmt::Display for ClaudeApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_by_error_type() {
+        let cases = [
+            (401, "authentication_error", ClaudeErrorKind::Authentication),
+            (403, "permission_error", ClaudeErrorKind::Permission),
+            (404, "not_found_error", ClaudeErrorKind::NotFound),
+            (429, "rate_limit_error", ClaudeErrorKind::RateLimit),
+            (529, "overloaded_error", ClaudeErrorKind::Overloaded),
+            (413, "request_too_large", ClaudeErrorKind::ContextTooLong),
+            (500, "api_error", ClaudeErrorKind::Server),
+            (
+                400,
+                "invalid_request_error",
+                ClaudeErrorKind::InvalidRequest,
+            ),
+        ];
+        for (status, error_type, kind) in cases {
+            let e = ClaudeApiError::new(status, Some(error_type), "msg".into(), None);
+            assert_eq!(e.kind, kind, "{}", error_type);
+        }
+    }
+
+    #[test]
+    fn test_classify_invalid_requests_by_message() {
+        let e = ClaudeApiError::new(
+            400,
+            Some("invalid_request_error"),
+            "prompt is too long: 215000 tokens > 200000 maximum".into(),
+            None,
+        );
+        assert_eq!(e.kind, ClaudeErrorKind::ContextTooLong);
+
+        let e = ClaudeApiError::new(
+            400,
+            Some("invalid_request_error"),
+            "Your credit balance is too low to access the Anthropic API.".into(),
+            None,
+        );
+        assert_eq!(e.kind, ClaudeErrorKind::Billing);
+        assert!(!e.is_transient());
+    }
+
+    #[test]
+    fn test_classify_by_status_without_body() {
+        let e = ClaudeApiError::new(502, None, "Bad Gateway".into(), None);
+        assert_eq!(e.kind, ClaudeErrorKind::Server);
+        assert!(e.is_transient());
+    }
+
+    #[test]
+    fn test_advice_mentions_retry_after() {
+        let e = ClaudeApiError::new(
+            429,
+            Some("rate_limit_error"),
+            "slow down".into(),
+            Some(Duration::from_secs(20)),
+        );
+        assert!(e.is_transient());
+        assert!(e.advice().contains("20 seconds"));
+        assert_eq!(AppError::ClaudeApi(e.clone()).user_message(), e.advice());
+    }
+}
@@ -129,6 +129,7 @@ impl FeedFetcher {
                 url: final_url,
                 site_url,
                 description,
+                folder: None,
             });
         }
 
@@ -154,6 +155,7 @@ impl FeedFetcher {
                             url: feed_url,
                             site_url,
                             description,
+                            folder: None,
                         });
                     }
                 }
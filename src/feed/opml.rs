@@ -14,12 +14,13 @@ pub fn parse_opml_string(content: &str) -> Result<Vec<NewFeed>> {
     let opml = OPML::from_str(content).map_err(|e| AppError::OpmlParse(e.to_string()))?;
 
     let mut feeds = Vec::new();
-    collect_feeds(&opml.body.outlines, &mut feeds);
+    collect_feeds(&opml.body.outlines, None, &mut feeds);
 
     Ok(feeds)
 }
 
-fn collect_feeds(outlines: &[Outline], feeds: &mut Vec<NewFeed>) {
+/// Walk the outline tree, tagging each feed with its nearest enclosing folder
+fn collect_feeds(outlines: &[Outline], folder: Option<&str>, feeds: &mut Vec<NewFeed>) {
     for outline in outlines {
         // Check if this outline is a feed (has xmlUrl)
         if let Some(xml_url) = &outline.xml_url {
@@ -28,12 +29,18 @@ fn collect_feeds(outlines: &[Outline], feeds: &mut Vec<NewFeed>) {
                 url: xml_url.clone(),
                 site_url: outline.html_url.clone(),
                 description: outline.description.clone(),
+                folder: folder.map(|f| f.to_string()),
             });
         }
 
         // Recursively process nested outlines (categories/folders)
         if !outline.outlines.is_empty() {
-            collect_feeds(&outline.outlines, feeds);
+            let nested_folder = if outline.xml_url.is_none() {
+                Some(outline.text.as_str())
+            } else {
+                folder
+            };
+            collect_feeds(&outline.outlines, nested_folder, feeds);
         }
     }
 }
@@ -54,7 +61,26 @@ pub fn export_opml_file(path: &Path, feeds: &[Feed]) -> Result<()> {
             description: feed.description.clone(),
             ..Default::default()
         };
-        opml.body.outlines.push(outline);
+
+        // Feeds with a folder go inside a folder outline of the same name
+        match &feed.folder {
+            Some(folder) => {
+                let existing = opml
+                    .body
+                    .outlines
+                    .iter_mut()
+                    .find(|o| o.xml_url.is_none() && &o.text == folder);
+                match existing {
+                    Some(folder_outline) => folder_outline.outlines.push(outline),
+                    None => opml.body.outlines.push(Outline {
+                        text: folder.clone(),
+                        outlines: vec![outline],
+                        ..Default::default()
+                    }),
+                }
+            }
+            None => opml.body.outlines.push(outline),
+        }
     }
 
     let content = opml
@@ -79,6 +105,7 @@ mod tests {
             url: url.to_string(),
             site_url: Some(format!("https://{}.com", title.to_lowercase())),
             description: Some(format!("{} feed", title)),
+            folder: None,
             last_fetched: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
@@ -133,6 +160,8 @@ mod tests {
         assert_eq!(feeds[0].title, "Ars Technica");
         assert_eq!(feeds[1].title, "The Verge");
         assert_eq!(feeds[2].title, "BBC");
+        assert_eq!(feeds[0].folder.as_deref(), Some("Tech"));
+        assert_eq!(feeds[2].folder.as_deref(), Some("News"));
     }
 
     #[test]
@@ -155,6 +184,7 @@ mod tests {
         assert_eq!(feeds.len(), 1);
         assert_eq!(feeds[0].title, "Deep Feed");
         assert_eq!(feeds[0].url, "https://deep.example.com/feed");
+        assert_eq!(feeds[0].folder.as_deref(), Some("Level3"));
     }
 
     #[test]
@@ -218,6 +248,7 @@ mod tests {
 
         assert_eq!(feeds.len(), 1);
         assert_eq!(feeds[0].title, "Real Feed");
+        assert_eq!(feeds[0].folder, None);
     }
 
     #[test]
@@ -297,6 +328,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_roundtrip_preserves_folders() {
+        let mut tech = make_feed(1, "Ars", "https://ars.example.com/feed");
+        tech.folder = Some("Tech".to_string());
+        let mut tech2 = make_feed(2, "Verge", "https://verge.example.com/feed");
+        tech2.folder = Some("Tech".to_string());
+        let loose = make_feed(3, "Loose", "https://loose.example.com/feed");
+
+        let temp_file = NamedTempFile::new().unwrap();
+        export_opml_file(temp_file.path(), &[tech, loose, tech2]).unwrap();
+
+        let imported = parse_opml_file(temp_file.path()).unwrap();
+        assert_eq!(imported.len(), 3);
+        let folder_of = |title: &str| {
+            imported
+                .iter()
+                .find(|f| f.title == title)
+                .and_then(|f| f.folder.clone())
+        };
+        assert_eq!(folder_of("Ars").as_deref(), Some("Tech"));
+        assert_eq!(folder_of("Verge").as_deref(), Some("Tech"));
+        assert_eq!(folder_of("Loose"), None);
+    }
+
     #[test]
     fn test_parse_opml_file_from_disk() {
         let opml_content = r#"<?xml version="1.0" encoding="UTF-8"?>
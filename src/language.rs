@@ -0,0 +1,69 @@
+/// Language translations go into when none is configured
+pub const DEFAULT_TARGET_LANGUAGE: &str = "English";
+
+/// Bytes of an article read to tell its language; more rarely changes the answer
+const SAMPLE_BYTES: usize = 1500;
+
+/// The language `text` is written in, by its English name ("Japanese"). None when the
+/// text is too short or mixed to tell reliably.
+pub fn detect(title: &str, text: &str) -> Option<&'static str> {
+    let sample = format!("{}\n{}", title, crate::ai::truncate(text, SAMPLE_BYTES));
+    let info = whatlang::detect(&sample)?;
+    info.is_reliable().then(|| info.lang().eng_name())
+}
+
+/// Whether `detected` (an English name from `detect`) is the configured `language`
+pub fn is_language(detected: &str, language: &str) -> bool {
+    detected.eq_ignore_ascii_case(language.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_common_feed_languages() {
+        assert_eq!(
+            detect(
+                "Apple kündigt neues iPhone an",
+                "Apple hat am Dienstag ein neues iPhone vorgestellt, das schneller ist und \
+                 eine bessere Kamera hat als das Modell aus dem letzten Jahr."
+            ),
+            Some("German")
+        );
+        assert_eq!(
+            detect(
+                "Apple annonce un nouvel iPhone",
+                "Apple a présenté mardi un nouvel iPhone, plus rapide et doté d'un meilleur \
+                 appareil photo que le modèle de l'année dernière."
+            ),
+            Some("French")
+        );
+        assert_eq!(
+            detect(
+                "アップルが新型iPhoneを発表",
+                "アップルは火曜日、昨年のモデルよりも高速で、より優れたカメラを搭載した新しいiPhoneを発表した。"
+            ),
+            Some("Japanese")
+        );
+        assert_eq!(
+            detect(
+                "Apple announces a new iPhone",
+                "Apple on Tuesday unveiled a new iPhone that is faster and has a better \
+                 camera than last year's model."
+            ),
+            Some("English")
+        );
+    }
+
+    #[test]
+    fn test_short_text_is_not_guessed() {
+        assert_eq!(detect("", ""), None);
+    }
+
+    #[test]
+    fn test_is_language() {
+        assert!(is_language("English", " english "));
+        assert!(!is_language("German", "English"));
+    }
+}
@@ -1,10 +1,16 @@
 pub mod ai;
 pub mod app;
 pub mod blocklist;
+pub mod briefing;
+pub mod cluster;
 pub mod config;
 pub mod db;
 pub mod error;
 pub mod feed;
+pub mod language;
 pub mod models;
+pub mod presummarize;
+pub mod rundown;
 pub mod services;
 pub mod tui;
+pub mod usage;
@@ -12,13 +12,19 @@ use ratatui::prelude::*;
 mod ai;
 mod app;
 mod blocklist;
+mod briefing;
+mod cluster;
 mod config;
 mod db;
 mod error;
 mod feed;
+mod language;
 mod models;
+mod presummarize;
+mod rundown;
 mod services;
 mod tui;
+mod usage;
 
 use app::App;
 use config::Config;
@@ -109,6 +115,43 @@ async fn main() -> Result<()> {
     // Check for --refresh flag (headless refresh)
     let headless_refresh = args.len() >= 2 && args[1] == "--refresh";
 
+    // Check for --usage flag (print summary token usage and cost)
+    let usage_report = args.len() >= 2 && args[1] == "--usage";
+
+    // Check for --briefing flag, with optional --hours N, --unread and --folder NAME
+    let briefing = if args.len() >= 2 && args[1] == "--briefing" {
+        Some(briefing_settings(&config, &args[2..])?)
+    } else {
+        None
+    };
+
+    // Check for --rundown SHOW flag (export a show's rundown)
+    let rundown_show = if args.len() >= 2 && args[1] == "--rundown" {
+        match args.get(2) {
+            Some(show) if rundown::SHOWS.contains(&show.as_str()) => Some(show.clone()),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "--rundown needs a show: {}",
+                    rundown::SHOWS.join(", ")
+                )
+                .into())
+            }
+        }
+    } else {
+        None
+    };
+
+    // Check for --cookies URL flag (show which cookie store is used for a page)
+    if args.len() >= 2 && args[1] == "--cookies" {
+        let url = args
+            .get(2)
+            .ok_or_else(|| anyhow::anyhow!("--cookies needs a URL"))?;
+        for line in services::CookieJar::new(config.cookies.clone()).diagnose(url)? {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
     // Initialize app
     let mut app = App::new(&config).await?;
 
@@ -119,10 +162,37 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // If usage requested, print the report and exit
+    if usage_report {
+        let report = usage::UsageReport::load(&app.repository, config.usage.monthly_budget).await?;
+        for line in report.lines() {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    // If a briefing was requested, write it and exit
+    if let Some(settings) = briefing {
+        let path = app.briefing_blocking(&settings).await?;
+        println!("Wrote briefing to {}", path.display());
+        return Ok(());
+    }
+
+    // If a rundown was requested, export it and exit
+    if let Some(show) = rundown_show {
+        let path = app.export_rundown(&show).await?;
+        println!("Wrote {} rundown to {}", show, path.display());
+        return Ok(());
+    }
+
     // If headless refresh, just refresh and exit
     if headless_refresh {
         app.refresh_feeds_blocking().await?;
         println!("Refreshed {} feeds", app.feeds.len());
+        let summarized = app.presummarize_blocking().await?;
+        if summarized > 0 {
+            println!("Summarized {} new articles", summarized);
+        }
         return Ok(());
     }
 
@@ -152,6 +222,32 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Briefing settings from config, overridden by `--hours N`, `--unread` and `--folder NAME`
+fn briefing_settings(config: &Config, args: &[String]) -> Result<config::BriefingConfig> {
+    let mut settings = config.briefing.clone();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--hours" => {
+                settings.hours = args
+                    .next()
+                    .and_then(|hours| hours.parse().ok())
+                    .ok_or_else(|| anyhow::anyhow!("--hours needs a number of hours"))?;
+            }
+            "--unread" => settings.unread_only = true,
+            "--folder" => {
+                settings.folder = Some(
+                    args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--folder needs a folder name"))?
+                        .clone(),
+                );
+            }
+            other => return Err(anyhow::anyhow!("Unknown briefing option: {}", other).into()),
+        }
+    }
+    Ok(settings)
+}
+
 async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
     loop {
         terminal.draw(|frame| draw(frame, app))?;
@@ -168,21 +264,32 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Resul
         // Poll for completed feed discovery results
         app.poll_discovery_result().await?;
 
+        // Refresh on the configured interval, then summarize new articles if enabled
+        app.check_auto_refresh();
+        app.poll_presummarize().await?;
+
+        // Report a finished briefing
+        app.poll_briefing().await;
+
+        // Show a finished chat answer
+        app.poll_chat().await;
+
+        // Pre-fill the tag prompt with suggested tags
+        app.poll_tag_suggestion().await;
+
+        // Send a bookmark once its summary has been rewritten
+        app.poll_bookmark().await?;
+
+        // Show a finished translation
+        app.poll_translation().await;
+
         // Clear bookmark status after timeout
         app.check_bookmark_status_timeout();
 
         // Poll for events with timeout to allow async operations
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
-                if let Some(action) = handle_key_event(
-                    key,
-                    app.tag_input_active,
-                    app.feed_input_active,
-                    app.opml_input_active,
-                    app.opml_export_active,
-                    app.show_help,
-                    app.bookmark_prefix_active,
-                ) {
+                if let Some(action) = handle_key_event(key, app.input_mode()) {
                     let should_quit = app.handle_action(action).await?;
                     if should_quit {
                         return Ok(());
@@ -14,6 +14,8 @@ pub struct Article {
     pub published_at: Option<DateTime<Utc>>,
     pub fetched_at: DateTime<Utc>,
     pub feed_title: Option<String>,
+    /// Id of the first article in this article's duplicate cluster (None when it has no duplicates)
+    pub cluster_id: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -27,3 +29,15 @@ pub struct NewArticle {
     pub content_text: Option<String>,
     pub published_at: Option<DateTime<Utc>>,
 }
+
+/// An article the blocklist hid from the list, with the rule that matched
+#[derive(Debug, Clone)]
+pub struct BlockedArticle {
+    pub id: i64,
+    pub title: String,
+    pub url: String,
+    pub published_at: Option<DateTime<Utc>>,
+    pub filtered_at: DateTime<Utc>,
+    pub rule: String,
+    pub feed_title: Option<String>,
+}
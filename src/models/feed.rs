@@ -8,6 +8,7 @@ pub struct Feed {
     pub url: String,
     pub site_url: Option<String>,
     pub description: Option<String>,
+    pub folder: Option<String>,
     pub last_fetched: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -19,4 +20,5 @@ pub struct NewFeed {
     pub url: String,
     pub site_url: Option<String>,
     pub description: Option<String>,
+    pub folder: Option<String>,
 }
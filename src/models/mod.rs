@@ -1,7 +1,13 @@
 mod article;
+mod chat;
 mod feed;
+mod rundown;
 mod summary;
+mod usage;
 
-pub use article::{Article, NewArticle};
+pub use article::{Article, BlockedArticle, NewArticle};
+pub use chat::{ChatMessage, ChatRole};
 pub use feed::{Feed, NewFeed};
-pub use summary::{Summary, SummaryStatus};
+pub use rundown::{Rundown, RundownItem};
+pub use summary::{NewSummary, Summary, SummaryFields, SummaryKind, SummaryStatus};
+pub use usage::UsageTotals;
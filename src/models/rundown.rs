@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+
+/// An article bookmarked for a show, as it appears in the show's rundown
+#[derive(Debug, Clone, PartialEq)]
+pub struct RundownItem {
+    pub article_id: i64,
+    pub title: String,
+    pub url: String,
+    pub feed_title: Option<String>,
+    /// First paragraph of the article's summary, if it has one
+    pub lede: Option<String>,
+    pub note: Option<String>,
+    pub saved_at: DateTime<Utc>,
+}
+
+/// Everything bookmarked with a show's tag since the show last aired, in running order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rundown {
+    pub show: String,
+    /// When the previous rundown was finished (None before the first one)
+    pub since: Option<DateTime<Utc>>,
+    pub items: Vec<RundownItem>,
+}
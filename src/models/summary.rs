@@ -8,6 +8,116 @@ pub struct Summary {
     pub content: String,
     pub model_version: String,
     pub generated_at: DateTime<Utc>,
+    /// Prompt template the summary was written with (None for summaries from before templates)
+    pub template: Option<String>,
+    /// Quotes that weren't found word for word in the article (None when not checked)
+    pub unverified_quotes: Option<Vec<String>>,
+    /// The summary's parts, when the model answered in the structured format.
+    /// `content` holds the same summary as plain text.
+    pub fields: Option<SummaryFields>,
+    /// Feed of the identical story this summary was copied from, rather than written for
+    /// this article
+    pub reused_from: Option<String>,
+}
+
+/// What a structured summary is about; product summaries list price and availability
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SummaryKind {
+    #[default]
+    Editorial,
+    Product,
+}
+
+impl SummaryKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SummaryKind::Editorial => "editorial",
+            SummaryKind::Product => "product",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "editorial" => Some(SummaryKind::Editorial),
+            "product" => Some(SummaryKind::Product),
+            _ => None,
+        }
+    }
+}
+
+/// The parts of a structured summary. Parts that don't apply are None.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SummaryFields {
+    #[serde(rename = "type", default)]
+    pub kind: SummaryKind,
+    /// Who did what (editorial), or what the product is and does
+    #[serde(default)]
+    pub lede: String,
+    #[serde(default)]
+    pub why_it_matters: Option<String>,
+    #[serde(default)]
+    pub price: Option<String>,
+    #[serde(default)]
+    pub availability: Option<String>,
+    #[serde(default)]
+    pub platforms: Option<String>,
+    /// Verbatim quote from the article, without quote marks
+    #[serde(default)]
+    pub quote: Option<String>,
+    #[serde(default)]
+    pub speaker: Option<String>,
+}
+
+impl SummaryFields {
+    /// Plain text for Raindrop notes and email: one paragraph per part, the quote last
+    /// as `"quote" -- Speaker`
+    pub fn to_plain_text(&self) -> String {
+        let mut paragraphs: Vec<String> = [
+            Some(&self.lede),
+            self.why_it_matters.as_ref(),
+            self.price.as_ref(),
+            self.availability.as_ref(),
+            self.platforms.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .collect();
+        if let Some(quote) = self
+            .quote
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+        {
+            paragraphs.push(match self.speaker.as_deref().map(str::trim) {
+                Some(speaker) if !speaker.is_empty() => format!("\"{}\" -- {}", quote, speaker),
+                _ => format!("\"{}\"", quote),
+            });
+        }
+        paragraphs.join("\n\n")
+    }
+}
+
+/// A summary to store, with the tokens it used when the backend reported them
+#[derive(Debug, Clone)]
+pub struct NewSummary {
+    pub article_id: i64,
+    pub content: String,
+    pub model_version: String,
+    pub template: String,
+    /// Written in the background rather than on request
+    pub automatic: bool,
+    pub input_tokens: Option<u32>,
+    pub output_tokens: Option<u32>,
+    pub cost_usd: Option<f64>,
+    pub unverified_quotes: Vec<String>,
+    pub fields: Option<SummaryFields>,
+    /// `cluster::content_hash` of the text summarized, for reuse by identical stories
+    pub content_hash: Option<String>,
+    /// `cluster::canonical_url` of the article
+    pub canonical_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -19,3 +129,40 @@ pub enum SummaryStatus {
     Failed,
     NoApiKey,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text_of_editorial_summary() {
+        let fields = SummaryFields {
+            kind: SummaryKind::Editorial,
+            lede: "Apple announced a phone.".into(),
+            why_it_matters: Some("It matters.".into()),
+            quote: Some("It's our best phone".into()),
+            speaker: Some("Tim Cook".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            fields.to_plain_text(),
+            "Apple announced a phone.\n\nIt matters.\n\n\"It's our best phone\" -- Tim Cook"
+        );
+    }
+
+    #[test]
+    fn test_plain_text_of_product_summary_skips_missing_parts() {
+        let fields = SummaryFields {
+            kind: SummaryKind::Product,
+            lede: "A phone.".into(),
+            price: Some("$799".into()),
+            platforms: Some(" ".into()),
+            quote: Some("Unattributed words here".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            fields.to_plain_text(),
+            "A phone.\n\n$799\n\n\"Unattributed words here\""
+        );
+    }
+}
@@ -0,0 +1,9 @@
+/// Summary requests and what they cost over some period
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UsageTotals {
+    pub requests: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Sum over requests with a known price
+    pub cost_usd: f64,
+}
@@ -0,0 +1,269 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use tokio::sync::mpsc;
+
+use crate::ai::{PromptFields, Summarizer};
+use crate::cluster;
+use crate::config::PresummarizeConfig;
+use crate::db::Repository;
+use crate::error::Result;
+use crate::models::{Article, NewSummary};
+use crate::services::ContentFetcher;
+use crate::usage;
+
+/// Whether the include/exclude lists allow articles from this feed.
+/// Feed names compare case-insensitively.
+fn feed_allowed(settings: &PresummarizeConfig, feed: Option<&str>) -> bool {
+    let listed = |names: &[String]| {
+        feed.is_some_and(|feed| {
+            names
+                .iter()
+                .any(|name| name.trim().to_lowercase() == feed.trim().to_lowercase())
+        })
+    };
+    (settings.include_feeds.is_empty() || listed(&settings.include_feeds))
+        && !listed(&settings.exclude_feeds)
+}
+
+/// Pick the articles to summarize from unsummarized ones (newest first): only feeds the
+/// settings allow, one article per duplicate cluster, at most `limit`.
+pub fn select_candidates(
+    articles: Vec<Article>,
+    settings: &PresummarizeConfig,
+    limit: usize,
+) -> Vec<Article> {
+    let mut clusters = HashSet::new();
+    articles
+        .into_iter()
+        .filter(|article| feed_allowed(settings, article.feed_title.as_deref()))
+        .filter(|article| article.cluster_id.is_none_or(|id| clusters.insert(id)))
+        .take(limit)
+        .collect()
+}
+
+/// Summarize unread articles fetched since `since` (the start of the refresh that brought
+/// them in) that don't have a summary yet, within the configured caps.
+/// Nothing runs once `monthly_budget` (USD) has been spent this month.
+/// The id of each finished article is sent on `done` (if given) so the TUI can show it.
+/// Returns the number of summaries written.
+pub async fn run(
+    repository: Repository,
+    summarizer: Arc<Summarizer>,
+    content_fetcher: Arc<ContentFetcher>,
+    settings: PresummarizeConfig,
+    since: DateTime<Utc>,
+    monthly_budget: Option<f64>,
+    done: Option<mpsc::UnboundedSender<i64>>,
+) -> Result<usize> {
+    if let Some(budget) = monthly_budget {
+        let spent = repository.get_usage_since(usage::start_of_month()).await?;
+        if spent.cost_usd >= budget {
+            tracing::info!(
+                "Monthly budget of ${:.2} reached (${:.2} spent), skipping background summaries",
+                budget,
+                spent.cost_usd
+            );
+            return Ok(0);
+        }
+    }
+
+    let today = repository
+        .count_automatic_summaries_since(usage::start_of_today())
+        .await?;
+    let limit = settings
+        .max_per_run
+        .min(settings.max_per_day.saturating_sub(today));
+    if limit == 0 {
+        tracing::info!("Daily background summary cap reached ({} today)", today);
+        return Ok(0);
+    }
+
+    let articles = repository.get_unsummarized_articles(since).await?;
+    let candidates = select_candidates(articles, &settings, limit);
+    if candidates.is_empty() {
+        return Ok(0);
+    }
+    tracing::info!(
+        "Summarizing {} articles in the background",
+        candidates.len()
+    );
+
+    let (repository, summarizer, content_fetcher, done) =
+        (&repository, &summarizer, &content_fetcher, &done);
+    let written = stream::iter(candidates)
+        .map(|article| async move {
+            let ok = summarize_article(repository, summarizer, content_fetcher, &article).await;
+            if ok {
+                if let Some(done) = done {
+                    let _ = done.send(article.id);
+                }
+            }
+            ok
+        })
+        .buffer_unordered(settings.concurrency.max(1))
+        .filter(|ok| std::future::ready(*ok))
+        .count()
+        .await;
+
+    tracing::info!("Wrote {} background summaries", written);
+    Ok(written)
+}
+
+/// Summarize one article with the default template and store it as automatic, or copy
+/// the summary of an identical story when there is one.
+/// Failures are logged and skipped so one bad article doesn't stop the run.
+async fn summarize_article(
+    repository: &Repository,
+    summarizer: &Summarizer,
+    content_fetcher: &ContentFetcher,
+    article: &Article,
+) -> bool {
+    let template = summarizer.default_template();
+    let canonical_url = cluster::canonical_url(&article.url);
+    let reuse = |content_hash: Option<String>| {
+        repository.reuse_summary(
+            article.id,
+            template.to_string(),
+            canonical_url.clone(),
+            content_hash,
+            true,
+        )
+    };
+    if let Ok(true) = reuse(None).await {
+        return true;
+    }
+
+    let rss_content = article
+        .content_text
+        .clone()
+        .or_else(|| article.content.clone())
+        .unwrap_or_default();
+    let content = match content_fetcher.fetch_full_content(&article.url).await {
+        Ok(Some(full_content)) => full_content,
+        _ => rss_content,
+    };
+    let content_hash = cluster::content_hash(&content);
+    if content_hash.is_some() {
+        if let Ok(true) = reuse(content_hash.clone()).await {
+            return true;
+        }
+    }
+
+    let fields = PromptFields {
+        title: &article.title,
+        content: &content,
+        feed: article.feed_title.as_deref(),
+        author: article.author.as_deref(),
+    };
+    let summary = match summarizer.generate_summary(&fields, template).await {
+        Ok(summary) => summary,
+        Err(e) => {
+            tracing::warn!("Background summary failed for {}: {}", article.url, e);
+            return false;
+        }
+    };
+
+    if let Err(e) = repository
+        .save_summary(NewSummary {
+            article_id: article.id,
+            content: summary.text,
+            model_version: summarizer.model_version().to_string(),
+            template: template.to_string(),
+            automatic: true,
+            input_tokens: summary.usage.map(|u| u.input_tokens),
+            output_tokens: summary.usage.map(|u| u.output_tokens),
+            cost_usd: summary.cost_usd,
+            unverified_quotes: summary.unverified_quotes,
+            fields: summary.fields,
+            content_hash,
+            canonical_url: Some(canonical_url),
+        })
+        .await
+    {
+        tracing::warn!("Failed to save background summary: {}", e);
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(id: i64, feed: &str, cluster_id: Option<i64>) -> Article {
+        Article {
+            id,
+            feed_id: 1,
+            guid: id.to_string(),
+            title: format!("Article {}", id),
+            url: format!("https://example.com/{}", id),
+            author: None,
+            content: None,
+            content_text: None,
+            published_at: None,
+            fetched_at: Utc::now(),
+            feed_title: Some(feed.to_string()),
+            cluster_id,
+        }
+    }
+
+    fn ids(articles: &[Article]) -> Vec<i64> {
+        articles.iter().map(|a| a.id).collect()
+    }
+
+    #[test]
+    fn test_candidates_respect_limit() {
+        let articles = (1..=5).map(|id| article(id, "Feed", None)).collect();
+        let picked = select_candidates(articles, &PresummarizeConfig::default(), 3);
+        assert_eq!(ids(&picked), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_candidates_include_and_exclude_feeds() {
+        let articles = vec![
+            article(1, "The Verge", None),
+            article(2, "Hacker News", None),
+            article(3, "MacRumors", None),
+        ];
+
+        let settings = PresummarizeConfig {
+            include_feeds: vec!["the verge".into(), "MacRumors".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            ids(&select_candidates(articles.clone(), &settings, 10)),
+            vec![1, 3]
+        );
+
+        let settings = PresummarizeConfig {
+            exclude_feeds: vec!["Hacker News".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            ids(&select_candidates(articles.clone(), &settings, 10)),
+            vec![1, 3]
+        );
+
+        // Exclusion wins over inclusion
+        let settings = PresummarizeConfig {
+            include_feeds: vec!["The Verge".into()],
+            exclude_feeds: vec!["The Verge".into()],
+            ..Default::default()
+        };
+        assert!(select_candidates(articles, &settings, 10).is_empty());
+    }
+
+    #[test]
+    fn test_candidates_one_per_cluster() {
+        let articles = vec![
+            article(1, "A", Some(7)),
+            article(2, "B", None),
+            article(3, "C", Some(7)),
+        ];
+        let picked = select_candidates(articles, &PresummarizeConfig::default(), 10);
+        assert_eq!(ids(&picked), vec![1, 2]);
+    }
+}
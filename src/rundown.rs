@@ -0,0 +1,208 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, NaiveDate};
+
+use crate::briefing::html_page;
+use crate::config::RundownConfig;
+use crate::db::Repository;
+use crate::error::Result;
+use crate::models::{Rundown, RundownItem};
+
+/// Show tags, as set by the quick-bookmark keys (`Space` then `t`, `i` or `m`)
+pub const SHOWS: [&str; 3] = ["twit", "im", "mbw"];
+
+/// Title and link as Markdown, safe for any title text
+fn markdown_link(item: &RundownItem) -> String {
+    format!(
+        "[{}](<{}>)",
+        item.title.replace('[', "(").replace(']', ")"),
+        item.url
+    )
+}
+
+/// The rundown as Markdown: a numbered heading per story with its feed, summary lede
+/// and note
+pub fn render_markdown(rundown: &Rundown, date: NaiveDate) -> String {
+    let mut out = format!(
+        "# {} rundown: {}\n\n_{}_\n",
+        rundown.show,
+        date.format("%A, %B %-d, %Y"),
+        scope(rundown)
+    );
+    for (i, item) in rundown.items.iter().enumerate() {
+        out.push_str(&format!("\n## {}. {}\n\n", i + 1, markdown_link(item)));
+        if let Some(feed) = &item.feed_title {
+            out.push_str(&format!("_{}_\n\n", feed));
+        }
+        if let Some(lede) = &item.lede {
+            out.push_str(&format!("{}\n\n", lede));
+        }
+        if let Some(note) = &item.note {
+            out.push_str(&format!("> **Note:** {}\n\n", note));
+        }
+    }
+    format!("{}\n", out.trim_end())
+}
+
+/// The rundown as a standalone HTML page
+pub fn render_html(rundown: &Rundown, date: NaiveDate) -> String {
+    html_page(
+        &render_markdown(rundown, date),
+        &format!("{} rundown: {}", rundown.show, date.format("%Y-%m-%d")),
+    )
+}
+
+/// The rundown as plain text, for pasting into show notes
+pub fn render_text(rundown: &Rundown, date: NaiveDate) -> String {
+    let mut out = format!(
+        "{} RUNDOWN - {}\n{}\n",
+        rundown.show.to_uppercase(),
+        date.format("%A, %B %-d, %Y"),
+        scope(rundown)
+    );
+    for (i, item) in rundown.items.iter().enumerate() {
+        out.push_str(&format!("\n{}. {}\n", i + 1, item.title));
+        if let Some(feed) = &item.feed_title {
+            out.push_str(&format!("   {}\n", feed));
+        }
+        out.push_str(&format!("   {}\n", item.url));
+        if let Some(lede) = &item.lede {
+            out.push_str(&format!("   {}\n", lede));
+        }
+        if let Some(note) = &item.note {
+            out.push_str(&format!("   Note: {}\n", note));
+        }
+    }
+    out
+}
+
+/// What the rundown covers, e.g. "3 stories bookmarked since Sunday, October 11"
+fn scope(rundown: &Rundown) -> String {
+    let stories = match rundown.items.len() {
+        1 => "1 story".to_string(),
+        n => format!("{} stories", n),
+    };
+    match rundown.since {
+        Some(since) => format!(
+            "{} bookmarked since {}",
+            stories,
+            since.with_timezone(&Local).format("%A, %B %-d")
+        ),
+        None => format!("{} bookmarked", stories),
+    }
+}
+
+/// Write `rundown-SHOW-YYYY-MM-DD` as `.md`, `.html` and `.txt` to `dir`, replacing an
+/// earlier export from the same day. Returns the Markdown file's path.
+pub fn write(rundown: &Rundown, date: NaiveDate, dir: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let show: String = rundown
+        .show
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+    let stem = format!("rundown-{}-{}", show, date.format("%Y-%m-%d"));
+    let markdown_path = dir.join(format!("{}.md", stem));
+    std::fs::write(&markdown_path, render_markdown(rundown, date))?;
+    std::fs::write(
+        dir.join(format!("{}.html", stem)),
+        render_html(rundown, date),
+    )?;
+    std::fs::write(
+        dir.join(format!("{}.txt", stem)),
+        render_text(rundown, date),
+    )?;
+    Ok(markdown_path)
+}
+
+/// Export the show's current rundown to the configured directory
+pub async fn export(
+    repository: &Repository,
+    show: &str,
+    settings: &RundownConfig,
+) -> Result<PathBuf> {
+    let rundown = repository.get_rundown(show).await?;
+    if rundown.items.is_empty() {
+        return Err(anyhow::anyhow!("Nothing bookmarked for {} since the last show", show).into());
+    }
+    let path = write(&rundown, Local::now().date_naive(), &settings.output_dir())?;
+    tracing::info!(
+        "Wrote {} rundown of {} stories to {}",
+        show,
+        rundown.items.len(),
+        path.display()
+    );
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn item(id: i64, note: Option<&str>) -> RundownItem {
+        RundownItem {
+            article_id: id,
+            title: format!("Story [{}]", id),
+            url: format!("https://example.com/{}", id),
+            feed_title: Some("The Verge".to_string()),
+            lede: Some(format!("Lede of story {}.", id)),
+            note: note.map(String::from),
+            saved_at: Utc::now(),
+        }
+    }
+
+    fn rundown() -> Rundown {
+        Rundown {
+            show: "twit".to_string(),
+            since: Some(Utc.with_ymd_and_hms(2026, 10, 11, 12, 0, 0).unwrap()),
+            items: vec![item(2, Some("Ask Leo <about> this")), item(1, None)],
+        }
+    }
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()
+    }
+
+    #[test]
+    fn test_markdown_keeps_running_order() {
+        let markdown = render_markdown(&rundown(), date());
+        assert!(markdown.starts_with("# twit rundown: Sunday, October 18, 2026\n\n"));
+        assert!(markdown.contains("_2 stories bookmarked since "));
+        let first = markdown
+            .find("## 1. [Story (2)](<https://example.com/2>)")
+            .unwrap();
+        let second = markdown
+            .find("## 2. [Story (1)](<https://example.com/1>)")
+            .unwrap();
+        assert!(first < second);
+        assert!(markdown.contains("Lede of story 2.\n\n> **Note:** Ask Leo <about> this"));
+    }
+
+    #[test]
+    fn test_html_and_text() {
+        let html = render_html(&rundown(), date());
+        assert!(html.contains("<title>twit rundown: 2026-10-18</title>"));
+        assert!(html.contains(r#"<a href="https://example.com/2">Story (2)</a>"#));
+        assert!(html.contains("Ask Leo &lt;about&gt; this"));
+
+        let text = render_text(&rundown(), date());
+        assert!(text.starts_with("TWIT RUNDOWN - Sunday, October 18, 2026\n"));
+        assert!(text.contains(
+            "\n1. Story [2]\n   The Verge\n   https://example.com/2\n   Lede of story 2.\n   Note: Ask Leo <about> this\n"
+        ));
+        assert!(text.ends_with(
+            "\n2. Story [1]\n   The Verge\n   https://example.com/1\n   Lede of story 1.\n"
+        ));
+    }
+
+    #[test]
+    fn test_write_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(&rundown(), date(), dir.path()).unwrap();
+        assert_eq!(path, dir.path().join("rundown-twit-2026-10-18.md"));
+        assert!(dir.path().join("rundown-twit-2026-10-18.html").exists());
+        let text = std::fs::read_to_string(dir.path().join("rundown-twit-2026-10-18.txt")).unwrap();
+        assert!(text.contains("Story [1]"));
+    }
+}
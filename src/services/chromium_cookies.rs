@@ -0,0 +1,228 @@
+use std::process::Command;
+
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use rusqlite::{params, Connection};
+use sha2::{Digest, Sha256};
+
+use crate::error::Result;
+
+type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
+
+/// Password Chromium encrypts `v10` values with on Linux when it has no keyring
+const FALLBACK_PASSWORD: &[u8] = b"peanuts";
+
+const SALT: &[u8] = b"saltysalt";
+
+const IV: [u8; 16] = [b' '; 16];
+
+/// AES keys for a Chromium profile's encrypted cookie values
+#[derive(Debug, Clone)]
+pub struct Keys {
+    v10: [u8; 16],
+    /// Keys to try for `v11` values: the keyring password's, then an empty password's,
+    /// which Chromium falls back to when the keyring answers with nothing
+    v11: Vec<[u8; 16]>,
+}
+
+impl Keys {
+    pub fn new(keyring_password: Option<&[u8]>) -> Self {
+        Self {
+            v10: derive_key(FALLBACK_PASSWORD),
+            v11: keyring_password
+                .into_iter()
+                .chain([&b""[..]])
+                .map(derive_key)
+                .collect(),
+        }
+    }
+
+    /// Keys for a browser whose password the Secret Service stores under `application`
+    /// ("chrome", "chromium", ...)
+    pub fn for_application(application: &str) -> Self {
+        Self::new(keyring_password(application).as_deref())
+    }
+}
+
+fn derive_key(password: &[u8]) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    pbkdf2::pbkdf2_hmac::<sha1::Sha1>(password, SALT, 1, &mut key);
+    key
+}
+
+/// The browser's "Safe Storage" password from the Secret Service (GNOME Keyring,
+/// KeePassXC, ...), looked up with libsecret's `secret-tool`
+fn keyring_password(application: &str) -> Option<Vec<u8>> {
+    let output = match Command::new("secret-tool")
+        .args(["lookup", "application", application])
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            tracing::debug!("Unable to run secret-tool: {}", e);
+            return None;
+        }
+    };
+    let password = output.stdout.trim_ascii_end();
+    if !output.status.success() || password.is_empty() {
+        tracing::debug!("No {} password in the keyring", application);
+        return None;
+    }
+    Some(password.to_vec())
+}
+
+/// Decrypt a `v10` or `v11` cookie value. Newer databases put the SHA-256 of the
+/// cookie's host in front of the value, which is dropped.
+pub fn decrypt(encrypted: &[u8], host_key: &str, keys: &Keys) -> Option<String> {
+    let (candidates, data) = match encrypted.split_at_checked(3) {
+        Some((b"v10", data)) => (std::slice::from_ref(&keys.v10), data),
+        Some((b"v11", data)) => (keys.v11.as_slice(), data),
+        _ => return None,
+    };
+    candidates.iter().find_map(|key| {
+        let mut buf = data.to_vec();
+        let plaintext = Aes128CbcDec::new(key.into(), &IV.into())
+            .decrypt_padded_mut::<Pkcs7>(&mut buf)
+            .ok()?;
+        let plaintext = match plaintext.split_at_checked(32) {
+            Some((hash, value)) if *hash == *Sha256::digest(host_key.as_bytes()) => value,
+            _ => plaintext,
+        };
+        String::from_utf8(plaintext.to_vec()).ok()
+    })
+}
+
+/// Unexpired cookies for `domain` from a Chromium `Cookies` database, decrypted
+pub fn read_cookies(conn: &Connection, domain: &str, keys: &Keys) -> Result<Vec<(String, String)>> {
+    // Current time in Chrome's timestamp format (microseconds since 1601-01-01)
+    // Chrome uses Windows FILETIME epoch, which is 11,644,473,600 seconds before Unix epoch
+    let now = (chrono::Utc::now().timestamp() + 11_644_473_600) * 1_000_000;
+
+    // Cookies set for this host, and domain cookies for it or a parent domain
+    // (".nytimes.com" for www.nytimes.com), though not for a bare TLD like ".com"
+    let mut stmt = conn.prepare(
+        "SELECT host_key, name, value, encrypted_value FROM cookies
+         WHERE (host_key = ?1
+                OR (host_key LIKE '.%.%' AND substr('.' || ?1, -length(host_key)) = host_key))
+         AND expires_utc > ?2
+         AND name != ''",
+    )?;
+
+    let mut undecryptable = 0;
+    let cookies = stmt
+        .query_map(params![domain, now], |row| {
+            let host_key: String = row.get(0)?;
+            let name: String = row.get(1)?;
+            let value: String = row.get(2)?;
+            let encrypted_value: Vec<u8> = row.get(3)?;
+            Ok((host_key, name, value, encrypted_value))
+        })?
+        .filter_map(|r| r.ok())
+        .filter_map(|(host_key, name, value, encrypted_value)| {
+            let value = if !value.is_empty() {
+                value
+            } else if encrypted_value.is_empty() {
+                return None;
+            } else {
+                match decrypt(&encrypted_value, &host_key, keys) {
+                    Some(value) => value,
+                    None => {
+                        undecryptable += 1;
+                        return None;
+                    }
+                }
+            };
+            Some((name, value))
+        })
+        .collect();
+
+    if undecryptable > 0 {
+        tracing::debug!("Unable to decrypt {} cookies for {}", undecryptable, domain);
+    }
+
+    Ok(cookies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::OpenFlags;
+
+    const KEYRING_PASSWORD: &[u8] = b"fixture-keyring-password";
+
+    fn cookie_header(conn: &Connection, domain: &str, keys: &Keys) -> String {
+        read_cookies(conn, domain, keys)
+            .unwrap()
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    fn fixture(name: &str) -> Connection {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/cookies")
+            .join(name);
+        Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY).unwrap()
+    }
+
+    #[test]
+    fn test_peanuts_key() {
+        assert_eq!(
+            derive_key(FALLBACK_PASSWORD),
+            [
+                0xfd, 0x62, 0x1f, 0xe5, 0xa2, 0xb4, 0x02, 0x53, 0x9d, 0xfa, 0x14, 0x7c, 0xa9, 0x27,
+                0x27, 0x78
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decrypt_v10_and_v11() {
+        let conn = fixture("chromium-v18.sqlite");
+        let keys = Keys::new(Some(KEYRING_PASSWORD));
+        assert_eq!(
+            cookie_header(&conn, "news.example.com", &keys),
+            "session=s3ss10n=ok; plain=visible; subscriber=yes"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_with_host_hash_prefix() {
+        let conn = fixture("chromium-v24.sqlite");
+        let keys = Keys::new(Some(KEYRING_PASSWORD));
+        assert_eq!(
+            cookie_header(&conn, "news.example.com", &keys),
+            "session=s3ss10n=ok; plain=visible; subscriber=yes"
+        );
+    }
+
+    #[test]
+    fn test_parent_domain_cookies() {
+        let conn = fixture("chromium-v24.sqlite");
+        let keys = Keys::new(Some(KEYRING_PASSWORD));
+        // Only the cookie set for .news.example.com applies to a subdomain
+        assert_eq!(
+            cookie_header(&conn, "www.news.example.com", &keys),
+            "subscriber=yes"
+        );
+        assert_eq!(cookie_header(&conn, "example.com", &keys), "");
+    }
+
+    #[test]
+    fn test_v11_without_keyring_password() {
+        // Only the v10 and plaintext cookies can be read without the keyring
+        let conn = fixture("chromium-v24.sqlite");
+        assert_eq!(
+            cookie_header(&conn, "news.example.com", &Keys::new(None)),
+            "plain=visible; subscriber=yes"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_rejects_unknown_values() {
+        let keys = Keys::new(None);
+        assert_eq!(decrypt(b"", "example.com", &keys), None);
+        assert_eq!(decrypt(b"v12abcdefghijklmnop", "example.com", &keys), None);
+        assert_eq!(decrypt(b"v10short", "example.com", &keys), None);
+    }
+}
@@ -0,0 +1,721 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use rusqlite::{params, Connection, OpenFlags};
+use url::Url;
+
+use super::chromium_cookies::{self, Keys};
+use crate::config::{Browser, CookieSource, CookiesConfig};
+use crate::error::Result;
+
+/// How a browser was installed, which decides where its profiles are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Install {
+    Native,
+    Flatpak,
+    Snap,
+}
+
+impl fmt::Display for Install {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Install::Native => "native",
+            Install::Flatpak => "Flatpak",
+            Install::Snap => "Snap",
+        })
+    }
+}
+
+/// Where a browser keeps its profiles for each kind of install, relative to the home
+/// directory
+fn data_dirs(browser: Browser) -> &'static [(Install, &'static str)] {
+    match browser {
+        Browser::Chrome => &[
+            (Install::Native, ".config/google-chrome"),
+            (
+                Install::Flatpak,
+                ".var/app/com.google.Chrome/config/google-chrome",
+            ),
+        ],
+        Browser::Chromium => &[
+            (Install::Native, ".config/chromium"),
+            (
+                Install::Flatpak,
+                ".var/app/org.chromium.Chromium/config/chromium",
+            ),
+            (Install::Snap, "snap/chromium/common/chromium"),
+        ],
+        Browser::Brave => &[
+            (Install::Native, ".config/BraveSoftware/Brave-Browser"),
+            (
+                Install::Flatpak,
+                ".var/app/com.brave.Browser/config/BraveSoftware/Brave-Browser",
+            ),
+            (
+                Install::Snap,
+                "snap/brave/current/.config/BraveSoftware/Brave-Browser",
+            ),
+        ],
+        Browser::Vivaldi => &[
+            (Install::Native, ".config/vivaldi"),
+            (
+                Install::Flatpak,
+                ".var/app/com.vivaldi.Vivaldi/config/vivaldi",
+            ),
+            (Install::Snap, "snap/vivaldi/current/.config/vivaldi"),
+        ],
+        Browser::Edge => &[
+            (Install::Native, ".config/microsoft-edge"),
+            (
+                Install::Flatpak,
+                ".var/app/com.microsoft.Edge/config/microsoft-edge",
+            ),
+        ],
+        Browser::Opera => &[
+            (Install::Native, ".config/opera"),
+            (Install::Flatpak, ".var/app/com.opera.Opera/config/opera"),
+            (Install::Snap, "snap/opera/current/.config/opera"),
+        ],
+        Browser::Firefox => &[
+            (Install::Native, ".mozilla/firefox"),
+            (
+                Install::Flatpak,
+                ".var/app/org.mozilla.firefox/.mozilla/firefox",
+            ),
+            (Install::Snap, "snap/firefox/common/.mozilla/firefox"),
+        ],
+    }
+}
+
+/// The name a Chromium browser's "Safe Storage" password is kept under in the keyring
+fn keyring_application(browser: Browser) -> &'static str {
+    match browser {
+        Browser::Chrome => "chrome",
+        Browser::Chromium => "chromium",
+        Browser::Brave => "brave",
+        Browser::Vivaldi => "vivaldi",
+        Browser::Edge => "microsoft-edge",
+        Browser::Opera => "opera",
+        Browser::Firefox => "firefox",
+    }
+}
+
+/// One browser profile's cookie database
+#[derive(Debug, Clone, PartialEq)]
+pub struct CookieStore {
+    pub browser: Browser,
+    pub install: Install,
+    /// Directory of the profile ("Default", "Profile 1", "x1y2z3.default-release")
+    pub profile: String,
+    /// Name the browser shows for the profile
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+impl CookieStore {
+    fn matches(&self, source: &CookieSource) -> bool {
+        source.browser.is_none_or(|browser| browser == self.browser)
+            && source.profile.as_ref().is_none_or(|profile| {
+                profile.eq_ignore_ascii_case(&self.profile)
+                    || self
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| name.eq_ignore_ascii_case(profile))
+            })
+    }
+}
+
+impl fmt::Display for CookieStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.browser)?;
+        if self.install != Install::Native {
+            write!(f, " ({})", self.install)?;
+        }
+        match &self.name {
+            Some(name) if *name != self.profile => {
+                write!(f, ", profile \"{}\" ({})", name, self.profile)
+            }
+            _ => write!(f, ", profile {}", self.profile),
+        }
+    }
+}
+
+/// Every cookie database under `home`, in the order they're tried: by browser, then by
+/// install, with each browser's default profile first
+pub fn discover(home: &Path) -> Vec<CookieStore> {
+    let mut stores = Vec::new();
+    for browser in Browser::ALL {
+        for &(install, dir) in data_dirs(browser) {
+            let dir = home.join(dir);
+            if !dir.is_dir() {
+                continue;
+            }
+            let profiles = match browser {
+                Browser::Firefox => firefox_profiles(&dir),
+                _ => chromium_profiles(&dir),
+            };
+            stores.extend(
+                profiles
+                    .into_iter()
+                    .map(|(profile, name, path)| CookieStore {
+                        browser,
+                        install,
+                        profile,
+                        name,
+                        path,
+                    }),
+            );
+        }
+    }
+    stores
+}
+
+/// Profile directories of a Chromium browser that have a cookie database, with the
+/// names from its `Local State`
+fn chromium_profiles(dir: &Path) -> Vec<(String, Option<String>, PathBuf)> {
+    let names = chromium_profile_names(dir);
+    let mut profiles: Vec<(String, PathBuf)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let profile = entry.file_name().to_str()?.to_string();
+            Some((profile, chromium_cookie_db(&entry.path())?))
+        })
+        .collect();
+    // Opera keeps its only profile in the data directory itself
+    if let Some(path) = chromium_cookie_db(dir) {
+        profiles.push(("Default".to_string(), path));
+    }
+    profiles.sort_by(|(a, _), (b, _)| (a != "Default", a).cmp(&(b != "Default", b)));
+    profiles
+        .into_iter()
+        .map(|(profile, path)| {
+            let name = names.get(&profile).cloned();
+            (profile, name, path)
+        })
+        .collect()
+}
+
+/// The cookie database of a Chromium profile, which moved into `Network` in Chrome 96
+fn chromium_cookie_db(profile: &Path) -> Option<PathBuf> {
+    [
+        profile.join("Network").join("Cookies"),
+        profile.join("Cookies"),
+    ]
+    .into_iter()
+    .find(|path| path.is_file())
+}
+
+/// Profile names by directory, from a Chromium browser's `Local State`
+fn chromium_profile_names(dir: &Path) -> HashMap<String, String> {
+    let state: serde_json::Value = match std::fs::read_to_string(dir.join("Local State"))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+    {
+        Some(state) => state,
+        None => return HashMap::new(),
+    };
+    state["profile"]["info_cache"]
+        .as_object()
+        .map(|cache| {
+            cache
+                .iter()
+                .filter_map(|(dir, info)| Some((dir.clone(), info["name"].as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Firefox profiles with a cookie database, from `profiles.ini`: the profile Firefox
+/// opens first, then the one marked default, then the rest. Without `profiles.ini`,
+/// every profile directory.
+fn firefox_profiles(dir: &Path) -> Vec<(String, Option<String>, PathBuf)> {
+    let sections =
+        parse_ini(&std::fs::read_to_string(dir.join("profiles.ini")).unwrap_or_default());
+
+    // Firefox 67 and later open the profile named by their install's section
+    let install_defaults: Vec<&str> = sections
+        .iter()
+        .filter(|(section, _)| section.starts_with("Install"))
+        .filter_map(|(_, values)| values.get("Default").map(String::as_str))
+        .collect();
+
+    let mut profiles: Vec<(u8, String, Option<String>, PathBuf)> = sections
+        .iter()
+        .filter(|(section, _)| section.starts_with("Profile"))
+        .filter_map(|(_, values)| {
+            let relative_path = values.get("Path")?;
+            let path = if values.get("IsRelative").map(String::as_str) == Some("0") {
+                PathBuf::from(relative_path)
+            } else {
+                dir.join(relative_path)
+            };
+            let rank = if install_defaults.contains(&relative_path.as_str()) {
+                0
+            } else if values.get("Default").map(String::as_str) == Some("1") {
+                1
+            } else {
+                2
+            };
+            let profile = path.file_name()?.to_str()?.to_string();
+            Some((rank, profile, values.get("Name").cloned(), path))
+        })
+        .collect();
+
+    if profiles.is_empty() {
+        profiles = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                Some((
+                    2,
+                    entry.file_name().to_str()?.to_string(),
+                    None,
+                    entry.path(),
+                ))
+            })
+            .collect();
+    }
+
+    profiles.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+    profiles
+        .into_iter()
+        .map(|(_, profile, name, path)| (profile, name, path.join("cookies.sqlite")))
+        .filter(|(_, _, path)| path.is_file())
+        .collect()
+}
+
+/// Sections of an INI file with their keys and values
+fn parse_ini(content: &str) -> Vec<(String, HashMap<String, String>)> {
+    let mut sections: Vec<(String, HashMap<String, String>)> = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            sections.push((section.to_string(), HashMap::new()));
+        } else if let (Some((key, value)), Some((_, values))) =
+            (line.split_once('='), sections.last_mut())
+        {
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    sections
+}
+
+/// Numbers the temporary copies of cookie databases, which are read concurrently
+static COPIES: AtomicUsize = AtomicUsize::new(0);
+
+/// Cookies for `domain` in a store, as names and values. The database is read from a
+/// copy, since the browser locks it while running.
+fn read(
+    store: &CookieStore,
+    domain: &str,
+    keys: impl FnOnce() -> Keys,
+) -> Result<Vec<(String, String)>> {
+    let temp_db = std::env::temp_dir().join(format!(
+        "beatcheck-cookies-{}-{}.sqlite",
+        std::process::id(),
+        COPIES.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::copy(&store.path, &temp_db)?;
+
+    let cookies = Connection::open_with_flags(&temp_db, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(Into::into)
+        .and_then(|conn| match store.browser {
+            Browser::Firefox => firefox_cookies(&conn, domain),
+            _ => chromium_cookies::read_cookies(&conn, domain, &keys()),
+        });
+
+    // Clean up temp file
+    let _ = std::fs::remove_file(&temp_db);
+
+    cookies
+}
+
+/// Unexpired cookies for `domain` from a Firefox `cookies.sqlite`
+fn firefox_cookies(conn: &Connection, domain: &str) -> Result<Vec<(String, String)>> {
+    // Current time in Unix timestamp (seconds) - Firefox uses standard Unix epoch
+    let now = chrono::Utc::now().timestamp();
+
+    // Cookies set for this host, and domain cookies for it or a parent domain
+    // (".nytimes.com" for www.nytimes.com), though not for a bare TLD like ".com"
+    let mut stmt = conn.prepare(
+        "SELECT name, value FROM moz_cookies
+         WHERE (host = ?1
+                OR (host LIKE '.%.%' AND substr('.' || ?1, -length(host)) = host))
+         AND expiry > ?2
+         AND name != '' AND value != ''",
+    )?;
+
+    let cookies = stmt
+        .query_map(params![domain, now], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(cookies)
+}
+
+/// What a lookup of a domain's cookies found
+#[derive(Debug)]
+pub struct Lookup {
+    /// The browser and profile configured for the domain
+    pub source: CookieSource,
+    /// Stores read, in order, with how many cookies each had for the domain or why it
+    /// couldn't be read. The last one is used when it had any.
+    pub tried: Vec<(CookieStore, std::result::Result<usize, String>)>,
+    pub cookies: Vec<(String, String)>,
+}
+
+impl Lookup {
+    /// The store the cookies came from
+    pub fn used(&self) -> Option<&CookieStore> {
+        match self.tried.last() {
+            Some((store, Ok(count))) if *count > 0 => Some(store),
+            _ => None,
+        }
+    }
+
+    /// The cookies as a `Cookie` header value
+    pub fn header(&self) -> String {
+        self.cookies
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Cookies from the browser profiles set in the `[cookies]` table
+pub struct CookieJar {
+    settings: CookiesConfig,
+    home: Option<PathBuf>,
+    /// Cookie decryption keys per browser, so the keyring is asked once per run. Each
+    /// browser has its own cell: waiting on one browser's keyring never holds up another.
+    keys: HashMap<Browser, OnceLock<Keys>>,
+}
+
+impl CookieJar {
+    pub fn new(settings: CookiesConfig) -> Self {
+        Self {
+            settings,
+            home: dirs::home_dir(),
+            keys: Browser::ALL
+                .map(|browser| (browser, OnceLock::new()))
+                .into(),
+        }
+    }
+
+    /// Every cookie database found, in the order they're tried
+    pub fn stores(&self) -> Vec<CookieStore> {
+        self.home.as_deref().map(discover).unwrap_or_default()
+    }
+
+    /// Cookies for `domain` from the first store of the configured browser and profile
+    /// that has any. Blocks on the file system and the keyring, so async code calls it
+    /// from `spawn_blocking`.
+    pub fn lookup(&self, domain: &str) -> Lookup {
+        let source = self.settings.source_for(domain);
+        let mut tried = Vec::new();
+        let mut cookies = Vec::new();
+        for store in self
+            .stores()
+            .into_iter()
+            .filter(|store| store.matches(&source))
+        {
+            match read(&store, domain, || self.keys(store.browser)) {
+                Ok(found) => {
+                    tried.push((store, Ok(found.len())));
+                    if !found.is_empty() {
+                        cookies = found;
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::debug!("Failed to read cookies from {}: {}", store, e);
+                    tried.push((store, Err(e.to_string())));
+                }
+            }
+        }
+        Lookup {
+            source,
+            tried,
+            cookies,
+        }
+    }
+
+    fn keys(&self, browser: Browser) -> Keys {
+        self.keys[&browser]
+            .get_or_init(|| Keys::for_application(keyring_application(browser)))
+            .clone()
+    }
+
+    /// Which cookie store is used for `url` and why, for `--cookies`. Lists cookie names
+    /// but never their values.
+    pub fn diagnose(&self, url: &str) -> Result<Vec<String>> {
+        let host = Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(String::from))
+            .ok_or_else(|| anyhow::anyhow!("Not a web address: {}", url))?;
+
+        let mut lines = vec!["Cookie stores found:".to_string()];
+        let stores = self.stores();
+        if stores.is_empty() {
+            lines.push("  none".to_string());
+        }
+        for store in &stores {
+            lines.push(format!("  {}: {}", store, store.path.display()));
+        }
+
+        let lookup = self.lookup(&host);
+        lines.push(String::new());
+        lines.push(format!(
+            "Configured for {}: {}, {}",
+            host,
+            lookup
+                .source
+                .browser
+                .map_or("any browser".to_string(), |browser| browser.to_string()),
+            lookup
+                .source
+                .profile
+                .as_ref()
+                .map_or("any profile".to_string(), |profile| format!(
+                    "profile {}",
+                    profile
+                ))
+        ));
+        for (store, result) in &lookup.tried {
+            lines.push(match result {
+                Ok(count) => format!("  {}: {} cookies", store, count),
+                Err(e) => format!("  {}: unreadable ({})", store, e),
+            });
+        }
+        lines.push(match lookup.used() {
+            Some(store) => format!(
+                "Using {} cookies from {}: {}",
+                lookup.cookies.len(),
+                store,
+                lookup
+                    .cookies
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None if lookup.tried.is_empty() => {
+                "No cookie store matches; fetching without cookies".to_string()
+            }
+            None => format!("No cookies for {}; fetching without cookies", host),
+        });
+        Ok(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    const KEYRING_PASSWORD: &[u8] = b"fixture-keyring-password";
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/cookies")
+            .join(name)
+    }
+
+    fn put(home: &Path, path: &str, from: Option<&str>) {
+        let path = home.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        match from {
+            Some(fixture_name) => {
+                std::fs::copy(fixture(fixture_name), &path).unwrap();
+            }
+            None => std::fs::write(&path, "").unwrap(),
+        }
+    }
+
+    /// Chrome with two profiles, a Flatpak Brave and a Snap Firefox with two profiles
+    fn home() -> tempfile::TempDir {
+        let home = tempfile::tempdir().unwrap();
+        let path = home.path();
+        put(
+            path,
+            ".config/google-chrome/Default/Network/Cookies",
+            Some("chromium-v18.sqlite"),
+        );
+        put(
+            path,
+            ".config/google-chrome/Profile 1/Cookies",
+            Some("chromium-v24.sqlite"),
+        );
+        std::fs::write(
+            path.join(".config/google-chrome/Local State"),
+            r#"{"profile": {"info_cache": {"Default": {"name": "Person 1"}, "Profile 1": {"name": "Work"}}}}"#,
+        )
+        .unwrap();
+        put(
+            path,
+            ".var/app/com.brave.Browser/config/BraveSoftware/Brave-Browser/Default/Network/Cookies",
+            Some("chromium-v18.sqlite"),
+        );
+
+        let firefox = path.join("snap/firefox/common/.mozilla/firefox");
+        std::fs::create_dir_all(firefox.join("abc.default")).unwrap();
+        std::fs::write(firefox.join("abc.default/cookies.sqlite"), "").unwrap();
+        std::fs::create_dir_all(firefox.join("xyz.default-release")).unwrap();
+        let conn = Connection::open(firefox.join("xyz.default-release/cookies.sqlite")).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE moz_cookies (id INTEGER PRIMARY KEY, name TEXT, value TEXT, host TEXT, expiry INTEGER);
+             INSERT INTO moz_cookies (name, value, host, expiry) VALUES
+                 ('nyt-s', 'abc', '.news.example.com', 4102444800),
+                 ('gone', 'old', 'news.example.com', 946684800);",
+        )
+        .unwrap();
+        std::fs::write(
+            firefox.join("profiles.ini"),
+            "[Profile1]\nName=default\nIsRelative=1\nPath=abc.default\nDefault=1\n\n\
+             [Profile0]\nName=default-release\nIsRelative=1\nPath=xyz.default-release\n\n\
+             [Install4F96D1932A9F858E]\nDefault=xyz.default-release\nLocked=1\n\n\
+             [General]\nStartWithLastProfile=1\nVersion=2\n",
+        )
+        .unwrap();
+        home
+    }
+
+    fn jar(home: &Path, settings: CookiesConfig) -> CookieJar {
+        CookieJar {
+            settings,
+            home: Some(home.to_path_buf()),
+            keys: Browser::ALL
+                .map(|browser| (browser, OnceLock::from(Keys::new(Some(KEYRING_PASSWORD)))))
+                .into(),
+        }
+    }
+
+    #[test]
+    fn test_discover_stores() {
+        let home = home();
+        let stores: Vec<String> = discover(home.path())
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(
+            stores,
+            [
+                "Chrome, profile \"Person 1\" (Default)",
+                "Chrome, profile \"Work\" (Profile 1)",
+                "Brave (Flatpak), profile Default",
+                "Firefox (Snap), profile \"default-release\" (xyz.default-release)",
+                "Firefox (Snap), profile \"default\" (abc.default)",
+            ]
+        );
+        assert!(discover(home.path())[0]
+            .path
+            .ends_with("Default/Network/Cookies"));
+    }
+
+    #[test]
+    fn test_lookup_tries_default_profile_first() {
+        let home = home();
+        let lookup = jar(home.path(), CookiesConfig::default()).lookup("news.example.com");
+        assert_eq!(lookup.used().map(|s| s.profile.as_str()), Some("Default"));
+        assert_eq!(
+            lookup.header(),
+            "session=s3ss10n=ok; plain=visible; subscriber=yes"
+        );
+    }
+
+    #[test]
+    fn test_lookup_pinned_per_domain() {
+        let home = home();
+        let settings = CookiesConfig {
+            browser: Some(Browser::Brave),
+            profile: None,
+            domains: BTreeMap::from([
+                (
+                    "example.com".to_string(),
+                    CookieSource {
+                        browser: Some(Browser::Chrome),
+                        profile: Some("work".to_string()),
+                    },
+                ),
+                (
+                    "news.example.com".to_string(),
+                    CookieSource {
+                        browser: Some(Browser::Firefox),
+                        profile: None,
+                    },
+                ),
+            ]),
+        };
+        let jar = jar(home.path(), settings);
+
+        let lookup = jar.lookup("news.example.com");
+        assert_eq!(lookup.used().unwrap().profile, "xyz.default-release");
+        assert_eq!(lookup.header(), "nyt-s=abc");
+
+        // Pinned to a profile without cookies for the domain
+        let lookup = jar.lookup("example.com");
+        assert_eq!(lookup.tried.len(), 1);
+        assert_eq!(lookup.tried[0].0.profile, "Profile 1");
+        assert!(lookup.used().is_none());
+
+        let lookup = jar.lookup("other.example.org");
+        assert_eq!(lookup.used().unwrap().browser, Browser::Brave);
+        assert_eq!(lookup.header(), "tracker=no");
+    }
+
+    #[test]
+    fn test_firefox_parent_domain_cookies() {
+        let home = home();
+        let conn = Connection::open(
+            home.path()
+                .join("snap/firefox/common/.mozilla/firefox/xyz.default-release/cookies.sqlite"),
+        )
+        .unwrap();
+        let names = |domain| -> Vec<String> {
+            firefox_cookies(&conn, domain)
+                .unwrap()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        };
+        assert_eq!(names("www.news.example.com"), vec!["nyt-s"]);
+        assert!(names("example.com").is_empty());
+        assert!(names("othernews.example.com").is_empty());
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let home = home();
+        let lines = jar(home.path(), CookiesConfig::default())
+            .diagnose("https://news.example.com/2026/10/story.html")
+            .unwrap();
+        assert_eq!(lines[0], "Cookie stores found:");
+        assert!(lines[1].starts_with("  Chrome, profile \"Person 1\" (Default): "));
+        assert!(lines
+            .contains(&"Configured for news.example.com: any browser, any profile".to_string()));
+        assert_eq!(
+            lines.last().unwrap(),
+            "Using 3 cookies from Chrome, profile \"Person 1\" (Default): session, plain, subscriber"
+        );
+        assert!(!lines.iter().any(|line| line.contains("s3ss10n")));
+
+        let empty = tempfile::tempdir().unwrap();
+        let lines = jar(empty.path(), CookiesConfig::default())
+            .diagnose("https://example.com/")
+            .unwrap();
+        assert_eq!(lines[1], "  none");
+        assert_eq!(
+            lines.last().unwrap(),
+            "No cookie store matches; fetching without cookies"
+        );
+        assert!(jar(empty.path(), CookiesConfig::default())
+            .diagnose("not a url")
+            .is_err());
+    }
+}
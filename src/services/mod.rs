@@ -1,5 +1,9 @@
+mod chromium_cookies;
 mod content_fetcher;
+mod cookies;
 mod raindrop;
+mod readability;
 
 pub use content_fetcher::ContentFetcher;
+pub use cookies::CookieJar;
 pub use raindrop::RaindropClient;
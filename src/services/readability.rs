@@ -0,0 +1,609 @@
+use std::collections::HashMap;
+
+use ego_tree::{NodeId, NodeRef};
+use scraper::{Html, Node};
+
+/// Text shorter than this doesn't count as a paragraph when scoring
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+/// How many ancestors of a paragraph share its score
+const SCORE_ANCESTORS: usize = 5;
+
+/// Never part of the article
+const SKIP_TAGS: &[&str] = &[
+    "script", "style", "noscript", "template", "svg", "canvas", "iframe", "object", "embed",
+    "form", "button", "input", "select", "textarea", "nav", "header", "footer", "aside", "dialog",
+    "menu", "figure", "head", "link", "meta",
+];
+
+/// Roles of page furniture
+const SKIP_ROLES: &[&str] = &[
+    "menu",
+    "menubar",
+    "complementary",
+    "navigation",
+    "alert",
+    "alertdialog",
+    "dialog",
+    "banner",
+    "contentinfo",
+];
+
+/// Class or id words of ads and bylines, left out even inside content-like containers
+const ALWAYS_UNLIKELY: &[&str] = &[
+    "-ad-", "ad-break", "ad-slot", "ad-unit", "advert", "byline", "dateline",
+];
+
+/// Class or id words of boilerplate: menus, banners, share bars, related links
+const UNLIKELY: &[&str] = &[
+    "agegate",
+    "banner",
+    "breadcrumb",
+    "combx",
+    "comment",
+    "community",
+    "consent",
+    "cookie",
+    "disqus",
+    "footer",
+    "gdpr",
+    "header",
+    "legends",
+    "menu",
+    "modal",
+    "newsletter",
+    "pager",
+    "pagination",
+    "paywall",
+    "popup",
+    "promo",
+    "related",
+    "remark",
+    "replies",
+    "rss",
+    "share",
+    "shoutbox",
+    "sidebar",
+    "signup",
+    "skyscraper",
+    "social",
+    "sponsor",
+    "subscribe",
+    "supplemental",
+    "yom-remote",
+];
+
+/// Class or id words that keep an element even when an unlikely word matches too
+const MAYBE: &[&str] = &[
+    "and", "article", "body", "column", "content", "main", "shadow",
+];
+
+/// Class or id words of article text
+const POSITIVE: &[&str] = &[
+    "article", "body", "content", "entry", "hentry", "h-entry", "main", "page", "post", "text",
+    "blog", "story",
+];
+
+/// Class or id words of everything else
+const NEGATIVE: &[&str] = &[
+    "-ad-",
+    "banner",
+    "combx",
+    "comment",
+    "com-",
+    "contact",
+    "cookie",
+    "footer",
+    "gdpr",
+    "masthead",
+    "media",
+    "meta",
+    "newsletter",
+    "outbrain",
+    "promo",
+    "related",
+    "scroll",
+    "share",
+    "shoutbox",
+    "sidebar",
+    "skyscraper",
+    "sponsor",
+    "shopping",
+    "tags",
+    "widget",
+];
+
+/// Elements that start a new paragraph of text
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "blockquote",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "td",
+    "th",
+    "tr",
+    "ul",
+];
+
+/// The main text of an article page, like Firefox's Reader View: paragraphs are scored
+/// by length and commas, their scores go to the elements around them, and the best
+/// scoring element (with siblings that look like part of it) is kept. Menus, cookie
+/// banners, share bars, related links and footers are left out. Paragraphs are separated
+/// by blank lines. None when the page has no article text.
+pub fn extract(html: &str) -> Option<String> {
+    // Parse errors are collected in the document, not logged
+    let document = Html::parse_document(html);
+    let root = document.tree.root();
+
+    let mut scores = HashMap::new();
+    score_paragraphs(root, &mut scores);
+    let mut lengths = HashMap::new();
+    measure_text(root, false, &mut lengths);
+
+    // Scores are cut by the share of link text, so lists of links never win
+    let (top, top_score) = scores
+        .iter()
+        .filter_map(|(&id, &score)| {
+            let node = document.tree.get(id)?;
+            Some((node, score * (1.0 - link_density(node, &lengths))))
+        })
+        .filter(|(_, score)| *score > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let mut paragraphs = Vec::new();
+    for node in article_nodes(top, top_score, &scores, &lengths) {
+        let mut current = String::new();
+        render(node, &lengths, &mut paragraphs, &mut current);
+        flush(&mut paragraphs, &mut current);
+    }
+    if paragraphs.is_empty() {
+        None
+    } else {
+        Some(paragraphs.join("\n\n"))
+    }
+}
+
+/// Give every paragraph's score to its ancestors: all of it to the parent, half to the
+/// grandparent, and less further up
+fn score_paragraphs(node: NodeRef<Node>, scores: &mut HashMap<NodeId, f64>) {
+    let Some(element) = node.value().as_element() else {
+        for child in node.children() {
+            score_paragraphs(child, scores);
+        }
+        return;
+    };
+    if is_skipped(node) {
+        return;
+    }
+
+    if is_paragraph(node) {
+        let text = inner_text(node);
+        let chars = text.chars().count();
+        if chars >= MIN_PARAGRAPH_CHARS {
+            let commas = text.matches([',', '，']).count() as f64;
+            let score = 1.0 + commas + (chars as f64 / 100.0).floor().min(3.0);
+            for (level, ancestor) in node
+                .ancestors()
+                .filter(|a| a.value().is_element())
+                .take(SCORE_ANCESTORS)
+                .enumerate()
+            {
+                let divider = match level {
+                    0 => 1.0,
+                    1 => 2.0,
+                    level => level as f64 * 3.0,
+                };
+                *scores
+                    .entry(ancestor.id())
+                    .or_insert_with(|| initial_score(ancestor)) += score / divider;
+            }
+        }
+    }
+
+    // A paragraph's own text was scored above; nested blocks are paragraphs of their own
+    if element.name() != "p" {
+        for child in node.children() {
+            score_paragraphs(child, scores);
+        }
+    }
+}
+
+/// Text blocks that are scored: paragraphs, and divs holding text rather than blocks
+fn is_paragraph(node: NodeRef<Node>) -> bool {
+    let Some(element) = node.value().as_element() else {
+        return false;
+    };
+    match element.name() {
+        "p" | "pre" | "td" | "blockquote" => true,
+        "div" | "section" => !node.children().any(|child| {
+            child
+                .value()
+                .as_element()
+                .is_some_and(|e| BLOCK_TAGS.contains(&e.name()) || e.name() == "img")
+        }),
+        _ => false,
+    }
+}
+
+/// Score of an element before any paragraph is counted: by tag and class
+fn initial_score(node: NodeRef<Node>) -> f64 {
+    let Some(element) = node.value().as_element() else {
+        return 0.0;
+    };
+    let by_tag = match element.name() {
+        "div" | "article" | "main" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    by_tag + class_weight(node)
+}
+
+/// +25 for classes and ids of article text, -25 for boilerplate
+fn class_weight(node: NodeRef<Node>) -> f64 {
+    let names = class_and_id(node);
+    let mut weight = 0.0;
+    if names.is_empty() {
+        return weight;
+    }
+    if contains_any(&names, NEGATIVE) {
+        weight -= 25.0;
+    }
+    if contains_any(&names, POSITIVE) {
+        weight += 25.0;
+    }
+    weight
+}
+
+/// The top candidate, and siblings that look like more of the article: well scored ones
+/// and paragraphs of real text
+fn article_nodes<'a>(
+    top: NodeRef<'a, Node>,
+    top_score: f64,
+    scores: &HashMap<NodeId, f64>,
+    lengths: &TextLengths,
+) -> Vec<NodeRef<'a, Node>> {
+    let Some(parent) = top.parent() else {
+        return vec![top];
+    };
+    let threshold = (top_score * 0.2).max(10.0);
+    parent
+        .children()
+        .filter(|sibling| {
+            if sibling.id() == top.id() {
+                return true;
+            }
+            let Some(element) = sibling.value().as_element() else {
+                return false;
+            };
+            if is_skipped(*sibling) {
+                return false;
+            }
+            if scores
+                .get(&sibling.id())
+                .is_some_and(|score| score * (1.0 - link_density(*sibling, lengths)) >= threshold)
+            {
+                return true;
+            }
+            if element.name() != "p" {
+                return false;
+            }
+            let text = inner_text(*sibling);
+            let chars = text.chars().count();
+            let density = link_density(*sibling, lengths);
+            (chars > 80 && density < 0.25) || (chars > 0 && density == 0.0 && text.ends_with('.'))
+        })
+        .collect()
+}
+
+/// Append the text under `node` to `paragraphs`, starting a new paragraph at each block.
+/// Boilerplate inside the article (share bars, lists of related links) is left out.
+fn render(
+    node: NodeRef<Node>,
+    lengths: &TextLengths,
+    paragraphs: &mut Vec<String>,
+    current: &mut String,
+) {
+    match node.value() {
+        Node::Text(text) => current.push_str(text),
+        Node::Element(element) => {
+            if is_skipped(node) {
+                return;
+            }
+            let name = element.name();
+            // A blank line made of two breaks ends a paragraph
+            if name == "br" {
+                if current.trim_end_matches([' ', '\t']).ends_with('\n') {
+                    flush(paragraphs, current);
+                } else {
+                    current.push('\n');
+                }
+                return;
+            }
+            if matches!(name, "ul" | "ol" | "div" | "section" | "table")
+                && link_density(node, lengths) > 0.5
+            {
+                return;
+            }
+            let block = BLOCK_TAGS.contains(&name);
+            if block {
+                flush(paragraphs, current);
+            }
+            if name == "li" {
+                current.push_str("- ");
+            }
+            for child in node.children() {
+                render(child, lengths, paragraphs, current);
+            }
+            if block {
+                flush(paragraphs, current);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// End the paragraph being written, with its whitespace collapsed
+fn flush(paragraphs: &mut Vec<String>, current: &mut String) {
+    let text = current.split_whitespace().collect::<Vec<_>>().join(" ");
+    current.clear();
+    if !text.is_empty() && text != "-" {
+        paragraphs.push(text);
+    }
+}
+
+/// Whether an element is page furniture that's never part of the article
+fn is_skipped(node: NodeRef<Node>) -> bool {
+    let Some(element) = node.value().as_element() else {
+        return false;
+    };
+    let name = element.name();
+    if SKIP_TAGS.contains(&name) {
+        return true;
+    }
+    if element.attr("hidden").is_some() || element.attr("aria-hidden") == Some("true") {
+        return true;
+    }
+    if element.attr("style").is_some_and(|style| {
+        let style = style.replace(' ', "").to_lowercase();
+        style.contains("display:none") || style.contains("visibility:hidden")
+    }) {
+        return true;
+    }
+    if element
+        .attr("role")
+        .is_some_and(|role| SKIP_ROLES.contains(&role.to_lowercase().as_str()))
+    {
+        return true;
+    }
+    if matches!(name, "html" | "body" | "article" | "main" | "a") {
+        return false;
+    }
+    let names = class_and_id(node);
+    contains_any(&names, ALWAYS_UNLIKELY)
+        || (contains_any(&names, UNLIKELY) && !contains_any(&names, MAYBE))
+}
+
+/// Class and id of an element, lowercased
+fn class_and_id(node: NodeRef<Node>) -> String {
+    let Some(element) = node.value().as_element() else {
+        return String::new();
+    };
+    format!(
+        "{} {}",
+        element.attr("class").unwrap_or_default(),
+        element.id().unwrap_or_default()
+    )
+    .trim()
+    .to_lowercase()
+}
+
+fn contains_any(names: &str, words: &[&str]) -> bool {
+    words.iter().any(|word| names.contains(word))
+}
+
+/// Text under `node`, skipping page furniture, with whitespace collapsed
+fn inner_text(node: NodeRef<Node>) -> String {
+    let mut text = String::new();
+    collect_text(node, &mut text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn collect_text(node: NodeRef<Node>, text: &mut String) {
+    match node.value() {
+        Node::Text(t) => text.push_str(t),
+        Node::Element(_) if is_skipped(node) => {}
+        Node::Element(element) => {
+            // Blocks are separate words; inline elements run on
+            let block = BLOCK_TAGS.contains(&element.name()) || element.name() == "br";
+            if block {
+                text.push(' ');
+            }
+            for child in node.children() {
+                collect_text(child, text);
+            }
+            if block {
+                text.push(' ');
+            }
+        }
+        _ => {
+            for child in node.children() {
+                collect_text(child, text);
+            }
+        }
+    }
+}
+
+/// Characters of text and of link text under each element, whitespace not counted
+type TextLengths = HashMap<NodeId, (usize, usize)>;
+
+/// Count the text and link text under every element in one walk of the page, leaving
+/// out page furniture like `inner_text` does
+fn measure_text(node: NodeRef<Node>, in_link: bool, lengths: &mut TextLengths) -> (usize, usize) {
+    match node.value() {
+        Node::Text(text) => {
+            let chars = text.chars().filter(|c| !c.is_whitespace()).count();
+            (chars, if in_link { chars } else { 0 })
+        }
+        Node::Element(_) if is_skipped(node) => (0, 0),
+        value => {
+            let in_link = in_link || value.as_element().is_some_and(|e| e.name() == "a");
+            let (mut text, mut links) = (0, 0);
+            for child in node.children() {
+                let (child_text, child_links) = measure_text(child, in_link, lengths);
+                text += child_text;
+                links += child_links;
+            }
+            lengths.insert(node.id(), (text, links));
+            (text, links)
+        }
+    }
+}
+
+/// Share of the text under `node` that is link text
+fn link_density(node: NodeRef<Node>, lengths: &TextLengths) -> f64 {
+    match lengths.get(&node.id()) {
+        Some(&(text, links)) if text > 0 => links as f64 / text as f64,
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        let path = format!(
+            "{}/tests/fixtures/pages/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+    }
+
+    /// Every phrase is in the text and none of the boilerplate is
+    fn assert_extracted(text: &str, present: &[&str], absent: &[&str]) {
+        for phrase in present {
+            assert!(text.contains(phrase), "missing {:?} in:\n{}", phrase, text);
+        }
+        for phrase in absent {
+            assert!(!text.contains(phrase), "kept {:?} in:\n{}", phrase, text);
+        }
+    }
+
+    #[test]
+    fn test_wordpress_article() {
+        let text = extract(&fixture("wordpress.html")).unwrap();
+        assert_extracted(
+            &text,
+            &[
+                "Stack Overflow published its analysis of 2017 hiring trends",
+                "I wonder how many of these skills are no longer listed",
+                "These are both highly portable skills that are in demand on the web.",
+            ],
+            &[
+                "Secondary Menu",
+                "Sarah Gooding",
+                "Click to share on Twitter",
+                "Stack Overflow Developer Survey Ranks WordPress as the 3rd Most Dreaded",
+                "If there were really no demand for WordPress developers",
+                "Your email address will not be published",
+                "Previous Post",
+                "Primary Sidebar",
+            ],
+        );
+        // One paragraph per block, in page order
+        let first = text.find("Stack Overflow published").unwrap();
+        let last = text.find("highly portable skills").unwrap();
+        assert!(first < last);
+        assert!(text.contains(".\n\nStack Overflow also measured"));
+    }
+
+    #[test]
+    fn test_medium_post() {
+        let text = extract(&fixture("medium.html")).unwrap();
+        assert_extracted(
+            &text,
+            &[
+                "We pushed out the first version of the Open Journalism site in January.",
+                "\n\nCirca 2011\n\n",
+                "We’re all in this together.",
+            ],
+            &[
+                "Sign in / Sign up",
+                "Ready to publish?",
+                "Share on Twitter",
+                "Yes, show me sharing options",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_news_site() {
+        let text = extract(&fixture("theverge.html")).unwrap();
+        assert_extracted(
+            &text,
+            &[
+                "I still remember using the iPhone 4 for the first time in 2010.",
+                "The headset packs an insane 23 megapixels",
+                "Like that first Retina display, it’s something you can’t unsee.",
+            ],
+            &[
+                "Skip to main content",
+                "Command Line newsletter",
+                "Share this story",
+                "Image: Vjeran Pavic / The Verge",
+                "Google confirms it just laid off around a thousand employees",
+                "Sign up for Verge Deals",
+                "More from this stream",
+                "All Rights Reserved",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_double_line_break_ends_paragraph() {
+        let html = "<html><body><article><div class=\"post-body\">\
+            Good morning. Three things happened this week, and all of them matter.<br>\
+            The first one is about phones.<br><br>\
+            Second, a chip maker, a bank and a regulator had a very busy Tuesday.\
+            </div></article></body></html>";
+        assert_eq!(
+            extract(html).unwrap(),
+            "Good morning. Three things happened this week, and all of them matter. \
+             The first one is about phones.\n\n\
+             Second, a chip maker, a bank and a regulator had a very busy Tuesday."
+        );
+    }
+
+    #[test]
+    fn test_page_without_article_text() {
+        let html = "<html><body>\
+            <nav><a href=\"/\">Home</a> <a href=\"/news\">News</a></nav>\
+            <ul><li><a href=\"/1\">Apple announces the iPhone 17 at its September event</a></li>\
+            <li><a href=\"/2\">The FCC votes to require broadband labels on every plan</a></li></ul>\
+            <footer>© 2025 Example Media. All rights reserved.</footer>\
+            </body></html>";
+        assert_eq!(extract(html), None);
+        assert_eq!(extract(""), None);
+    }
+}
@@ -45,77 +45,233 @@ pub enum AppAction {
     // Space prefix mode for quick bookmarks
     BookmarkPrefixStart,
     CancelBookmarkPrefix,
+    // Blocklist filtered view actions
+    ShowFiltered,
+    HideFiltered,
+    FilteredMoveUp,
+    FilteredMoveDown,
+    RescueFiltered,
+    OpenFilteredInBrowser,
+    ReapplyBlocklist,
+    ToggleCluster,
+    ShowTemplatePicker,
+    TemplatePickerMoveUp,
+    TemplatePickerMoveDown,
+    TemplatePickerConfirm,
+    TemplatePickerCancel,
+    ShowBlocklist,
+    HideBlocklist,
+    BlocklistMoveUp,
+    BlocklistMoveDown,
+    BlocklistRemove,
+    BlocklistInputStart,
+    BlocklistInputChar(char),
+    BlocklistInputBackspace,
+    BlocklistInputConfirm,
+    BlocklistInputCancel,
+    BlockThis,
+    BlockSuggestMoveUp,
+    BlockSuggestMoveDown,
+    BlockSuggestConfirm,
+    BlockSuggestEdit,
+    BlockSuggestCancel,
+    ShowStats,
+    CloseStats,
+    WriteBriefing,
+    Translate,
+    // Follow-up chat about the selected article
+    ShowChat,
+    ChatInputChar(char),
+    ChatInputBackspace,
+    ChatSend,
+    ChatClear,
+    CloseChat,
+    // Show rundown built from quick bookmarks
+    ShowRundown,
+    CloseRundown,
+    RundownNextShow,
+    RundownPrevShow,
+    RundownMoveUp,
+    RundownMoveDown,
+    RundownMoveItemUp,
+    RundownMoveItemDown,
+    RundownNoteStart,
+    RundownNoteChar(char),
+    RundownNoteBackspace,
+    RundownNoteConfirm,
+    RundownNoteCancel,
+    RundownExport,
+    RundownFinish,
 }
 
-pub fn handle_key_event(
-    key: KeyEvent,
-    tag_input_active: bool,
-    feed_input_active: bool,
-    opml_input_active: bool,
-    opml_export_active: bool,
-    show_help: bool,
-    bookmark_prefix_active: bool,
-) -> Option<AppAction> {
-    // If help is showing, any key closes it
-    if show_help {
-        return Some(AppAction::HideHelp);
-    }
+/// Which popup or prompt currently receives key presses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    Normal,
+    Help,
+    BookmarkPrefix,
+    TagInput,
+    FeedInput,
+    OpmlInput,
+    OpmlExport,
+    FilteredView,
+    BlocklistInput,
+    BlocklistView,
+    BlockSuggest,
+    TemplatePicker,
+    Stats,
+    Chat,
+    Rundown,
+    RundownNote,
+}
 
-    // Space prefix mode (waiting for second key after Space)
-    if bookmark_prefix_active {
-        return match key.code {
+pub fn handle_key_event(key: KeyEvent, mode: InputMode) -> Option<AppAction> {
+    match mode {
+        // If help is showing, any key closes it
+        InputMode::Help => Some(AppAction::HideHelp),
+
+        // Space prefix mode (waiting for second key after Space)
+        InputMode::BookmarkPrefix => match key.code {
             KeyCode::Char('t') => Some(AppAction::SaveToRaindropWithTag("twit".to_string())),
             KeyCode::Char('i') => Some(AppAction::SaveToRaindropWithTag("im".to_string())),
             KeyCode::Char('m') => Some(AppAction::SaveToRaindropWithTag("mbw".to_string())),
             KeyCode::Esc => Some(AppAction::CancelBookmarkPrefix),
             _ => Some(AppAction::CancelBookmarkPrefix), // Any other key cancels
-        };
-    }
+        },
 
-    // Tag input mode
-    if tag_input_active {
-        return match key.code {
+        // Tag input mode
+        InputMode::TagInput => match key.code {
             KeyCode::Enter => Some(AppAction::TagInputConfirm),
             KeyCode::Esc => Some(AppAction::TagInputCancel),
             KeyCode::Backspace => Some(AppAction::TagInputBackspace),
             KeyCode::Char(c) => Some(AppAction::TagInputChar(c)),
             _ => None,
-        };
-    }
+        },
 
-    // Feed input mode
-    if feed_input_active {
-        return match key.code {
+        // Feed input mode
+        InputMode::FeedInput => match key.code {
             KeyCode::Enter => Some(AppAction::FeedInputConfirm),
             KeyCode::Esc => Some(AppAction::FeedInputCancel),
             KeyCode::Backspace => Some(AppAction::FeedInputBackspace),
             KeyCode::Char(c) => Some(AppAction::FeedInputChar(c)),
             _ => None,
-        };
-    }
+        },
 
-    // OPML import input mode
-    if opml_input_active {
-        return match key.code {
+        // OPML import input mode
+        InputMode::OpmlInput => match key.code {
             KeyCode::Enter => Some(AppAction::OpmlInputConfirm),
             KeyCode::Esc => Some(AppAction::OpmlInputCancel),
             KeyCode::Backspace => Some(AppAction::OpmlInputBackspace),
             KeyCode::Char(c) => Some(AppAction::OpmlInputChar(c)),
             _ => None,
-        };
-    }
+        },
 
-    // OPML export input mode
-    if opml_export_active {
-        return match key.code {
+        // OPML export input mode
+        InputMode::OpmlExport => match key.code {
             KeyCode::Enter => Some(AppAction::OpmlExportConfirm),
             KeyCode::Esc => Some(AppAction::OpmlExportCancel),
             KeyCode::Backspace => Some(AppAction::OpmlExportBackspace),
             KeyCode::Char(c) => Some(AppAction::OpmlExportChar(c)),
             _ => None,
-        };
+        },
+
+        // Blocklist filtered view
+        InputMode::FilteredView => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Some(AppAction::FilteredMoveDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(AppAction::FilteredMoveUp),
+            KeyCode::Enter => Some(AppAction::RescueFiltered),
+            KeyCode::Char('o') => Some(AppAction::OpenFilteredInBrowser),
+            KeyCode::Esc | KeyCode::Char('f') | KeyCode::Char('q') => Some(AppAction::HideFiltered),
+            _ => None,
+        },
+
+        // Blocklist screen: typing a new rule
+        InputMode::BlocklistInput => match key.code {
+            KeyCode::Enter => Some(AppAction::BlocklistInputConfirm),
+            KeyCode::Esc => Some(AppAction::BlocklistInputCancel),
+            KeyCode::Backspace => Some(AppAction::BlocklistInputBackspace),
+            KeyCode::Char(c) => Some(AppAction::BlocklistInputChar(c)),
+            _ => None,
+        },
+
+        // Blocklist screen
+        InputMode::BlocklistView => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Some(AppAction::BlocklistMoveDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(AppAction::BlocklistMoveUp),
+            KeyCode::Char('a') => Some(AppAction::BlocklistInputStart),
+            KeyCode::Char('d') | KeyCode::Delete => Some(AppAction::BlocklistRemove),
+            KeyCode::Esc | KeyCode::Char('B') | KeyCode::Char('q') => {
+                Some(AppAction::HideBlocklist)
+            }
+            _ => None,
+        },
+
+        // "Block this" rule suggestions
+        InputMode::BlockSuggest => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Some(AppAction::BlockSuggestMoveDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(AppAction::BlockSuggestMoveUp),
+            KeyCode::Enter => Some(AppAction::BlockSuggestConfirm),
+            KeyCode::Char('e') => Some(AppAction::BlockSuggestEdit),
+            KeyCode::Esc | KeyCode::Char('q') => Some(AppAction::BlockSuggestCancel),
+            _ => None,
+        },
+
+        // Prompt template picker for regenerating a summary
+        InputMode::TemplatePicker => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Some(AppAction::TemplatePickerMoveDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(AppAction::TemplatePickerMoveUp),
+            KeyCode::Enter => Some(AppAction::TemplatePickerConfirm),
+            KeyCode::Esc | KeyCode::Char('q') => Some(AppAction::TemplatePickerCancel),
+            _ => None,
+        },
+
+        // Usage and cost screen
+        InputMode::Stats => match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('$') => Some(AppAction::CloseStats),
+            _ => None,
+        },
+
+        // Follow-up chat: typing a question
+        InputMode::Chat => match (key.code, key.modifiers) {
+            (KeyCode::Enter, _) => Some(AppAction::ChatSend),
+            (KeyCode::Esc, _) => Some(AppAction::CloseChat),
+            (KeyCode::Backspace, _) => Some(AppAction::ChatInputBackspace),
+            (KeyCode::Char('l'), KeyModifiers::CONTROL) => Some(AppAction::ChatClear),
+            (KeyCode::Char(c), _) => Some(AppAction::ChatInputChar(c)),
+            _ => None,
+        },
+
+        // Show rundown screen
+        InputMode::Rundown => match key.code {
+            KeyCode::Char('j') | KeyCode::Down => Some(AppAction::RundownMoveDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(AppAction::RundownMoveUp),
+            KeyCode::Char('J') => Some(AppAction::RundownMoveItemDown),
+            KeyCode::Char('K') => Some(AppAction::RundownMoveItemUp),
+            KeyCode::Tab | KeyCode::Char('l') | KeyCode::Right => Some(AppAction::RundownNextShow),
+            KeyCode::BackTab | KeyCode::Char('h') | KeyCode::Left => {
+                Some(AppAction::RundownPrevShow)
+            }
+            KeyCode::Enter | KeyCode::Char('e') => Some(AppAction::RundownNoteStart),
+            KeyCode::Char('w') => Some(AppAction::RundownExport),
+            KeyCode::Char('N') => Some(AppAction::RundownFinish),
+            KeyCode::Esc | KeyCode::Char('R') | KeyCode::Char('q') => Some(AppAction::CloseRundown),
+            _ => None,
+        },
+
+        // Show rundown screen: typing a note
+        InputMode::RundownNote => match key.code {
+            KeyCode::Enter => Some(AppAction::RundownNoteConfirm),
+            KeyCode::Esc => Some(AppAction::RundownNoteCancel),
+            KeyCode::Backspace => Some(AppAction::RundownNoteBackspace),
+            KeyCode::Char(c) => Some(AppAction::RundownNoteChar(c)),
+            _ => None,
+        },
+
+        InputMode::Normal => handle_normal_key(key),
     }
+}
 
+fn handle_normal_key(key: KeyEvent) -> Option<AppAction> {
     // Normal mode
     match (key.code, key.modifiers) {
         (KeyCode::Char('q'), _) => Some(AppAction::Quit),
@@ -134,6 +290,7 @@ pub fn handle_key_event(
         (KeyCode::Char('b'), _) => Some(AppAction::SaveToRaindrop),
         (KeyCode::Char(' '), _) => Some(AppAction::BookmarkPrefixStart),
         (KeyCode::Char('g'), _) => Some(AppAction::RegenerateSummary),
+        (KeyCode::Char('G'), _) => Some(AppAction::ShowTemplatePicker),
         (KeyCode::Char('d'), KeyModifiers::NONE) | (KeyCode::Backspace, _) => {
             Some(AppAction::DeleteArticle)
         }
@@ -142,6 +299,16 @@ pub fn handle_key_event(
         (KeyCode::Char('a'), _) => Some(AppAction::AddFeed),
         (KeyCode::Char('i'), _) => Some(AppAction::ImportOpmlStart),
         (KeyCode::Char('w'), _) => Some(AppAction::ExportOpmlStart),
+        (KeyCode::Char('f'), _) => Some(AppAction::ShowFiltered),
+        (KeyCode::Char('F'), _) => Some(AppAction::ReapplyBlocklist),
+        (KeyCode::Char('B'), _) => Some(AppAction::ShowBlocklist),
+        (KeyCode::Char('x'), _) => Some(AppAction::BlockThis),
+        (KeyCode::Char('c'), KeyModifiers::NONE) => Some(AppAction::ToggleCluster),
+        (KeyCode::Char('$'), _) => Some(AppAction::ShowStats),
+        (KeyCode::Char('n'), _) => Some(AppAction::WriteBriefing),
+        (KeyCode::Char('A'), _) => Some(AppAction::ShowChat),
+        (KeyCode::Char('t'), _) => Some(AppAction::Translate),
+        (KeyCode::Char('R'), _) => Some(AppAction::ShowRundown),
 
         (KeyCode::Char('?'), _) => Some(AppAction::ShowHelp),
 
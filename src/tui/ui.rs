@@ -2,13 +2,14 @@ use chrono::Datelike;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-use crate::app::App;
-use crate::models::SummaryStatus;
+use crate::app::{App, ArticleRow};
+use crate::models::{ChatRole, SummaryFields, SummaryKind, SummaryStatus};
+use crate::rundown::SHOWS;
 
 pub fn draw(frame: &mut Frame, app: &App) {
     // Main vertical split: content area + status bar
@@ -80,6 +81,41 @@ pub fn draw(frame: &mut Frame, app: &App) {
         render_opml_export(frame, app);
     }
 
+    // Render blocklist filtered view if active
+    if app.filtered_view_active {
+        render_filtered_view(frame, app);
+    }
+
+    // Render blocklist screen if active
+    if app.blocklist_view_active {
+        render_blocklist_view(frame, app);
+    }
+
+    // Render "block this" suggestions if active
+    if app.block_suggest_active {
+        render_block_suggest(frame, app);
+    }
+
+    // Render template picker if active
+    if app.template_picker_active {
+        render_template_picker(frame, app);
+    }
+
+    // Render usage and cost screen if active
+    if app.stats_active {
+        render_stats(frame, app);
+    }
+
+    // Render follow-up chat if active
+    if app.chat_active {
+        render_chat(frame, app);
+    }
+
+    // Render show rundown if active
+    if app.rundown_active {
+        render_rundown(frame, app);
+    }
+
     // Render help popup if active
     if app.show_help {
         render_help(frame);
@@ -116,11 +152,12 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_article_list(frame: &mut Frame, app: &App, area: Rect) {
-    let articles = app.filtered_articles();
+    let rows = app.visible_rows();
 
-    let items: Vec<ListItem> = articles
+    let items: Vec<ListItem> = rows
         .iter()
-        .map(|article| {
+        .map(|row| {
+            let article = row.article();
             let (day, date) = article
                 .published_at
                 .map(|dt| {
@@ -139,15 +176,41 @@ fn render_article_list(frame: &mut Frame, app: &App, area: Rect) {
                 .unwrap_or(("? ", "??-??".to_string()));
             let feed = article.feed_title.as_deref().unwrap_or("Unknown");
 
-            let line = Line::from(vec![
+            let mut spans = vec![
                 Span::styled(day, Style::default().fg(Color::DarkGray)),
                 Span::styled(" ", Style::default()),
                 Span::styled(date, Style::default().fg(Color::DarkGray)),
                 Span::styled(" ", Style::default()),
-                Span::styled(feed.to_string(), Style::default().fg(Color::White)),
-            ]);
-
-            ListItem::new(line)
+            ];
+            match row {
+                ArticleRow::Single(_) => {
+                    spans.push(Span::styled(
+                        feed.to_string(),
+                        Style::default().fg(Color::White),
+                    ));
+                }
+                ArticleRow::Cluster {
+                    members, expanded, ..
+                } => {
+                    spans.push(Span::styled(
+                        feed.to_string(),
+                        Style::default().fg(Color::White),
+                    ));
+                    let marker = if *expanded { "▾" } else { "▸" };
+                    spans.push(Span::styled(
+                        format!(" {} +{}", marker, members.len() - 1),
+                        Style::default().fg(Color::Cyan),
+                    ));
+                }
+                ArticleRow::Member(_) => {
+                    spans.push(Span::styled(
+                        format!("└ {}", feed),
+                        Style::default().fg(Color::Gray),
+                    ));
+                }
+            }
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -172,6 +235,16 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
             format!("{} Refreshing...", app.spinner_char()),
             Color::DarkGray,
         )
+    } else if app.is_writing_briefing() {
+        (
+            format!("{} Writing briefing...", app.spinner_char()),
+            Color::DarkGray,
+        )
+    } else if app.is_translating() {
+        (
+            format!("{} Translating...", app.spinner_char()),
+            Color::DarkGray,
+        )
     } else if matches!(app.summary_status, SummaryStatus::Generating) {
         (
             format!("{} Summarizing...", app.spinner_char()),
@@ -210,13 +283,21 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_article_title(frame: &mut Frame, app: &App, area: Rect) {
-    let title = app
-        .selected_article()
-        .map(|a| a.title.as_str())
-        .unwrap_or("No article selected");
+    let translation = app.shown_translation();
+    let title = match translation {
+        Some(translation) => translation.title.as_str(),
+        None => app
+            .selected_article()
+            .map(|a| a.title.as_str())
+            .unwrap_or("No article selected"),
+    };
 
     let block = Block::default()
-        .title(" Article ")
+        .title(if translation.is_some() {
+            " Article (translated) "
+        } else {
+            " Article "
+        })
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green));
 
@@ -228,14 +309,25 @@ fn render_article_title(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_feed_content(frame: &mut Frame, app: &App, area: Rect) {
-    let content = app
-        .selected_article()
-        .and_then(|a| a.content_text.as_ref().or(a.content.as_ref()))
-        .map(|c| c.split_whitespace().collect::<Vec<_>>().join(" "))
-        .unwrap_or_else(|| "No content available".to_string());
+    let translation = app.shown_translation();
+    let content = match translation {
+        Some(translation) => translation.text.clone(),
+        None => app
+            .selected_article()
+            .and_then(|a| a.content_text.as_ref().or(a.content.as_ref()))
+            .map(|c| c.split_whitespace().collect::<Vec<_>>().join(" "))
+            .unwrap_or_else(|| "No content available".to_string()),
+    };
 
+    // Name the language of an article that can be translated
+    let title = match (translation, app.translatable_language()) {
+        (Some(_), Some(language)) => format!(" Feed Content (translated from {}) ", language),
+        (Some(_), None) => " Feed Content (translated) ".to_string(),
+        (None, Some(language)) => format!(" Feed Content ({}, t to translate) ", language),
+        (None, None) => " Feed Content ".to_string(),
+    };
     let block = Block::default()
-        .title(" Feed Content ")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue));
 
@@ -249,9 +341,17 @@ fn render_feed_content(frame: &mut Frame, app: &App, area: Rect) {
 fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
     let content = match app.summary_status {
         SummaryStatus::NotGenerated => "Press Enter to generate summary...".to_string(),
-        SummaryStatus::Generating => format!("{} Generating summary...", app.spinner_char()),
-        SummaryStatus::Failed => "Failed to generate summary. Press 'g' to retry.".to_string(),
-        SummaryStatus::NoApiKey => "Claude API key not configured.\n\nPlease add your API key to:\n~/.config/beatcheck/config.toml\n\nExample:\nclaude_api_key = \"sk-ant-...\"".to_string(),
+        SummaryStatus::Generating if app.streaming_summary.trim().is_empty() => {
+            format!("{} Generating summary...", app.spinner_char())
+        }
+        // Show the text received so far, as it will read once the reply is parsed
+        SummaryStatus::Generating => crate::ai::preview_summary(&app.streaming_summary),
+        SummaryStatus::Failed => match app.summary_error.as_deref() {
+            Some(error) if error.contains("'g'") => format!("Failed to generate summary.\n\n{}", error),
+            Some(error) => format!("Failed to generate summary.\n\n{}\n\nPress 'g' to retry.", error),
+            None => "Failed to generate summary. Press 'g' to retry.".to_string(),
+        },
+        SummaryStatus::NoApiKey => "Summarizer API key not configured.\n\nPlease add your API key to:\n~/.config/beatcheck/config.toml\n\nExample:\nclaude_api_key = \"sk-ant-...\"\n\nOr use a local model:\n[summarizer]\nbackend = \"ollama\"".to_string(),
         SummaryStatus::Generated => app
             .current_summary
             .as_ref()
@@ -259,18 +359,172 @@ fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
             .unwrap_or_else(|| "No summary available".to_string()),
     };
 
+    // Name the template when the summary came from a non-default one, and the source
+    // when it was copied from an identical story
+    let title = match (&app.summary_status, &app.current_summary) {
+        (SummaryStatus::Generated, Some(summary)) => {
+            let mut notes = Vec::new();
+            if let Some(template) = summary.template.as_deref() {
+                if Some(template) != app.default_template() {
+                    notes.push(template.to_string());
+                }
+            }
+            if let Some(source) = &summary.reused_from {
+                notes.push(format!("reused from {}", source));
+            }
+            if notes.is_empty() {
+                " AI Summary ".to_string()
+            } else {
+                format!(" AI Summary ({}) ", notes.join(", "))
+            }
+        }
+        (SummaryStatus::Generating, _) if !app.streaming_summary.trim().is_empty() => {
+            format!(" AI Summary {} ", app.spinner_char())
+        }
+        _ => " AI Summary ".to_string(),
+    };
+
     let block = Block::default()
-        .title(" AI Summary ")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Magenta));
 
-    let paragraph = Paragraph::new(content)
-        .block(block)
-        .wrap(Wrap { trim: true });
+    // Flag quotes that aren't word for word in the article
+    let unverified = match (&app.summary_status, &app.current_summary) {
+        (SummaryStatus::Generated, Some(summary)) => {
+            summary.unverified_quotes.clone().unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    let fields = match (&app.summary_status, &app.current_summary) {
+        (SummaryStatus::Generated, Some(summary)) => summary.fields.as_ref(),
+        _ => None,
+    };
+    let text = if let Some(fields) = fields {
+        Text::from(summary_field_lines(fields, &unverified))
+    } else if unverified.is_empty() {
+        Text::from(content)
+    } else {
+        Text::from(highlight_unverified_quotes(&content, &unverified))
+    };
+
+    let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
 
     frame.render_widget(paragraph, area);
 }
 
+/// A structured summary with each part labelled, and its quote flagged if unverified
+fn summary_field_lines(fields: &SummaryFields, unverified: &[String]) -> Vec<Line<'static>> {
+    let label = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let kind_style = match fields.kind {
+        SummaryKind::Editorial => Style::default().fg(Color::Magenta),
+        SummaryKind::Product => Style::default().fg(Color::Green),
+    };
+
+    let mut lines = vec![
+        Line::styled(
+            fields.kind.as_str().to_uppercase(),
+            kind_style.add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+        Line::styled(
+            fields.lede.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+    ];
+    for (name, value) in [
+        ("Why it matters", &fields.why_it_matters),
+        ("Price", &fields.price),
+        ("Availability", &fields.availability),
+        ("Platforms", &fields.platforms),
+    ] {
+        if let Some(value) = value {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", name), label),
+                Span::raw(value.clone()),
+            ]));
+        }
+    }
+
+    let mut quote_unverified = false;
+    if let Some(quote) = &fields.quote {
+        quote_unverified = unverified.iter().any(|q| q == quote);
+        let (style, mark) = if quote_unverified {
+            (unverified_style(), " ⚠")
+        } else {
+            (Style::default().add_modifier(Modifier::ITALIC), "")
+        };
+        let mut spans = vec![Span::styled(format!("“{}”{}", quote, mark), style)];
+        if let Some(speaker) = &fields.speaker {
+            spans.push(Span::styled(
+                format!(" — {}", speaker),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(spans));
+    }
+    if quote_unverified {
+        lines.extend(unverified_warning(1));
+    }
+    lines
+}
+
+fn unverified_style() -> Style {
+    Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::UNDERLINED)
+}
+
+/// The footer under a summary with quotes not found in the article
+fn unverified_warning(count: usize) -> [Line<'static>; 2] {
+    [
+        Line::from(""),
+        Line::styled(
+            format!(
+                "⚠ {} not found word for word in the article. Check before using.",
+                if count == 1 {
+                    "1 quote".to_string()
+                } else {
+                    format!("{} quotes", count)
+                }
+            ),
+            Style::default().fg(Color::Yellow),
+        ),
+    ]
+}
+
+/// Summary lines with each unverified quote highlighted and marked, plus a warning line
+fn highlight_unverified_quotes(content: &str, unverified: &[String]) -> Vec<Line<'static>> {
+    let warning = unverified_style();
+
+    let mut lines: Vec<Line> = content
+        .lines()
+        .map(|line| {
+            let mut spans = Vec::new();
+            let mut rest = line;
+            // Earliest unverified quote in what's left of the line
+            while let Some((at, quote)) = unverified
+                .iter()
+                .filter_map(|quote| rest.find(quote.as_str()).map(|at| (at, quote)))
+                .min_by_key(|(at, _)| *at)
+            {
+                spans.push(Span::raw(rest[..at].to_string()));
+                spans.push(Span::styled(format!("{} ⚠", quote), warning));
+                rest = &rest[at + quote.len()..];
+            }
+            spans.push(Span::raw(rest.to_string()));
+            Line::from(spans)
+        })
+        .collect();
+
+    lines.extend(unverified_warning(unverified.len()));
+    lines
+}
+
 fn render_tag_input(frame: &mut Frame, app: &App) {
     let area = centered_rect(60, 20, frame.area());
 
@@ -285,9 +539,22 @@ fn render_tag_input(frame: &mut Frame, app: &App) {
     frame.render_widget(ratatui::widgets::Clear, area);
     frame.render_widget(block, area);
 
-    let input_text = format!("> {}_", app.tag_input);
-    let paragraph = Paragraph::new(input_text).style(Style::default().fg(Color::White));
-    frame.render_widget(paragraph, inner);
+    let mut lines = vec![Line::styled(
+        format!("> {}_", app.tag_input),
+        Style::default().fg(Color::White),
+    )];
+    if app.is_suggesting_tags() {
+        lines.push(Line::styled(
+            format!("{} Suggesting tags...", app.spinner_char()),
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else if !app.suggested_tags.is_empty() {
+        lines.push(Line::styled(
+            "Suggested from your tags. Enter to accept, or edit.",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
 }
 
 fn render_feed_input(frame: &mut Frame, app: &App) {
@@ -427,6 +694,287 @@ fn render_opml_export(frame: &mut Frame, app: &App) {
     }
 }
 
+fn render_filtered_view(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 80, frame.area());
+
+    let block = Block::default()
+        .title(" Filtered by Blocklist ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    // Rule hit counts on top, filtered entries below, key hints at the bottom
+    let rules_height = (app.filter_rule_hits.len() as u16 + 2).clamp(3, 10);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(rules_height),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let rule_lines: Vec<Line> = if app.filter_rule_hits.is_empty() {
+        vec![Line::from(Span::styled(
+            "No rules in ~/.config/beatcheck/blocklist.txt",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        app.filter_rule_hits
+            .iter()
+            .map(|(rule, hits, rescued)| {
+                let color = if *hits == 0 {
+                    Color::DarkGray
+                } else {
+                    Color::White
+                };
+                let mut spans = vec![
+                    Span::styled(format!("{:>5}  ", hits), Style::default().fg(Color::Yellow)),
+                    Span::styled(rule.clone(), Style::default().fg(color)),
+                ];
+                if *rescued > 0 {
+                    spans.push(Span::styled(
+                        format!("  ({} rescued)", rescued),
+                        Style::default().fg(Color::Green),
+                    ));
+                }
+                Line::from(spans)
+            })
+            .collect()
+    };
+    let rules = Paragraph::new(rule_lines).block(
+        Block::default()
+            .title(" Hits per rule ")
+            .borders(Borders::BOTTOM),
+    );
+    frame.render_widget(rules, chunks[0]);
+
+    let items: Vec<ListItem> = app
+        .blocked_articles
+        .iter()
+        .map(|entry| {
+            let dt = entry.published_at.unwrap_or(entry.filtered_at);
+            let date = format!("{:02}-{:02}", dt.month(), dt.day());
+            let feed = entry.feed_title.as_deref().unwrap_or("Unknown");
+
+            ListItem::new(Line::from(vec![
+                Span::styled(date, Style::default().fg(Color::DarkGray)),
+                Span::raw(" "),
+                Span::styled(feed.to_string(), Style::default().fg(Color::Cyan)),
+                Span::raw("  "),
+                Span::styled(entry.title.clone(), Style::default().fg(Color::White)),
+                Span::styled(
+                    format!("  [{}]", entry.rule),
+                    Style::default().fg(Color::Red),
+                ),
+            ]))
+        })
+        .collect();
+
+    if items.is_empty() {
+        let empty = Paragraph::new("Nothing has been filtered.")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, chunks[1]);
+    } else {
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default();
+        state.select(Some(app.blocked_selected_index));
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    let hints = Paragraph::new("j/k:move  Enter:rescue  o:open  Esc:close")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[2]);
+}
+
+fn render_blocklist_view(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 70, frame.area());
+
+    let block = Block::default()
+        .title(" Blocklist - ~/.config/beatcheck/blocklist.txt ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    // Rules, then the new-rule input, a status line and key hints
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let rules = app.blocklist().rules();
+    if rules.is_empty() {
+        let empty = Paragraph::new("No rules yet. Press a to add one.")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = rules
+            .iter()
+            .map(|rule| {
+                ListItem::new(Span::styled(
+                    rule.source.clone(),
+                    Style::default().fg(Color::White),
+                ))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default();
+        state.select(Some(app.blocklist_selected_index));
+        frame.render_stateful_widget(list, chunks[0], &mut state);
+    }
+
+    if app.blocklist_input_active {
+        let input_text = format!("> {}_", app.blocklist_input);
+        let paragraph = Paragraph::new(input_text).style(Style::default().fg(Color::White));
+        frame.render_widget(paragraph, chunks[1]);
+    }
+
+    if let Some(status) = &app.blocklist_status {
+        let color = if status.starts_with("Error:") {
+            Color::Red
+        } else {
+            Color::Green
+        };
+        let status_paragraph = Paragraph::new(status.as_str()).style(Style::default().fg(color));
+        frame.render_widget(status_paragraph, chunks[2]);
+    }
+
+    let hints = if app.blocklist_input_active {
+        "Enter:add rule  Esc:cancel"
+    } else {
+        "j/k:move  a:add  d:remove  Esc:close"
+    };
+    let hints = Paragraph::new(hints).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[3]);
+}
+
+fn render_block_suggest(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 40, frame.area());
+
+    let block = Block::default()
+        .title(" Block this - pick a rule ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let items: Vec<ListItem> = app
+        .block_suggestions
+        .iter()
+        .map(|rule| {
+            ListItem::new(Span::styled(
+                rule.clone(),
+                Style::default().fg(Color::White),
+            ))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = ListState::default();
+    state.select(Some(app.block_suggest_index));
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+
+    if let Some(status) = &app.blocklist_status {
+        let status_paragraph =
+            Paragraph::new(status.as_str()).style(Style::default().fg(Color::Red));
+        frame.render_widget(status_paragraph, chunks[1]);
+    }
+
+    let hints = Paragraph::new("j/k:move  Enter:block  e:edit  Esc:cancel")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[2]);
+}
+
+fn render_template_picker(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, frame.area());
+
+    let block = Block::default()
+        .title(" Regenerate with template ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let default = app.default_template();
+    let items: Vec<ListItem> = app
+        .template_names()
+        .into_iter()
+        .map(|name| {
+            let mut spans = vec![Span::styled(
+                name.clone(),
+                Style::default().fg(Color::White),
+            )];
+            if Some(name.as_str()) == default {
+                spans.push(Span::styled(
+                    "  (default)",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = ListState::default();
+    state.select(Some(app.template_picker_index));
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+
+    let hints = Paragraph::new("j/k:move  Enter:regenerate  Esc:cancel")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[1]);
+}
+
 fn render_help(frame: &mut Frame) {
     let area = centered_rect(50, 80, frame.area());
 
@@ -444,11 +992,22 @@ fn render_help(frame: &mut Frame) {
         "   a        Add new feed",
         "   i        Import OPML file",
         "   w        Export OPML file",
+        "   f        Show blocklist filtered articles",
+        "   F        Re-apply blocklist to stored articles",
+        "   B        Manage blocklist rules",
+        "   x        Block this (suggest rules from article)",
+        "   c        Expand/collapse duplicate stories",
         "   o        Open in browser",
         "   e        Email article",
         "   b        Bookmark to Raindrop.io (enter tags)",
         "   Space+t/i/m  Quick bookmark (twit/im/mbw)",
         "   g        Regenerate summary",
+        "   G        Regenerate with another prompt template",
+        "   $        Summary usage and cost",
+        "   n        Write the daily briefing",
+        "   A        Ask follow-up questions about the article",
+        "   R        Show rundowns (twit/im/mbw bookmarks)",
+        "   t        Translate the article / show the original",
         "   d / ⌫    Delete article",
         "   D        Delete feed",
         "   u        Undelete last",
@@ -473,6 +1032,289 @@ fn render_help(frame: &mut Frame) {
     frame.render_widget(paragraph, area);
 }
 
+fn render_stats(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, frame.area());
+
+    let block = Block::default()
+        .title(" Summary usage and cost ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let report = &app.usage_report;
+    let lines: Vec<Line> = report
+        .lines()
+        .into_iter()
+        .map(|line| {
+            let style = if line.starts_with("Budget") && report.over_budget() {
+                Style::default().fg(Color::Red)
+            } else if line.starts_with("Today") || line.starts_with("This month") {
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            Line::styled(line, style)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let hints = Paragraph::new("Esc:close").style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[1]);
+}
+
+fn render_rundown(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 80, frame.area());
+
+    let block = Block::default()
+        .title(" Show Rundown ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    // Show tabs, the stories, then the note input, a status line and key hints
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let mut tabs = Vec::new();
+    for (i, show) in SHOWS.iter().enumerate() {
+        let style = if i == app.rundown_show {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Magenta)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        tabs.push(Span::styled(format!(" {} ", show), style));
+        tabs.push(Span::raw(" "));
+    }
+    if let Some(since) = app.rundown.as_ref().and_then(|r| r.since) {
+        tabs.push(Span::styled(
+            format!(
+                " since {}",
+                since
+                    .with_timezone(&chrono::Local)
+                    .format("%a %b %-d, %H:%M")
+            ),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(tabs)), chunks[0]);
+
+    let items = app
+        .rundown
+        .as_ref()
+        .map(|r| r.items.as_slice())
+        .unwrap_or_default();
+    if items.is_empty() {
+        let empty = Paragraph::new(
+            "Nothing bookmarked for this show yet. Bookmark with Space then t, i or m.",
+        )
+        .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, chunks[1]);
+    } else {
+        let list_items: Vec<ListItem> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let mut lines = vec![Line::styled(
+                    format!("{:>2}. {}", i + 1, item.title),
+                    Style::default().fg(Color::White),
+                )];
+                let source = match (&item.feed_title, &item.lede) {
+                    (Some(feed), Some(lede)) => format!("{} - {}", feed, lede),
+                    (Some(feed), None) => feed.clone(),
+                    (None, Some(lede)) => lede.clone(),
+                    (None, None) => item.url.clone(),
+                };
+                lines.push(Line::styled(
+                    format!("    {}", source),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                if let Some(note) = &item.note {
+                    lines.push(Line::styled(
+                        format!("    Note: {}", note),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+                ListItem::new(lines)
+            })
+            .collect();
+        let list = List::new(list_items).highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut state = ListState::default();
+        state.select(Some(app.rundown_selected_index));
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    if app.rundown_note_active {
+        let input_text = format!("Note> {}_", app.rundown_note_input);
+        let paragraph = Paragraph::new(input_text).style(Style::default().fg(Color::White));
+        frame.render_widget(paragraph, chunks[2]);
+    }
+
+    if let Some(status) = &app.rundown_status {
+        let color = if status.starts_with("Error:") {
+            Color::Red
+        } else if app.rundown_confirm_finish {
+            Color::Yellow
+        } else {
+            Color::Green
+        };
+        let status_paragraph = Paragraph::new(status.as_str()).style(Style::default().fg(color));
+        frame.render_widget(status_paragraph, chunks[3]);
+    }
+
+    let hints = if app.rundown_note_active {
+        "Enter:save note (empty removes it)  Esc:cancel"
+    } else {
+        "Tab:next show  j/k:move  J/K:reorder  e:note  w:export  N:new rundown  Esc:close"
+    };
+    let hints = Paragraph::new(hints).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[4]);
+}
+
+fn render_chat(frame: &mut Frame, app: &App) {
+    let area = centered_rect(80, 80, frame.area());
+
+    let title = app
+        .selected_article()
+        .map(|article| format!(" Ask about: {} ", article.title))
+        .unwrap_or_else(|| " Ask about this article ".to_string());
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta));
+
+    let inner = block.inner(area);
+
+    // Clear the area first
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let mut lines: Vec<Line> = Vec::new();
+    if app.chat_messages.is_empty() {
+        lines.push(Line::styled(
+            "Ask a question about this article. Answers come only from the article text.",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    for message in &app.chat_messages {
+        let (label, color) = match message.role {
+            ChatRole::User => ("You", Color::Cyan),
+            ChatRole::Assistant => ("AI", Color::Magenta),
+        };
+        for (i, text) in message.content.lines().enumerate() {
+            if i == 0 {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{}: ", label),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(text.to_string()),
+                ]));
+            } else {
+                lines.push(Line::from(text.to_string()));
+            }
+        }
+        lines.push(Line::from(""));
+    }
+    if app.is_answering() {
+        lines.push(Line::styled(
+            format!("{} Reading the article...", app.spinner_char()),
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else if let Some(error) = &app.chat_error {
+        lines.push(Line::styled(
+            format!("Couldn't answer: {}", error),
+            Style::default().fg(Color::Red),
+        ));
+    }
+
+    // Keep the latest messages in view
+    let rows: u16 = lines
+        .iter()
+        .map(|line| wrapped_rows(line, chunks[0].width))
+        .sum();
+    let scroll = rows.saturating_sub(chunks[0].height);
+    let conversation = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(conversation, chunks[0]);
+
+    let input =
+        Paragraph::new(format!("> {}_", app.chat_input)).style(Style::default().fg(Color::White));
+    frame.render_widget(input, chunks[1]);
+
+    let hints = Paragraph::new("Enter:ask  Ctrl+L:clear conversation  Esc:close")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[2]);
+}
+
+/// Rows a line takes when word-wrapped to `width` columns
+fn wrapped_rows(line: &Line, width: u16) -> u16 {
+    let width = usize::from(width.max(1));
+    let text: String = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect();
+    let mut rows = 1;
+    let mut used = 0;
+    for word in text.split(' ') {
+        let len = word.chars().count();
+        let needed = if used == 0 { len } else { used + 1 + len };
+        if needed <= width {
+            used = needed;
+        } else {
+            // A word wider than the line is broken across rows
+            rows += (used > 0) as usize + len.saturating_sub(1) / width;
+            used = len % width;
+            if used == 0 && len > 0 {
+                used = width;
+            }
+        }
+    }
+    u16::try_from(rows).unwrap_or(u16::MAX)
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, TimeZone, Utc};
+
+use crate::db::Repository;
+use crate::error::Result;
+use crate::models::UsageTotals;
+
+/// Days listed in the daily breakdown
+const REPORT_DAYS: i64 = 14;
+
+/// Local midnight at the start of `date`, in UTC
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(Utc::now)
+}
+
+/// Start of today, local time
+pub fn start_of_today() -> DateTime<Utc> {
+    local_midnight(Local::now().date_naive())
+}
+
+/// Start of the current month, local time
+pub fn start_of_month() -> DateTime<Utc> {
+    let today = Local::now().date_naive();
+    local_midnight(today.with_day(1).unwrap_or(today))
+}
+
+/// Summary spending for the stats screen and `--usage`
+#[derive(Debug, Clone, Default)]
+pub struct UsageReport {
+    pub today: UsageTotals,
+    pub month: UsageTotals,
+    /// (local date, totals), newest first; days without summaries are left out
+    pub daily: Vec<(String, UsageTotals)>,
+    pub monthly_budget: Option<f64>,
+}
+
+impl UsageReport {
+    pub async fn load(repository: &Repository, monthly_budget: Option<f64>) -> Result<Self> {
+        let since = local_midnight(Local::now().date_naive() - Duration::days(REPORT_DAYS - 1));
+        Ok(Self {
+            today: repository.get_usage_since(start_of_today()).await?,
+            month: repository.get_usage_since(start_of_month()).await?,
+            daily: repository.get_daily_usage(since).await?,
+            monthly_budget,
+        })
+    }
+
+    /// Whether this month's spending has reached the budget
+    pub fn over_budget(&self) -> bool {
+        self.monthly_budget
+            .is_some_and(|budget| self.month.cost_usd >= budget)
+    }
+
+    /// Plain-text report, one line per period
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            format!("Today       {}", format_totals(&self.today)),
+            format!("This month  {}", format_totals(&self.month)),
+        ];
+        if let Some(budget) = self.monthly_budget {
+            let status = if self.over_budget() {
+                "reached, background summaries paused"
+            } else {
+                "ok"
+            };
+            lines.push(format!(
+                "Budget      ${:.2} of ${:.2} ({})",
+                self.month.cost_usd, budget, status
+            ));
+        }
+        if !self.daily.is_empty() {
+            lines.push(String::new());
+            lines.push(format!("Last {} days", REPORT_DAYS));
+            for (day, totals) in &self.daily {
+                lines.push(format!("{}  {}", day, format_totals(totals)));
+            }
+        }
+        lines
+    }
+}
+
+/// "12 summaries  34.5k in / 2.1k out  $0.0612"
+pub fn format_totals(totals: &UsageTotals) -> String {
+    format!(
+        "{:>4} summaries  {:>7} in / {:>6} out  ${:.4}",
+        totals.requests,
+        format_tokens(totals.input_tokens),
+        format_tokens(totals.output_tokens),
+        totals.cost_usd
+    )
+}
+
+/// Token count shortened to k/M
+fn format_tokens(tokens: u64) -> String {
+    match tokens {
+        0..=999 => tokens.to_string(),
+        1_000..=999_999 => format!("{:.1}k", tokens as f64 / 1_000.0),
+        _ => format!("{:.2}M", tokens as f64 / 1_000_000.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_tokens() {
+        assert_eq!(format_tokens(850), "850");
+        assert_eq!(format_tokens(34_512), "34.5k");
+        assert_eq!(format_tokens(2_100_000), "2.10M");
+    }
+
+    #[test]
+    fn test_budget_status() {
+        let mut report = UsageReport {
+            month: UsageTotals {
+                requests: 10,
+                cost_usd: 4.99,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(!report.over_budget());
+        assert_eq!(report.lines().len(), 2);
+
+        report.monthly_budget = Some(5.0);
+        assert!(!report.over_budget());
+        report.month.cost_usd = 5.0;
+        assert!(report.over_budget());
+        assert!(report.lines()[2].contains("paused"));
+    }
+
+    #[test]
+    fn test_periods_start_at_local_midnight() {
+        let today = start_of_today().with_timezone(&Local);
+        assert_eq!(today.date_naive(), Local::now().date_naive());
+        assert_eq!(today.time(), chrono::NaiveTime::MIN);
+        assert_eq!(start_of_month().with_timezone(&Local).day(), 1);
+        assert!(start_of_month() <= start_of_today());
+    }
+}
@@ -0,0 +1,86 @@
+#!/usr/bin/env python3
+"""Writes the Chromium cookie databases used by the cookie decryption tests.
+
+Values are encrypted the way Chromium does on Linux: AES-128-CBC with a key from
+PBKDF2-HMAC-SHA1(password, "saltysalt", 1 iteration), an IV of 16 spaces and a
+"v10" (password "peanuts") or "v11" (keyring password) prefix. Databases from
+version 24 on prefix the plaintext with the SHA-256 of the cookie's host.
+
+Needs the `cryptography` package. Run from this directory.
+"""
+
+import hashlib
+import os
+import sqlite3
+
+from cryptography.hazmat.primitives import padding
+from cryptography.hazmat.primitives.ciphers import Cipher, algorithms, modes
+
+KEYRING_PASSWORD = b"fixture-keyring-password"
+
+# Microseconds since 1601-01-01
+FAR_FUTURE = (4102444800 + 11644473600) * 1000000  # 2100-01-01
+LONG_AGO = (946684800 + 11644473600) * 1000000  # 2000-01-01
+
+SCHEMA = """
+CREATE TABLE meta(key LONGVARCHAR NOT NULL UNIQUE PRIMARY KEY, value LONGVARCHAR);
+CREATE TABLE cookies(
+    creation_utc INTEGER NOT NULL, host_key TEXT NOT NULL, top_frame_site_key TEXT NOT NULL,
+    name TEXT NOT NULL, value TEXT NOT NULL, encrypted_value BLOB NOT NULL, path TEXT NOT NULL,
+    expires_utc INTEGER NOT NULL, is_secure INTEGER NOT NULL, is_httponly INTEGER NOT NULL,
+    last_access_utc INTEGER NOT NULL, has_expires INTEGER NOT NULL,
+    is_persistent INTEGER NOT NULL, priority INTEGER NOT NULL, samesite INTEGER NOT NULL,
+    source_scheme INTEGER NOT NULL, source_port INTEGER NOT NULL,
+    last_update_utc INTEGER NOT NULL, source_type INTEGER NOT NULL,
+    has_cross_site_ancestor INTEGER NOT NULL,
+    UNIQUE (host_key, top_frame_site_key, has_cross_site_ancestor, name, path,
+            source_scheme, source_port));
+"""
+
+
+def encrypt(prefix, password, host, value, hash_host):
+    key = hashlib.pbkdf2_hmac("sha1", password, b"saltysalt", 1, 16)
+    plaintext = value.encode()
+    if hash_host:
+        plaintext = hashlib.sha256(host.encode()).digest() + plaintext
+    padder = padding.PKCS7(128).padder()
+    padded = padder.update(plaintext) + padder.finalize()
+    encryptor = Cipher(algorithms.AES(key), modes.CBC(b" " * 16)).encryptor()
+    return prefix + encryptor.update(padded) + encryptor.finalize()
+
+
+def write(path, version, rows):
+    if os.path.exists(path):
+        os.remove(path)
+    conn = sqlite3.connect(path)
+    conn.executescript(SCHEMA)
+    conn.execute("INSERT INTO meta VALUES ('version', ?)", (str(version),))
+    for host, name, value, encrypted_value, expires in rows:
+        conn.execute(
+            "INSERT INTO cookies VALUES (0, ?, '', ?, ?, ?, '/', ?, 1, 1, 0, 1, 1, 1, 0, 2, 443, 0, 0, 0)",
+            (host, name, value, encrypted_value, expires),
+        )
+    conn.commit()
+    conn.close()
+
+
+def rows(version):
+    hash_host = version >= 24
+
+    def v10(host, value):
+        return encrypt(b"v10", b"peanuts", host, value, hash_host)
+
+    def v11(host, value):
+        return encrypt(b"v11", KEYRING_PASSWORD, host, value, hash_host)
+
+    return [
+        ("news.example.com", "session", "", v11("news.example.com", "s3ss10n=ok"), FAR_FUTURE),
+        ("news.example.com", "plain", "visible", b"", FAR_FUTURE),
+        (".news.example.com", "subscriber", "", v10(".news.example.com", "yes"), FAR_FUTURE),
+        ("news.example.com", "expired", "", v10("news.example.com", "old"), LONG_AGO),
+        ("other.example.org", "tracker", "", v10("other.example.org", "no"), FAR_FUTURE),
+    ]
+
+
+write("chromium-v18.sqlite", 18, rows(18))
+write("chromium-v24.sqlite", 24, rows(24))
@@ -0,0 +1,54 @@
+#!/usr/bin/env python3
+"""Trims a saved article page down to what the readability tests need.
+
+Scripts, styles, inline SVG, comments, <link>/<meta> tags and attributes the
+extractor never reads (data-*, aria-* other than aria-hidden, srcset, title,
+event handlers, ...) are removed and indentation dropped. Everything it looks
+at (tags, text, class, id, role, hidden, aria-hidden, style, href) is left as
+saved.
+
+The pages are saved copies of real articles from the test suite of Mozilla's
+Readability (https://github.com/mozilla/readability, test/test-pages),
+distributed under the Apache License 2.0:
+
+  wordpress.html  test-pages/wordpress  WordPress Tavern, a WordPress site
+                  https://wptavern.com/stack-overflow-jobs-data-shows-reactjs-skills-in-high-demand-wordpress-market-oversaturated-with-developers
+  medium.html     test-pages/medium-1   a Medium post
+                  https://medium.com/@pippinlee/the-open-journalism-project-better-student-journalism-fb39f4f701bb
+  theverge.html   test-pages/theverge   The Verge
+                  https://www.theverge.com/23752959/apple-vision-pro-headset-hands-on-demo-impressions
+
+Usage: trim.py SOURCE.html OUT.html
+"""
+
+import re
+import sys
+
+REMOVE_ELEMENTS = ["script", "style", "noscript", "svg", "template"]
+
+DROP_ATTRIBUTES = re.compile(
+    r"""\s(data-[\w-]+|aria-(?!hidden)[\w-]+|on\w+|srcset|sizes|src-orig|title|alt|"""
+    r"""itemprop|itemscope|itemtype|rel|target|width|height|loading|tabindex)"""
+    r"""(\s*=\s*("[^"]*"|'[^']*'|[^\s>]+))?(?=[\s/>])""",
+    re.I,
+)
+
+
+def trim(html):
+    for tag in REMOVE_ELEMENTS:
+        html = re.sub(
+            r"<%s\b[^>]*>.*?</%s\s*>" % (tag, tag), "", html, flags=re.S | re.I
+        )
+    html = re.sub(r"<!--.*?-->", "", html, flags=re.S)
+    html = re.sub(r"<(link|meta)\b(?![^>]*charset)[^>]*>", "", html, flags=re.I)
+    html = re.sub(r"<\w+\s[^>]*>", lambda tag: DROP_ATTRIBUTES.sub("", tag.group(0)), html)
+    html = re.sub(r"[ \t]*\n\s*", "\n", html)
+    return html.strip() + "\n"
+
+
+if __name__ == "__main__":
+    source, out = sys.argv[1:]
+    with open(source, encoding="utf-8") as f:
+        html = f.read()
+    with open(out, "w", encoding="utf-8") as f:
+        f.write(trim(html))